@@ -2,6 +2,7 @@
 mod tests;
 
 use basset::eris_lp::StateResponse;
+use basset::wrapper::MigrateMsg;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Coin;
 use cosmwasm_std::{
@@ -9,12 +10,15 @@ use cosmwasm_std::{
     QueryRequest, Response, StdResult, Uint128, WasmMsg, WasmQuery,
 };
 
+use cw2::set_contract_version;
 use cw20::BalanceResponse;
 use cw20::Cw20ExecuteMsg;
 use cw20::Cw20QueryMsg;
 
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
+use basset::version::{migrate_version, CW20_BASE_CONTRACT_NAME};
 use basset::wrapper::QueryMsg;
-use cw20_base::ContractError;
 
 use basset::wrapper::ExecuteMsg;
 
@@ -39,7 +43,7 @@ pub struct AmphHub {
 }
 
 impl LSDHub<ContractsRaw> for AmphHub {
-    fn instantiate_config(deps: Deps, config: ContractsRaw) -> StdResult<Self> {
+    fn instantiate_config(deps: Deps, config: ContractsRaw) -> Result<Self, ContractError> {
         Ok(Self {
             contracts: Contracts {
                 hub: deps.api.addr_validate(&config.hub)?,
@@ -48,7 +52,7 @@ impl LSDHub<ContractsRaw> for AmphHub {
         })
     }
 
-    fn query_exchange_rate(&self, deps: Deps, _env: Env) -> StdResult<Decimal> {
+    fn query_exchange_rate(&self, deps: Deps, _env: Env) -> Result<Decimal, ContractError> {
         // For steak based LSD, we query the corresponding luna value of that LSD
         // This is located in the state query of the LSD
         let amp_token_state: StateResponse =
@@ -59,13 +63,19 @@ impl LSDHub<ContractsRaw> for AmphHub {
         Ok(amp_token_state.exchange_rate)
     }
 
+    fn held_asset(&self) -> AssetInfo {
+        AssetInfo::Token {
+            contract_addr: self.contracts.token.clone(),
+        }
+    }
+
     fn get_balance(
         &self,
         deps: Deps,
         _env: Env,
         address: Addr,
         _funds: Vec<Coin>,
-    ) -> StdResult<Uint128> {
+    ) -> Result<Uint128, ContractError> {
         let balance: BalanceResponse =
             deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
                 contract_addr: self.contracts.token.to_string(),
@@ -83,7 +93,7 @@ impl LSDHub<ContractsRaw> for AmphHub {
         _info: MessageInfo,
         amount: Uint128,
         from: Addr,
-    ) -> StdResult<Vec<CosmosMsg>> {
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
         Ok(vec![CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: self.contracts.token.to_string(),
             msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
@@ -101,7 +111,7 @@ impl LSDHub<ContractsRaw> for AmphHub {
         _env: Env,
         amount: Uint128,
         to: Addr,
-    ) -> StdResult<Vec<CosmosMsg>> {
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
         Ok(vec![CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: self.contracts.token.to_string(),
             msg: to_binary(&Cw20ExecuteMsg::Transfer {
@@ -115,16 +125,27 @@ impl LSDHub<ContractsRaw> for AmphHub {
 
 pub type SpectrumInitMsg = TokenInitMsg<ContractsRaw>;
 
+pub const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Contracts that can be migrated to this one
+const REPLACED: &[&str] = &[CW20_BASE_CONTRACT_NAME];
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: SpectrumInitMsg,
 ) -> StdResult<Response> {
-    cavern_lsd_wrapper_token_with_limit::contract::instantiate::<ContractsRaw, AmphHub>(
-        deps, env, info, msg,
-    )
+    let res = cavern_lsd_wrapper_token_with_limit::contract::instantiate::<ContractsRaw, AmphHub>(
+        deps.branch(),
+        env,
+        info,
+        msg,
+    )?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(res)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -132,7 +153,7 @@ pub fn execute(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    msg: ExecuteMsg,
+    msg: ExecuteMsg<ContractsRaw>,
 ) -> Result<Response, ContractError> {
     cavern_lsd_wrapper_token_with_limit::contract::execute::<ContractsRaw, AmphHub>(
         deps, env, info, msg,
@@ -143,3 +164,11 @@ pub fn execute(
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     cavern_lsd_wrapper_token_with_limit::contract::query::<ContractsRaw, AmphHub>(deps, env, msg)
 }
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, REPLACED)?;
+    Ok(cavern_lsd_wrapper_token_with_limit::contract::migrate(
+        deps, env, msg,
+    )?)
+}
@@ -5,7 +5,7 @@ use cosmwasm_std::SubMsg;
 use basset::external::{
     CTokenStateResponse, SpectrumQueryMsg, UserInfoResponse,
 };
-use basset::hub::Config;
+use basset::hub::{Config, HubMode};
 use cosmwasm_std::WasmMsg;
 use cosmwasm_std::testing::MOCK_CONTRACT_ADDR;
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
@@ -71,6 +71,10 @@ impl WasmMockQuerier {
                         creator: api.addr_validate("owner1").unwrap(),
                         reward_contract: Some(api.addr_validate("reward").unwrap()),
                         token_contract: Some(api.addr_validate("token").unwrap()),
+                        lsd_asset: None,
+                        reward_splits: vec![],
+                        keeper_fee: None,
+                        mode: HubMode::SwapRewards,
                     };
                     SystemResult::Ok(ContractResult::from(to_binary(
                         &to_binary(&config).unwrap(),
@@ -7,9 +7,9 @@ use cosmwasm_std::to_binary;
 use cosmwasm_std::MemoryStorage;
 use cosmwasm_std::OwnedDeps;
 use cosmwasm_std::{from_binary, CosmosMsg, SubMsg, WasmMsg};
-use cosmwasm_std::{Decimal, Uint128, StdError};
+use cosmwasm_std::{Decimal, Uint128};
 use cw20::{BalanceResponse, Cw20ExecuteMsg};
-use cw20_base::ContractError;
+use basset::contract_error::ContractError;
 use std::str::FromStr;
 
 use crate::tests::mock_deps::mock_dependencies;
@@ -64,6 +64,8 @@ fn init_env(
         symbol: "w-S".to_string(),
 
         hub_contract: "hub".to_string(),
+        admin: None,
+        enable_snapshots: None,
 
         lsd_config: ContractsRaw {
             token: MOCK_SPECTRUM_TOKEN.to_string(),
@@ -290,7 +292,7 @@ fn test_decompound_same_time() {
     )
     .unwrap_err();
 
-    assert_eq!(err, ContractError::Std(StdError::generic_err("Can't decompound too often",)));
+    assert_eq!(err, ContractError::DecompoundTooOften {});
 }
 
 // What happens when we need to decompound (exchange rate changes)
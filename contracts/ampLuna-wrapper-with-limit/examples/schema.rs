@@ -0,0 +1,12 @@
+use cosmwasm_schema::write_api;
+
+use basset::wrapper::{MigrateMsg, QueryMsg};
+use wrapper_implementations::steak_with_limit::{SteakExecuteMsg, SteakInitMsg};
+fn main() {
+    write_api! {
+        instantiate: SteakInitMsg,
+        query: QueryMsg,
+        execute: SteakExecuteMsg,
+        migrate: MigrateMsg,
+    };
+}
@@ -1,4 +1,5 @@
-use cw20_base::msg::{ExecuteMsg, QueryMsg};
+use basset::wrapper::QueryMsg;
+use cw20_base::msg::ExecuteMsg;
 use wrapper_implementations::steak::SteakInitMsg;
 
 use cosmwasm_schema::write_api;
@@ -0,0 +1,11 @@
+use cosmwasm_schema::write_api;
+
+use astroport_lp_wrapper_token::{AstroportLPExecuteMsg, AstroportLPInitMsg};
+use basset::wrapper::QueryMsg;
+fn main() {
+    write_api! {
+        instantiate: AstroportLPInitMsg,
+        query: QueryMsg,
+        execute: AstroportLPExecuteMsg,
+    };
+}
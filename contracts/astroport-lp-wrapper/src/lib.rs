@@ -0,0 +1,302 @@
+#[cfg(test)]
+mod tests;
+
+use astroport::asset::{AssetInfo, PairInfo};
+use astroport::factory::PairType;
+use astroport::pair::{PoolResponse, QueryMsg as PairQueryMsg};
+use basset::external::{
+    AstroportGeneratorCw20HookMsg, AstroportGeneratorExecuteMsg, AstroportGeneratorQueryMsg,
+    PendingTokenResponse,
+};
+use basset::wrapper::MigrateMsg;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, to_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env,
+    MessageInfo, QueryRequest, Response, StdError, StdResult, Uint128, WasmMsg, WasmQuery,
+};
+
+use cw2::set_contract_version;
+use cw20::BalanceResponse;
+use cw20::Cw20ExecuteMsg;
+use cw20::Cw20QueryMsg;
+
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo as HeldAsset;
+use basset::version::migrate_version;
+use basset::wrapper::ExecuteMsg;
+use basset::wrapper::QueryMsg;
+
+use cavern_lsd_wrapper_token_with_limit::msg::TokenInitMsg;
+use cavern_lsd_wrapper_token_with_limit::trait_def::LSDHub;
+
+#[cw_serde]
+pub struct AstroportLPConfigRaw {
+    /// Astroport pair of the lp token
+    pub pair: String,
+    /// Astroport incentives contract the lp tokens are staked in
+    pub generator: String,
+    pub astro_token: String,
+}
+
+/// The wrapper holds raw Astroport lp tokens, staked in the incentives contract.
+/// The lp token is valued through its share of the pool and the incentives are harvested on decompound
+#[cw_serde]
+pub struct AstroportLPHub {
+    pub pair: Addr,
+    pub lp_token: Addr,
+    pub generator: Addr,
+    pub astro_token: Addr,
+}
+
+impl LSDHub<AstroportLPConfigRaw> for AstroportLPHub {
+    fn instantiate_config(deps: Deps, config: AstroportLPConfigRaw) -> Result<Self, ContractError> {
+        let pair = deps.api.addr_validate(&config.pair)?;
+        let pair_info: PairInfo = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: pair.to_string(),
+            msg: to_binary(&PairQueryMsg::Pair {})?,
+        }))?;
+        if pair_info.asset_infos.len() != 2 {
+            return Err(StdError::generic_err("Only pairs with 2 assets are supported").into());
+        }
+        // The exchange rate only grows monotonically on constant product pools
+        if pair_info.pair_type != (PairType::Xyk {}) {
+            return Err(StdError::generic_err("Only xyk pairs are supported").into());
+        }
+
+        Ok(Self {
+            pair,
+            lp_token: pair_info.liquidity_token,
+            generator: deps.api.addr_validate(&config.generator)?,
+            astro_token: deps.api.addr_validate(&config.astro_token)?,
+        })
+    }
+
+    fn query_exchange_rate(&self, deps: Deps, _env: Env) -> Result<Decimal, ContractError> {
+        // The value of an lp token is the geometric mean of the assets it can be withdrawn for.
+        // It only grows with the swap fees, price movements between the two assets don't change it
+        let pool: PoolResponse = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: self.pair.to_string(),
+            msg: to_binary(&PairQueryMsg::Pool {})?,
+        }))?;
+        if pool.total_share.is_zero() {
+            return Err(StdError::generic_err("The pool has no liquidity").into());
+        }
+
+        let asset_per_share = |amount: Uint128| Decimal::from_ratio(amount, pool.total_share);
+        let squared_rate = asset_per_share(pool.assets[0].amount)
+            .checked_mul(asset_per_share(pool.assets[1].amount))?;
+        Ok(squared_rate.sqrt())
+    }
+
+    fn held_asset(&self) -> HeldAsset {
+        HeldAsset::Token {
+            contract_addr: self.lp_token.clone(),
+        }
+    }
+
+    fn get_balance(
+        &self,
+        deps: Deps,
+        _env: Env,
+        address: Addr,
+        _funds: Vec<Coin>,
+    ) -> Result<Uint128, ContractError> {
+        // The lp tokens are staked in the incentives contract, not held by the wrapper
+        let deposit: Uint128 = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: self.generator.to_string(),
+            msg: to_binary(&AstroportGeneratorQueryMsg::Deposit {
+                lp_token: self.lp_token.to_string(),
+                user: address.to_string(),
+            })?,
+        }))?;
+        Ok(deposit)
+    }
+
+    fn deposit_funds(
+        &self,
+        _deps: Deps,
+        env: Env,
+        _info: MessageInfo,
+        amount: Uint128,
+        from: Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        Ok(vec![
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: self.lp_token.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                    owner: from.to_string(),
+                    recipient: env.contract.address.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            }),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: self.lp_token.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: self.generator.to_string(),
+                    amount,
+                    msg: to_binary(&AstroportGeneratorCw20HookMsg::Deposit {})?,
+                })?,
+                funds: vec![],
+            }),
+        ])
+    }
+
+    fn send_funds(
+        &self,
+        _deps: Deps,
+        _env: Env,
+        amount: Uint128,
+        to: Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        Ok(vec![
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: self.generator.to_string(),
+                msg: to_binary(&AstroportGeneratorExecuteMsg::Withdraw {
+                    lp_token: self.lp_token.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            }),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: self.lp_token.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: to.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            }),
+        ])
+    }
+
+    fn harvest_rewards(
+        &self,
+        deps: Deps,
+        env: Env,
+        recipient: Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        let pending: PendingTokenResponse =
+            deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                contract_addr: self.generator.to_string(),
+                msg: to_binary(&AstroportGeneratorQueryMsg::PendingToken {
+                    lp_token: self.lp_token.to_string(),
+                    user: env.contract.address.to_string(),
+                })?,
+            }))?;
+
+        let mut rewards = vec![(
+            AssetInfo::Token {
+                contract_addr: self.astro_token.clone(),
+            },
+            pending.pending,
+        )];
+        rewards.extend(
+            pending
+                .pending_on_proxy
+                .unwrap_or_default()
+                .into_iter()
+                .map(|asset| (asset.info, asset.amount)),
+        );
+
+        let mut messages = vec![];
+        if rewards.iter().any(|(_, pending)| !pending.is_zero()) {
+            messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: self.generator.to_string(),
+                msg: to_binary(&AstroportGeneratorExecuteMsg::ClaimRewards {
+                    lp_tokens: vec![self.lp_token.to_string()],
+                })?,
+                funds: vec![],
+            }));
+        }
+
+        // Deposits and withdrawals also send the pending rewards to the wrapper, those are harvested here as well
+        for (info, pending) in rewards {
+            let amount = pending + query_asset_balance(deps, &info, &env.contract.address)?;
+            if !amount.is_zero() {
+                messages.push(transfer_asset_msg(info, amount, &recipient)?);
+            }
+        }
+        Ok(messages)
+    }
+}
+
+fn query_asset_balance(deps: Deps, info: &AssetInfo, address: &Addr) -> StdResult<Uint128> {
+    match info {
+        AssetInfo::Token { contract_addr } => {
+            let balance: BalanceResponse =
+                deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                    contract_addr: contract_addr.to_string(),
+                    msg: to_binary(&Cw20QueryMsg::Balance {
+                        address: address.to_string(),
+                    })?,
+                }))?;
+            Ok(balance.balance)
+        }
+        AssetInfo::NativeToken { denom } => Ok(deps.querier.query_balance(address, denom)?.amount),
+    }
+}
+
+fn transfer_asset_msg(info: AssetInfo, amount: Uint128, to: &Addr) -> StdResult<CosmosMsg> {
+    match info {
+        AssetInfo::Token { contract_addr } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: to.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        })),
+        AssetInfo::NativeToken { denom } => Ok(CosmosMsg::Bank(BankMsg::Send {
+            to_address: to.to_string(),
+            amount: vec![Coin { denom, amount }],
+        })),
+    }
+}
+
+pub type AstroportLPInitMsg = TokenInitMsg<AstroportLPConfigRaw>;
+pub type AstroportLPExecuteMsg = ExecuteMsg<AstroportLPConfigRaw>;
+
+pub const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: AstroportLPInitMsg,
+) -> StdResult<Response> {
+    let res = cavern_lsd_wrapper_token_with_limit::contract::instantiate::<
+        AstroportLPConfigRaw,
+        AstroportLPHub,
+    >(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(res)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: AstroportLPExecuteMsg,
+) -> Result<Response, ContractError> {
+    cavern_lsd_wrapper_token_with_limit::contract::execute::<AstroportLPConfigRaw, AstroportLPHub>(
+        deps, env, info, msg,
+    )
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    cavern_lsd_wrapper_token_with_limit::contract::query::<AstroportLPConfigRaw, AstroportLPHub>(
+        deps, env, msg,
+    )
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, &[])?;
+    Ok(cavern_lsd_wrapper_token_with_limit::contract::migrate(
+        deps, env, msg,
+    )?)
+}
@@ -0,0 +1,166 @@
+use astroport::asset::{Asset, AssetInfo, PairInfo};
+use astroport::factory::PairType;
+use astroport::pair::{PoolResponse, QueryMsg as PairQueryMsg};
+use basset::external::{AstroportGeneratorQueryMsg, PendingTokenResponse};
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    from_binary, from_slice, to_binary, Addr, ContractResult, Empty, OwnedDeps, Querier,
+    QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
+};
+use cw20::{BalanceResponse, Cw20QueryMsg};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+pub const MOCK_PAIR: &str = "astroport-pair";
+pub const MOCK_LP_TOKEN: &str = "astroport-lp-token";
+pub const MOCK_GENERATOR: &str = "astroport-generator";
+pub const MOCK_ASTRO_TOKEN: &str = "astro-token";
+
+pub fn mock_dependencies() -> OwnedDeps<MockStorage, MockApi, WasmMockQuerier> {
+    let custom_querier: WasmMockQuerier = WasmMockQuerier::new(MockQuerier::new(&[]));
+
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: custom_querier,
+        custom_query_type: PhantomData,
+    }
+}
+
+pub struct WasmMockQuerier {
+    pub base: MockQuerier<Empty>,
+    pair_type: PairType,
+    pool: (Uint128, Uint128, Uint128),
+    deposit: Uint128,
+    pending: PendingTokenResponse,
+    token_balances: HashMap<String, Uint128>,
+}
+
+impl Querier for WasmMockQuerier {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        // MockQuerier doesn't support Custom, so we ignore it completely here
+        let request: QueryRequest<Empty> = match from_slice(bin_request) {
+            Ok(v) => v,
+            Err(e) => {
+                return SystemResult::Err(SystemError::InvalidRequest {
+                    error: format!("Parsing query request: {}", e),
+                    request: bin_request.into(),
+                })
+            }
+        };
+        self.handle_query(&request)
+    }
+}
+
+impl WasmMockQuerier {
+    pub fn handle_query(&self, request: &QueryRequest<Empty>) -> QuerierResult {
+        match &request {
+            QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => {
+                if *contract_addr == MOCK_PAIR {
+                    match from_binary(msg).unwrap() {
+                        PairQueryMsg::Pair {} => {
+                            SystemResult::Ok(ContractResult::from(to_binary(&PairInfo {
+                                asset_infos: vec![
+                                    AssetInfo::NativeToken {
+                                        denom: "uluna".to_string(),
+                                    },
+                                    AssetInfo::NativeToken {
+                                        denom: "uusd".to_string(),
+                                    },
+                                ],
+                                contract_addr: Addr::unchecked(MOCK_PAIR),
+                                liquidity_token: Addr::unchecked(MOCK_LP_TOKEN),
+                                pair_type: self.pair_type.clone(),
+                            })))
+                        }
+                        PairQueryMsg::Pool {} => {
+                            SystemResult::Ok(ContractResult::from(to_binary(&PoolResponse {
+                                assets: vec![
+                                    Asset {
+                                        info: AssetInfo::NativeToken {
+                                            denom: "uluna".to_string(),
+                                        },
+                                        amount: self.pool.0,
+                                    },
+                                    Asset {
+                                        info: AssetInfo::NativeToken {
+                                            denom: "uusd".to_string(),
+                                        },
+                                        amount: self.pool.1,
+                                    },
+                                ],
+                                total_share: self.pool.2,
+                            })))
+                        }
+                        _ => unimplemented!(),
+                    }
+                } else if *contract_addr == MOCK_GENERATOR {
+                    match from_binary(msg).unwrap() {
+                        AstroportGeneratorQueryMsg::Deposit { .. } => {
+                            SystemResult::Ok(ContractResult::from(to_binary(&self.deposit)))
+                        }
+                        AstroportGeneratorQueryMsg::PendingToken { .. } => {
+                            SystemResult::Ok(ContractResult::from(to_binary(&self.pending)))
+                        }
+                    }
+                } else {
+                    match from_binary(msg).unwrap() {
+                        Cw20QueryMsg::Balance { address } => {
+                            let balance = self
+                                .token_balances
+                                .get(&format!("{}-{}", contract_addr, address))
+                                .cloned()
+                                .unwrap_or_default();
+                            SystemResult::Ok(ContractResult::from(to_binary(&BalanceResponse {
+                                balance,
+                            })))
+                        }
+                        _ => unimplemented!(),
+                    }
+                }
+            }
+            _ => self.base.handle_query(request),
+        }
+    }
+}
+
+impl WasmMockQuerier {
+    pub fn new(base: MockQuerier<Empty>) -> Self {
+        WasmMockQuerier {
+            base,
+            pair_type: PairType::Xyk {},
+            pool: (
+                1_000_000u128.into(),
+                4_000_000u128.into(),
+                2_000_000u128.into(),
+            ),
+            deposit: Uint128::zero(),
+            pending: PendingTokenResponse {
+                pending: Uint128::zero(),
+                pending_on_proxy: None,
+            },
+            token_balances: HashMap::new(),
+        }
+    }
+
+    pub fn with_pair_type(&mut self, pair_type: PairType) {
+        self.pair_type = pair_type;
+    }
+
+    pub fn with_pool(&mut self, asset_0: u128, asset_1: u128, total_share: u128) {
+        self.pool = (asset_0.into(), asset_1.into(), total_share.into());
+    }
+
+    pub fn with_deposit(&mut self, deposit: u128) {
+        self.deposit = deposit.into();
+    }
+
+    pub fn with_pending(&mut self, pending: PendingTokenResponse) {
+        self.pending = pending;
+    }
+
+    pub fn with_token_balance(&mut self, token: &str, address: &str, balance: u128) {
+        self.token_balances
+            .insert(format!("{}-{}", token, address), balance.into());
+    }
+}
@@ -0,0 +1,259 @@
+use crate::tests::mock_deps::{
+    mock_dependencies, WasmMockQuerier, MOCK_ASTRO_TOKEN, MOCK_GENERATOR, MOCK_LP_TOKEN, MOCK_PAIR,
+};
+use crate::{execute, instantiate, query, AstroportLPConfigRaw, AstroportLPInitMsg};
+use astroport::asset::{Asset, AssetInfo};
+use astroport::factory::PairType;
+use basset::contract_error::ContractError;
+use basset::external::{
+    AstroportGeneratorCw20HookMsg, AstroportGeneratorExecuteMsg, PendingTokenResponse,
+};
+use basset::wrapper::ExecuteMsg;
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    from_binary, to_binary, BankMsg, Coin, CosmosMsg, MemoryStorage, OwnedDeps, StdError, SubMsg,
+    Uint128, WasmMsg,
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg};
+
+fn get_wasm_msg<T: serde::Serialize>(contract: &str, msg: &T) -> SubMsg {
+    SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: contract.to_string(),
+        msg: to_binary(msg).unwrap(),
+        funds: vec![],
+    }))
+}
+
+fn get_withdraw_msgs(to: &str, amount: u128) -> Vec<SubMsg> {
+    vec![
+        get_wasm_msg(
+            MOCK_GENERATOR,
+            &AstroportGeneratorExecuteMsg::Withdraw {
+                lp_token: MOCK_LP_TOKEN.to_string(),
+                amount: amount.into(),
+            },
+        ),
+        get_wasm_msg(
+            MOCK_LP_TOKEN,
+            &Cw20ExecuteMsg::Transfer {
+                recipient: to.to_string(),
+                amount: amount.into(),
+            },
+        ),
+    ]
+}
+
+fn instantiate_msg() -> AstroportLPInitMsg {
+    AstroportLPInitMsg {
+        decimals: 6,
+        initial_balances: vec![],
+        types: None,
+        name: "astroport-lp-wrapper".to_string(),
+        symbol: "w-LP".to_string(),
+
+        hub_contract: "hub".to_string(),
+        admin: None,
+        enable_snapshots: None,
+
+        lsd_config: AstroportLPConfigRaw {
+            pair: MOCK_PAIR.to_string(),
+            generator: MOCK_GENERATOR.to_string(),
+            astro_token: MOCK_ASTRO_TOKEN.to_string(),
+        },
+        max_decompound_ratio: None,
+    }
+}
+
+fn init_env() -> OwnedDeps<MemoryStorage, MockApi, WasmMockQuerier> {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        instantiate_msg(),
+    )
+    .unwrap();
+
+    // The pool starts with 1 lp token worth 1 unit of underlying value
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("depositor", &[]),
+        ExecuteMsg::MintWith {
+            recipient: "depositor".to_string(),
+            lsd_amount: 1_000_000u128.into(),
+        },
+    )
+    .unwrap();
+    deps.querier.with_deposit(1_000_000);
+
+    deps
+}
+
+#[test]
+fn test_only_xyk_pairs() {
+    let mut deps = mock_dependencies();
+    deps.querier.with_pair_type(PairType::Stable {});
+
+    let err = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        instantiate_msg(),
+    )
+    .unwrap_err();
+    assert_eq!(err, StdError::generic_err("Only xyk pairs are supported"));
+}
+
+#[test]
+fn test_deposit_funds() {
+    let mut deps = init_env();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("depositor", &[]),
+        ExecuteMsg::MintWith {
+            recipient: "depositor".to_string(),
+            lsd_amount: 1_000_000u128.into(),
+        },
+    )
+    .unwrap();
+
+    // The lp tokens are transferred to the wrapper and staked in the incentives contract
+    assert_eq!(
+        res.messages,
+        vec![
+            get_wasm_msg(
+                MOCK_LP_TOKEN,
+                &Cw20ExecuteMsg::TransferFrom {
+                    owner: "depositor".to_string(),
+                    recipient: MOCK_CONTRACT_ADDR.to_string(),
+                    amount: 1_000_000u128.into(),
+                }
+            ),
+            get_wasm_msg(
+                MOCK_LP_TOKEN,
+                &Cw20ExecuteMsg::Send {
+                    contract: MOCK_GENERATOR.to_string(),
+                    amount: 1_000_000u128.into(),
+                    msg: to_binary(&AstroportGeneratorCw20HookMsg::Deposit {}).unwrap(),
+                }
+            ),
+        ]
+    );
+
+    let balance: BalanceResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            cw20_base::msg::QueryMsg::Balance {
+                address: "depositor".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(balance.balance, Uint128::from(2_000_000u128));
+}
+
+#[test]
+fn test_decompound_pool_share() {
+    let mut deps = init_env();
+
+    // The pool earned fees, 1 lp token is now worth 4 units of underlying value
+    deps.querier.with_pool(2_000_000, 8_000_000, 1_000_000);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::Decompound { recipient: None },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(1);
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("hub", &[]),
+        ExecuteMsg::Decompound { recipient: None },
+    )
+    .unwrap();
+
+    // Only 250_000 lp tokens are needed to back the 1_000_000 wrapper tokens
+    assert_eq!(res.messages, get_withdraw_msgs("hub", 750_000u128 - 1u128));
+}
+
+#[test]
+fn test_decompound_harvests_incentives() {
+    let mut deps = init_env();
+
+    deps.querier.with_pending(PendingTokenResponse {
+        pending: 100u128.into(),
+        pending_on_proxy: Some(vec![Asset {
+            info: AssetInfo::NativeToken {
+                denom: "uproxy".to_string(),
+            },
+            amount: 30u128.into(),
+        }]),
+    });
+    // ASTRO received on previous deposits and withdrawals
+    deps.querier
+        .with_token_balance(MOCK_ASTRO_TOKEN, MOCK_CONTRACT_ADDR, 20);
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(1);
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("hub", &[]),
+        ExecuteMsg::Decompound {
+            recipient: Some("reward".to_string()),
+        },
+    )
+    .unwrap();
+
+    // The lp value didn't change, only the incentives are sent to the reward contract
+    assert_eq!(
+        res.messages,
+        vec![
+            get_wasm_msg(
+                MOCK_GENERATOR,
+                &AstroportGeneratorExecuteMsg::ClaimRewards {
+                    lp_tokens: vec![MOCK_LP_TOKEN.to_string()],
+                }
+            ),
+            get_wasm_msg(
+                MOCK_ASTRO_TOKEN,
+                &Cw20ExecuteMsg::Transfer {
+                    recipient: "reward".to_string(),
+                    amount: 120u128.into(),
+                }
+            ),
+            SubMsg::new(BankMsg::Send {
+                to_address: "reward".to_string(),
+                amount: vec![Coin::new(30, "uproxy")],
+            }),
+        ]
+    );
+}
+
+#[test]
+fn test_decompound_nothing_to_harvest() {
+    let mut deps = init_env();
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(1);
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("hub", &[]),
+        ExecuteMsg::Decompound { recipient: None },
+    )
+    .unwrap();
+
+    assert_eq!(res.messages, vec![]);
+}
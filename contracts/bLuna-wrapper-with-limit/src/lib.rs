@@ -0,0 +1,75 @@
+use basset::external::LSDStateResponseTrait;
+use basset::wrapper::MigrateMsg;
+use basset::wrapper::QueryMsg;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Binary;
+use cosmwasm_std::Decimal;
+use cosmwasm_std::Deps;
+use cosmwasm_std::DepsMut;
+use cosmwasm_std::Env;
+use cosmwasm_std::MessageInfo;
+use cosmwasm_std::Response;
+use cosmwasm_std::StdResult;
+use cosmwasm_std::Uint128;
+use cosmwasm_std::{entry_point, Coin};
+use cw2::set_contract_version;
+
+use basset::contract_error::ContractError;
+use basset::version::{migrate_version, CW20_BASE_CONTRACT_NAME};
+use wrapper_implementations::steak_with_limit;
+
+pub const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Contracts that can be migrated to this one
+const REPLACED: &[&str] = &[CW20_BASE_CONTRACT_NAME, "crates.io:b_luna-token"];
+
+#[cw_serde]
+pub struct BLunaStateResponse {
+    pub total_usteak: Uint128,
+    pub total_native: Uint128,
+    pub exchange_rate: Decimal,
+    pub unlocked_coins: Vec<Coin>,
+}
+
+impl LSDStateResponseTrait for BLunaStateResponse {
+    fn exchange_rate(&self) -> Decimal {
+        self.exchange_rate
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: steak_with_limit::SteakInitMsg,
+) -> StdResult<Response> {
+    let res = steak_with_limit::instantiate::<BLunaStateResponse>(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(res)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: steak_with_limit::SteakExecuteMsg,
+) -> Result<Response, ContractError> {
+    steak_with_limit::execute::<BLunaStateResponse>(deps, env, info, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    steak_with_limit::query::<BLunaStateResponse>(deps, env, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, REPLACED)?;
+    Ok(steak_with_limit::migrate::<BLunaStateResponse>(
+        deps, env, msg,
+    )?)
+}
@@ -1,6 +1,7 @@
 
 use wrapper_implementations::steak::SteakInitMsg;
-use cw20_base::msg::{ExecuteMsg, QueryMsg};
+use basset::wrapper::QueryMsg;
+use cw20_base::msg::ExecuteMsg;
 use cosmwasm_schema::write_api;
 
 fn main() {
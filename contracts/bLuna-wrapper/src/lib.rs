@@ -1,5 +1,6 @@
 use basset::external::LSDStateResponseTrait;
-use basset::wrapper::ExecuteMsg;
+use basset::wrapper::MigrateMsg;
+use basset::wrapper::QueryMsg;
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Binary;
@@ -12,11 +13,18 @@ use cosmwasm_std::Response;
 use cosmwasm_std::StdResult;
 use cosmwasm_std::Uint128;
 use cosmwasm_std::{entry_point, Coin};
+use cw2::set_contract_version;
 
-use cw20_base::msg::QueryMsg;
-use cw20_base::ContractError;
+use basset::contract_error::ContractError;
+use basset::version::{migrate_version, CW20_BASE_CONTRACT_NAME};
 use wrapper_implementations::steak;
 
+pub const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Contracts that can be migrated to this one
+const REPLACED: &[&str] = &[CW20_BASE_CONTRACT_NAME];
+
 #[cw_serde]
 pub struct BLunaStateResponse {
     pub total_usteak: Uint128,
@@ -33,12 +41,14 @@ impl LSDStateResponseTrait for BLunaStateResponse {
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: steak::SteakInitMsg,
 ) -> StdResult<Response> {
-    steak::instantiate::<BLunaStateResponse>(deps, env, info, msg)
+    let res = steak::instantiate::<BLunaStateResponse>(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(res)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -46,7 +56,7 @@ pub fn execute(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    msg: ExecuteMsg,
+    msg: steak::SteakExecuteMsg,
 ) -> Result<Response, ContractError> {
     steak::execute::<BLunaStateResponse>(deps, env, info, msg)
 }
@@ -55,3 +65,9 @@ pub fn execute(
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     steak::query::<BLunaStateResponse>(deps, env, msg)
 }
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, REPLACED)?;
+    Ok(steak::migrate(deps, env, msg)?)
+}
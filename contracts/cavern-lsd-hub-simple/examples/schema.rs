@@ -1,11 +0,0 @@
-use basset::hub::{InstantiateMsg,ExecuteMsg, QueryMsg};
-
-use cosmwasm_schema::write_api;
-
-fn main() {
-    write_api! {
-        instantiate: InstantiateMsg,
-        query: QueryMsg,
-        execute: ExecuteMsg,
-    };
-}
@@ -1,7 +0,0 @@
-pub mod contract;
-pub mod state;
-
-mod config;
-
-#[cfg(test)]
-mod testing;
@@ -1,2 +0,0 @@
-mod mock_querier;
-mod tests;
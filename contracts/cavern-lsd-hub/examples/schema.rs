@@ -1,11 +1,12 @@
-use basset::hub::{InstantiateMsg,ExecuteMsg, QueryMsg};
+use basset::hub::{OldInstantiateMsg,ExecuteMsg, QueryMsg, MigrateMsg};
 
 use cosmwasm_schema::write_api;
 
 fn main() {
     write_api! {
-        instantiate: InstantiateMsg,
+        instantiate: OldInstantiateMsg,
         query: QueryMsg,
         execute: ExecuteMsg,
+        migrate: MigrateMsg,
     };
 }
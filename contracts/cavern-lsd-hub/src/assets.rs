@@ -0,0 +1,325 @@
+use crate::config::validate_asset;
+use crate::contract::{
+    decompounded_amount, execute_forward_rewards, forwards_rewards, query_asset_balance,
+    transfer_asset_msg, update_guards, ASSET_DECOMPOUND_REPLY_ID,
+    ISOLATED_ASSET_DECOMPOUND_REPLY_ID,
+};
+use crate::hooks::hub_updated_hook_msgs;
+use crate::state::{
+    asset_response, read_assets, ASSETS, ASSET_BALANCES_BEFORE, CONFIG, KEEPER, NEXT_ASSET_ID,
+    PARAMETERS,
+};
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
+use basset::hub::{AssetResponse, AssetsResponse, HubAsset, HubMode, NewHubAsset};
+use basset::reward::ExecuteMsg::SwapToRewardDenom;
+use basset::wrapper::{
+    ExecuteMsg as LSDWrapperExecuteMsg, PendingDecompoundResponse, QueryMsg as LSDWrapperQueryMsg,
+};
+use cosmwasm_std::{
+    attr, to_binary, Addr, Attribute, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order,
+    Reply, Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
+};
+
+/// Registers new wrapper and reward contract pairs, without checking the caller.
+/// Their LSD must be known when the hub forwards the rewards
+pub fn register_assets(
+    deps: DepsMut,
+    env: &Env,
+    assets: Vec<NewHubAsset>,
+) -> Result<Vec<Attribute>, ContractError> {
+    let forwards = forwards_rewards(&CONFIG.load(deps.storage)?);
+    let mut next_id = NEXT_ASSET_ID.may_load(deps.storage)?.unwrap_or_default();
+    let mut attributes = vec![];
+    for asset in assets {
+        validate_mode(deps.as_ref(), &asset.mode)?;
+        if forwards && asset.lsd_asset.is_none() {
+            return Err(ContractError::MissingLsdAsset {});
+        }
+        let asset = HubAsset {
+            token_contract: deps.api.addr_validate(&asset.token_contract)?,
+            reward_contract: deps.api.addr_validate(&asset.reward_contract)?,
+            mode: asset.mode,
+            last_index_modification: env.block.time.seconds(),
+            lsd_asset: asset
+                .lsd_asset
+                .map(|lsd_asset| validate_asset(deps.as_ref(), lsd_asset))
+                .transpose()?,
+        };
+        ASSETS.save(deps.storage, next_id, &asset)?;
+        attributes.push(attr("registered_asset", next_id.to_string()));
+        next_id += 1;
+    }
+    NEXT_ASSET_ID.save(deps.storage, &next_id)?;
+    Ok(attributes)
+}
+
+/// Registers new assets, queued like the config updates
+pub fn execute_register_assets(
+    deps: DepsMut,
+    env: Env,
+    assets: Vec<NewHubAsset>,
+) -> Result<Response, ContractError> {
+    let attributes = register_assets(deps, &env, assets)?;
+    Ok(Response::new()
+        .add_attribute("action", "register_assets")
+        .add_attributes(attributes))
+}
+
+/// Deregisters an asset, queued like the config updates
+pub fn execute_deregister_asset(deps: DepsMut, id: u64) -> Result<Response, ContractError> {
+    if !ASSETS.has(deps.storage, id) {
+        return Err(ContractError::AssetNotRegistered { id });
+    }
+    ASSETS.remove(deps.storage, id);
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "deregister_asset"),
+        attr("id", id.to_string()),
+    ]))
+}
+
+/// Returns all the registered asset ids
+pub fn registered_asset_ids(storage: &dyn Storage) -> StdResult<Vec<u64>> {
+    ASSETS.keys(storage, None, None, Order::Ascending).collect()
+}
+
+/// Decompounds the rewards of the given registered assets to their reward contract,
+/// or to the hub when their LSD is known and the rewards are forwarded like the primary ones.
+/// The rewards are handled in the replies.
+/// Assets updated less than `min_update_interval` ago are skipped.
+/// When `isolate` is set, a failing asset doesn't revert the update of the others
+pub fn update_registered_assets(
+    deps: DepsMut,
+    env: &Env,
+    keeper: &Addr,
+    ids: Vec<u64>,
+    isolate: bool,
+) -> Result<(Vec<SubMsg>, Vec<Attribute>), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let (min_interval, min_decompound) = update_guards(&config, &PARAMETERS.load(deps.storage)?);
+    let forwards = forwards_rewards(&config);
+    if forwards && !ids.is_empty() {
+        KEEPER.save(deps.storage, keeper)?;
+    }
+    let mut messages = vec![];
+    let mut attributes = vec![];
+    for id in ids {
+        let mut asset = ASSETS
+            .may_load(deps.storage, id)?
+            .ok_or(ContractError::AssetNotRegistered { id })?;
+        if env.block.time.seconds() < asset.last_index_modification + min_interval {
+            attributes.push(attr("skipped_asset", id.to_string()));
+            continue;
+        }
+
+        // Nothing is sent when there is not enough to decompound, like for the primary asset
+        if !min_decompound.is_zero() {
+            let pending_decompound: StdResult<PendingDecompoundResponse> =
+                deps.querier.query_wasm_smart(
+                    asset.token_contract.to_string(),
+                    &LSDWrapperQueryMsg::PendingDecompound {},
+                );
+            match pending_decompound {
+                Ok(pending_decompound) if pending_decompound.lsd_rewards >= min_decompound => {}
+                Err(err) if !isolate => return Err(err.into()),
+                _ => {
+                    attributes.push(attr("skipped_asset", id.to_string()));
+                    continue;
+                }
+            }
+        }
+
+        // Only what the hub receives during the decompound is forwarded.
+        // Assets registered before the hub forwarded the rewards may not know their LSD
+        let recipient = match &asset.lsd_asset {
+            Some(lsd_asset) if forwards => {
+                let balance = query_asset_balance(deps.as_ref(), &env.contract.address, lsd_asset)?;
+                ASSET_BALANCES_BEFORE.save(deps.storage, id, &balance)?;
+                env.contract.address.clone()
+            }
+            None if forwards && !isolate => return Err(ContractError::MissingLsdAsset {}),
+            None if forwards => {
+                attributes.push(attr("skipped_asset", id.to_string()));
+                continue;
+            }
+            _ => asset.reward_contract.clone(),
+        };
+        let decompound = WasmMsg::Execute {
+            contract_addr: asset.token_contract.to_string(),
+            msg: to_binary(&LSDWrapperExecuteMsg::<Empty>::Decompound {
+                recipient: Some(recipient.to_string()),
+            })?,
+            funds: vec![],
+        };
+        // The reply id tells which asset was decompounded and whether it is isolated
+        messages.push(if isolate {
+            SubMsg::reply_always(decompound, ISOLATED_ASSET_DECOMPOUND_REPLY_ID + id)
+        } else {
+            SubMsg::reply_on_success(decompound, ASSET_DECOMPOUND_REPLY_ID + id)
+        });
+
+        asset.last_index_modification = env.block.time.seconds();
+        ASSETS.save(deps.storage, id, &asset)?;
+        attributes.push(attr("updated_asset", id.to_string()));
+    }
+    Ok((messages, attributes))
+}
+
+/// Handles the rewards decompounded for the given asset and notifies the hooks.
+/// The failure of an isolated asset is only reported.
+/// When its rewards can't be forwarded, they go straight to its reward contract
+pub fn execute_asset_decompound_reply(
+    mut deps: DepsMut,
+    env: Env,
+    id: u64,
+    isolated: bool,
+    msg: Reply,
+) -> Result<Response, ContractError> {
+    let asset = ASSETS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::AssetNotRegistered { id })?;
+    let balance_before = ASSET_BALANCES_BEFORE.may_load(deps.storage, id)?;
+    ASSET_BALANCES_BEFORE.remove(deps.storage, id);
+    let response = match msg.result.into_result() {
+        Ok(response) => response,
+        Err(error) => {
+            return Ok(Response::new().add_attributes(vec![
+                attr("action", "asset_decompound_failed"),
+                attr("id", id.to_string()),
+                attr("error", error),
+            ]))
+        }
+    };
+
+    let config = CONFIG.load(deps.storage)?;
+    let (res, decompounded) = match (balance_before, &asset.lsd_asset) {
+        (Some(balance_before), Some(lsd_asset)) => match execute_forward_rewards(
+            deps.branch(),
+            &env,
+            &config,
+            lsd_asset,
+            &asset.reward_contract,
+            &asset.mode,
+            balance_before,
+        ) {
+            Ok(forwarded) => forwarded,
+            Err(error) if isolated => {
+                return forward_failed_response(
+                    deps.as_ref(),
+                    &env,
+                    id,
+                    &asset,
+                    lsd_asset,
+                    balance_before,
+                    error,
+                )
+            }
+            Err(error) => return Err(error),
+        },
+        _ => (
+            Response::new().add_messages(follow_up_msgs(&asset.mode, &asset.reward_contract)?),
+            decompounded_amount(&response.events, &asset.token_contract)?,
+        ),
+    };
+
+    Ok(res
+        .add_submessages(hub_updated_hook_msgs(
+            deps.as_ref(),
+            &env,
+            &asset.token_contract,
+            decompounded,
+        )?)
+        .add_attributes(vec![
+            attr("action", "asset_decompounded"),
+            attr("id", id.to_string()),
+            attr("decompounded", decompounded),
+        ]))
+}
+
+/// Reports the failed forwarding of an isolated asset.
+/// What the hub received during the decompound is sent to the reward contract,
+/// so that nothing is left in the hub
+fn forward_failed_response(
+    deps: Deps,
+    env: &Env,
+    id: u64,
+    asset: &HubAsset,
+    lsd_asset: &AssetInfo,
+    balance_before: Uint128,
+    error: ContractError,
+) -> Result<Response, ContractError> {
+    let decompounded =
+        query_asset_balance(deps, &env.contract.address, lsd_asset)?.saturating_sub(balance_before);
+    let mut messages = vec![];
+    if !decompounded.is_zero() {
+        messages.push(transfer_asset_msg(
+            lsd_asset,
+            &asset.reward_contract,
+            decompounded,
+        )?);
+        messages.extend(follow_up_msgs(&asset.mode, &asset.reward_contract)?);
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        attr("action", "asset_decompound_failed"),
+        attr("id", id.to_string()),
+        attr("error", error.to_string()),
+        attr("decompounded", decompounded),
+    ]))
+}
+
+/// Returns the messages sent after the rewards are decompounded to the reward contract
+pub fn follow_up_msgs(mode: &HubMode, reward_contract: &Addr) -> StdResult<Vec<CosmosMsg>> {
+    match mode {
+        HubMode::SwapRewards => Ok(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: reward_contract.to_string(),
+            msg: to_binary(&SwapToRewardDenom {})?,
+            funds: vec![],
+        })]),
+        HubMode::DecompoundOnly => Ok(vec![]),
+        HubMode::Custom { msgs } => Ok(msgs
+            .iter()
+            .map(|msg| {
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: msg.contract_addr.clone(),
+                    msg: msg.msg.clone(),
+                    funds: vec![],
+                })
+            })
+            .collect()),
+    }
+}
+
+pub fn validate_mode(deps: Deps, mode: &HubMode) -> StdResult<()> {
+    if let HubMode::Custom { msgs } = mode {
+        for msg in msgs {
+            deps.api.addr_validate(&msg.contract_addr)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn query_asset(deps: Deps, id: u64) -> StdResult<AssetResponse> {
+    let asset = ASSETS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::AssetNotRegistered { id })?;
+    Ok(asset_response(id, asset))
+}
+
+pub fn query_assets(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<AssetsResponse> {
+    Ok(AssetsResponse {
+        assets: read_assets(deps, start_after, limit)?,
+    })
+}
+
+pub fn assert_owner(storage: &dyn Storage, info: &MessageInfo) -> Result<(), ContractError> {
+    if info.sender != CONFIG.load(storage)?.creator {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
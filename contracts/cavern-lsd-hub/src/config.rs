@@ -1,48 +1,156 @@
-use crate::state::CONFIG;
-use basset::hub::Config;
-use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, StdError, StdResult};
+use crate::assets::validate_mode;
+use crate::contract::forwards_rewards;
+use crate::state::{ASSETS, CONFIG, PARAMETERS};
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
+use basset::hub::{Config, HubMode, KeeperFee, Parameters, RewardSplit};
+use cosmwasm_std::{
+    attr, Addr, Decimal, Deps, DepsMut, Env, Order, Response, StdError, StdResult, Uint128,
+};
 
-/// Update the config. Update the owner, reward and token contracts.
-/// Only creator/owner is allowed to execute
+/// Update the config. Update the contracts, the reward splits, the keeper fee,
+/// the update parameters and the mode.
+/// Only creator/owner is allowed to execute, which is checked when the change is queued
+#[allow(clippy::too_many_arguments)]
 pub fn execute_update_config(
     deps: DepsMut,
     _env: Env,
-    info: MessageInfo,
-    owner: Option<String>,
     reward_contract: Option<String>,
     token_contract: Option<String>,
-) -> StdResult<Response> {
-    // only owner must be able to send this message.
-    let conf = CONFIG.load(deps.storage)?;
-    if info.sender != conf.creator {
-        return Err(StdError::generic_err("unauthorized"));
+    lsd_asset: Option<AssetInfo>,
+    reward_splits: Option<Vec<RewardSplit<String>>>,
+    keeper_fee: Option<KeeperFee>,
+    min_decompound_value: Option<Uint128>,
+    min_update_interval: Option<u64>,
+    mode: Option<HubMode>,
+) -> Result<Response, ContractError> {
+    if let Some(reward) = reward_contract {
+        let reward_raw = deps.api.addr_validate(reward.as_str())?;
+
+        CONFIG.update(deps.storage, |mut last_config| -> StdResult<Config> {
+            last_config.reward_contract = Some(reward_raw);
+            Ok(last_config)
+        })?;
     }
 
-    if let Some(o) = owner {
-        let owner_raw = deps.api.addr_validate(o.as_str())?;
+    if let Some(token) = token_contract {
+        let token_raw = deps.api.addr_validate(token.as_str())?;
 
         CONFIG.update(deps.storage, |mut last_config| -> StdResult<Config> {
-            last_config.creator = owner_raw;
+            last_config.token_contract = Some(token_raw);
             Ok(last_config)
         })?;
     }
-    if let Some(reward) = reward_contract {
-        let reward_raw = deps.api.addr_validate(reward.as_str())?;
+
+    if let Some(asset) = lsd_asset {
+        let asset = validate_asset(deps.as_ref(), asset)?;
 
         CONFIG.update(deps.storage, |mut last_config| -> StdResult<Config> {
-            last_config.reward_contract = Some(reward_raw);
+            last_config.lsd_asset = Some(asset);
             Ok(last_config)
         })?;
     }
 
-    if let Some(token) = token_contract {
-        let token_raw = deps.api.addr_validate(token.as_str())?;
+    if let Some(splits) = reward_splits {
+        let splits = validate_reward_splits(deps.as_ref(), splits)?;
 
         CONFIG.update(deps.storage, |mut last_config| -> StdResult<Config> {
-            last_config.token_contract = Some(token_raw);
+            last_config.reward_splits = splits;
+            Ok(last_config)
+        })?;
+    }
+
+    if let Some(fee) = keeper_fee {
+        if fee.ratio > Decimal::one() {
+            return Err(ContractError::Std(StdError::generic_err(
+                "the keeper fee ratio can't be more than 100%",
+            )));
+        }
+
+        CONFIG.update(deps.storage, |mut last_config| -> StdResult<Config> {
+            last_config.keeper_fee = Some(fee);
             Ok(last_config)
         })?;
     }
 
+    if let Some(min_decompound_value) = min_decompound_value {
+        PARAMETERS.update(deps.storage, |mut last_params| -> StdResult<Parameters> {
+            last_params.min_decompound_value = min_decompound_value;
+            Ok(last_params)
+        })?;
+    }
+
+    if let Some(min_update_interval) = min_update_interval {
+        PARAMETERS.update(deps.storage, |mut last_params| -> StdResult<Parameters> {
+            last_params.min_update_interval = min_update_interval;
+            Ok(last_params)
+        })?;
+    }
+
+    if let Some(mode) = mode {
+        validate_mode(deps.as_ref(), &mode)?;
+
+        CONFIG.update(deps.storage, |mut last_config| -> StdResult<Config> {
+            last_config.mode = mode;
+            Ok(last_config)
+        })?;
+    }
+
+    let conf = CONFIG.load(deps.storage)?;
+    if forwards_rewards(&conf) && conf.lsd_asset.is_none() {
+        return Err(ContractError::Std(StdError::generic_err(
+            "the lsd asset must be registered to forward the rewards",
+        )));
+    }
+    // The registered assets forward what they decompound to their own reward contract
+    if forwards_rewards(&conf)
+        && ASSETS
+            .range(deps.storage, None, None, Order::Ascending)
+            .any(|asset| matches!(asset, Ok((_, asset)) if asset.lsd_asset.is_none()))
+    {
+        return Err(ContractError::MissingLsdAsset {});
+    }
+
     Ok(Response::new().add_attributes(vec![attr("action", "update_config")]))
 }
+
+pub fn validate_asset(deps: Deps, asset: AssetInfo) -> StdResult<AssetInfo> {
+    Ok(match asset {
+        AssetInfo::Token { contract_addr } => AssetInfo::Token {
+            contract_addr: deps.api.addr_validate(contract_addr.as_str())?,
+        },
+        AssetInfo::NativeToken { denom } => AssetInfo::NativeToken { denom },
+    })
+}
+
+/// Validates the recipients of the splits and makes sure the shares sum to 100%.
+/// An empty list is valid and sends all the rewards to the reward contract
+fn validate_reward_splits(
+    deps: Deps,
+    splits: Vec<RewardSplit<String>>,
+) -> StdResult<Vec<RewardSplit<Addr>>> {
+    let mut validated: Vec<RewardSplit<Addr>> = vec![];
+    for split in splits {
+        let recipient = deps.api.addr_validate(&split.recipient)?;
+        if split.share.is_zero() {
+            return Err(StdError::generic_err("a reward share can't be zero"));
+        }
+        if validated.iter().any(|s| s.recipient == recipient) {
+            return Err(StdError::generic_err(format!(
+                "duplicate reward recipient {}",
+                recipient
+            )));
+        }
+        validated.push(RewardSplit {
+            recipient,
+            share: split.share,
+        });
+    }
+
+    let total_share: Decimal = validated.iter().map(|s| s.share).sum();
+    if !validated.is_empty() && total_share != Decimal::one() {
+        return Err(StdError::generic_err("the reward shares must sum to 100%"));
+    }
+
+    Ok(validated)
+}
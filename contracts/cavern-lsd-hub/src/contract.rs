@@ -1,21 +1,55 @@
 use basset::hub::Parameters;
 use cosmwasm_std::entry_point;
-use cosmwasm_std::Empty;
 use cosmwasm_std::{
-    attr, to_binary, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Response,
-    StdError, StdResult, WasmMsg,
+    attr, coins, to_binary, Addr, BankMsg, Binary, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env,
+    Event, MessageInfo, Reply, Response, StdError, StdResult, SubMsg, Uint128, WasmMsg,
 };
+use cw2::set_contract_version;
+use cw20::{BalanceResponse as Cw20BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+use std::str::FromStr;
 
-use crate::config::execute_update_config;
+use crate::assets::{
+    execute_asset_decompound_reply, follow_up_msgs, query_asset, query_assets, register_assets,
+    registered_asset_ids, update_registered_assets, validate_mode,
+};
+use crate::health::query_health;
+use crate::hooks::{execute_hook_failed, hub_updated_hook_msgs, query_hooks};
+use crate::ownership::{execute_claim_ownership, execute_drop_ownership_proposal};
+use crate::pause::{execute_pause, execute_unpause};
+use crate::simulate::query_simulate_update_global_index;
+use crate::timelock::{
+    execute_cancel_config_change, execute_config_change, execute_queue_config_change,
+    execute_revoke_role,
+};
 
-use crate::state::{CONFIG, PARAMETERS, STATE};
+use crate::state::{
+    ACCESS, BALANCE_BEFORE_DECOMPOUND, CONFIG, KEEPER, OWNERSHIP, PARAMETERS, PAUSE, STATE,
+    TIMELOCK,
+};
 
 use basset::hub::{
-    Config, ConfigResponse, ExecuteMsg, OldInstantiateMsg, QueryMsg, State, StateResponse,
+    Config, ConfigResponse, ExecuteMsg, HubMode, MigrateMsg, OldInstantiateMsg, QueryMsg,
+    RewardSplit, State, StateResponse,
+};
+
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
+use basset::pause::Operation;
+use basset::version::{migrate_version, LEGACY_VERSION};
+use basset::wrapper::{
+    AnyTokenInfoResponse, ExecuteMsg as LSDWrapperExecuteMsg, PendingDecompoundResponse,
+    QueryMsg as LSDWrapperQueryMsg,
 };
 
-use basset::wrapper::ExecuteMsg as LSDWrapperExecuteMsg;
-use basset::reward::ExecuteMsg::SwapToRewardDenom;
+pub const DECOMPOUND_REPLY_ID: u64 = 1;
+pub const HOOK_REPLY_ID: u64 = 3;
+/// The replies of the registered assets carry their id on top of this one
+pub const ASSET_DECOMPOUND_REPLY_ID: u64 = 1 << 32;
+/// Offset of the reply ids of the registered assets updated in isolation
+pub const ISOLATED_ASSET_DECOMPOUND_REPLY_ID: u64 = 1 << 33;
+
+pub const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -23,13 +57,18 @@ pub fn instantiate(
     env: Env,
     info: MessageInfo,
     msg: OldInstantiateMsg,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     // store config
     let data = Config {
         creator: info.sender,
         reward_contract: None,
         token_contract: None,
+        lsd_asset: None,
+        reward_splits: vec![],
+        keeper_fee: None,
+        mode: msg.mode.unwrap_or_default(),
     };
+    validate_mode(deps.as_ref(), &data.mode)?;
     CONFIG.save(deps.storage, &data)?;
 
     // store state
@@ -44,63 +83,161 @@ pub fn instantiate(
     // instantiate parameters
     let params = Parameters {
         reward_denom: msg.reward_denom,
+        min_decompound_value: Uint128::zero(),
+        min_update_interval: 0,
     };
 
     PARAMETERS.save(deps.storage, &params)?;
 
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     Ok(Response::new())
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::UpdateGlobalIndex {} => execute_update_global(deps, env),
+        ExecuteMsg::UpdateGlobalIndex { ids } => execute_update_global(deps, env, info, ids),
         // No need to check whether slashing has happened in our case.
         /*
         ExecuteMsg::CheckSlashing {} => execute_slashing(deps, env),
         */
-        ExecuteMsg::UpdateConfig {
-            owner,
-            reward_contract,
-            token_contract,
-        } => execute_update_config(deps, env, info, owner, reward_contract, token_contract),
+        ExecuteMsg::UpdateConfig { .. }
+        | ExecuteMsg::UpdateTimelock { .. }
+        | ExecuteMsg::GrantRole { .. }
+        | ExecuteMsg::ProposeNewOwner { .. }
+        | ExecuteMsg::RegisterAssets { .. }
+        | ExecuteMsg::DeregisterAsset { .. }
+        | ExecuteMsg::AddHook { .. }
+        | ExecuteMsg::RemoveHook { .. } => execute_queue_config_change(deps, env, info, msg),
+        ExecuteMsg::ExecuteConfigChange { id } => execute_config_change(deps, env, info, id),
+        ExecuteMsg::CancelConfigChange { id } => execute_cancel_config_change(deps, info, id),
+        ExecuteMsg::RevokeRole { role, addr } => execute_revoke_role(deps, info, role, addr),
+        ExecuteMsg::DropOwnershipProposal {} => execute_drop_ownership_proposal(deps, info),
+        ExecuteMsg::ClaimOwnership {} => execute_claim_ownership(deps, env, info),
+        ExecuteMsg::Pause { operations } => execute_pause(deps, info, operations),
+        ExecuteMsg::Unpause { operations } => execute_unpause(deps, info, operations),
     }
 }
 
 /// Update general parameters
-/// Permissionless
+/// Only the keepers can call this once one is granted, anyone can before.
+/// Fails while the updates are paused.
+/// Without ids, the primary asset and all the registered assets are updated
 pub fn execute_update_global(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    ids: Option<Vec<u64>>,
+) -> Result<Response, ContractError> {
+    PAUSE.assert_not_paused(deps.storage, Operation::Update)?;
+    let owner = CONFIG.load(deps.storage)?.creator;
+    ACCESS.assert_keeper(deps.storage, &info.sender, &owner)?;
+
+    let sender = info.sender.clone();
+    // Only the update of all the assets goes on when one of them fails
+    let isolate = ids.is_none();
+    let (res, ids) = match ids {
+        Some(ids) => (
+            Response::new().add_attributes(vec![attr("action", "update_global_index")]),
+            ids,
+        ),
+        None => {
+            let config = CONFIG.load(deps.storage)?;
+            let ids = registered_asset_ids(deps.storage)?;
+            // A hub without any asset still complains about the missing primary contracts
+            let res = if config.token_contract.is_some() || ids.is_empty() {
+                execute_update_primary(deps.branch(), env.clone(), info)?
+            } else {
+                Response::new().add_attributes(vec![attr("action", "update_global_index")])
+            };
+            (res, ids)
+        }
+    };
+
+    let (messages, attributes) = update_registered_assets(deps, &env, &sender, ids, isolate)?;
+    Ok(res.add_submessages(messages).add_attributes(attributes))
+}
+
+/// Updates the asset set in the config.
+/// The caller is paid the keeper fee if one is configured
+fn execute_update_primary(
     deps: DepsMut,
     env: Env,
+    info: MessageInfo,
     //airdrop_hooks: Option<Vec<Binary>>,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
+    let last_index_modification = STATE.load(deps.storage)?.last_index_modification;
+    let (min_interval, min_decompound) = update_guards(&config, &PARAMETERS.load(deps.storage)?);
+    if env.block.time.seconds() < last_index_modification + min_interval {
+        return Err(ContractError::UpdateTooOften {});
+    }
     let reward_addr = config
         .reward_contract
         .clone()
-        .ok_or_else(|| StdError::generic_err("the reward contract must have been registered"))?
-        .to_string();
+        .ok_or(ContractError::MissingRewardContract {})?;
 
     let lsd_wrapper_contract = config
         .token_contract
-        .ok_or_else(|| StdError::generic_err("the token contract must have been registered"))?
+        .clone()
+        .ok_or(ContractError::MissingTokenContract {})?
         .to_string();
 
-    // Send decompound message so that LSD rewards get taken out of the token if they exist
-    let mut messages = vec![CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: lsd_wrapper_contract,
-        msg: to_binary(&LSDWrapperExecuteMsg::Decompound {
-            recipient: Some(reward_addr.clone()),
-        })?,
-        funds: vec![],
-    })];
-
-    // Send Swap message to reward contract
-    messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: reward_addr,
-        msg: to_binary(&SwapToRewardDenom {}).unwrap(),
-        funds: vec![],
-    }));
+    // Nothing is sent when there is not enough to decompound, to save gas
+    if !min_decompound.is_zero() {
+        let pending: PendingDecompoundResponse = deps.querier.query_wasm_smart(
+            lsd_wrapper_contract.clone(),
+            &LSDWrapperQueryMsg::PendingDecompound {},
+        )?;
+        if pending.lsd_rewards < min_decompound {
+            return Ok(Response::new().add_attributes(vec![
+                attr("action", "update_global_index"),
+                attr("skipped", "pending_decompound_below_minimum"),
+                attr("pending_decompound", pending.lsd_rewards),
+            ]));
+        }
+    }
+
+    let messages = if !forwards_rewards(&config) {
+        // Send decompound message so that LSD rewards get taken out of the token if they exist
+        // The rewards are handled and the state is updated in the reply
+        vec![SubMsg::reply_on_success(
+            WasmMsg::Execute {
+                contract_addr: lsd_wrapper_contract,
+                msg: to_binary(&LSDWrapperExecuteMsg::<Empty>::Decompound {
+                    recipient: Some(reward_addr.to_string()),
+                })?,
+                funds: vec![],
+            },
+            DECOMPOUND_REPLY_ID,
+        )]
+    } else {
+        // The rewards are decompounded to the hub, and the shares are forwarded in the reply.
+        // Only what the hub receives in between is forwarded
+        let lsd_asset = config
+            .lsd_asset
+            .as_ref()
+            .ok_or(ContractError::MissingLsdAsset {})?;
+        let balance = query_asset_balance(deps.as_ref(), &env.contract.address, lsd_asset)?;
+        BALANCE_BEFORE_DECOMPOUND.save(deps.storage, &balance)?;
+        KEEPER.save(deps.storage, &info.sender)?;
+        vec![SubMsg::reply_on_success(
+            WasmMsg::Execute {
+                contract_addr: lsd_wrapper_contract,
+                msg: to_binary(&LSDWrapperExecuteMsg::<Empty>::Decompound {
+                    recipient: Some(env.contract.address.to_string()),
+                })?,
+                funds: vec![],
+            },
+            DECOMPOUND_REPLY_ID,
+        )]
+    };
 
     //update state last modified
     STATE.update(deps.storage, |mut last_state| -> StdResult<State> {
@@ -109,16 +246,299 @@ pub fn execute_update_global(
     })?;
 
     Ok(Response::new()
-        .add_messages(messages)
+        .add_submessages(messages)
         .add_attributes(vec![attr("action", "update_global_index")]))
 }
 
+/// The minimum interval and decompound of the parameters,
+/// tightened by the keeper fee ones when a fee is configured
+pub fn update_guards(config: &Config, params: &Parameters) -> (u64, Uint128) {
+    match &config.keeper_fee {
+        Some(fee) => (
+            fee.min_interval.max(params.min_update_interval),
+            fee.min_decompound.max(params.min_decompound_value),
+        ),
+        None => (params.min_update_interval, params.min_decompound_value),
+    }
+}
+
+/// The decompounded rewards go through the hub when they are split or when a keeper fee is paid
+pub fn forwards_rewards(config: &Config) -> bool {
+    !config.reward_splits.is_empty()
+        || config
+            .keeper_fee
+            .as_ref()
+            .map_or(false, |fee| !fee.ratio.is_zero())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        DECOMPOUND_REPLY_ID => execute_decompound_reply(deps, env, msg),
+        HOOK_REPLY_ID => execute_hook_failed(msg),
+        id if id >= ISOLATED_ASSET_DECOMPOUND_REPLY_ID => execute_asset_decompound_reply(
+            deps,
+            env,
+            id - ISOLATED_ASSET_DECOMPOUND_REPLY_ID,
+            true,
+            msg,
+        ),
+        id if id >= ASSET_DECOMPOUND_REPLY_ID => {
+            execute_asset_decompound_reply(deps, env, id - ASSET_DECOMPOUND_REPLY_ID, false, msg)
+        }
+        _ => Err(StdError::generic_err("unknown reply id").into()),
+    }
+}
+
+/// Forwards the decompounded rewards if needed and lets the reward contract handle them,
+/// then records the decompounded amount and the wrapper token info in the state.
+/// The hooks are notified last
+fn execute_decompound_reply(
+    mut deps: DepsMut,
+    env: Env,
+    msg: Reply,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let lsd_wrapper_contract = config
+        .token_contract
+        .clone()
+        .ok_or(ContractError::MissingTokenContract {})?;
+
+    let (res, decompounded) = if forwards_rewards(&config) {
+        let lsd_asset = config
+            .lsd_asset
+            .clone()
+            .ok_or(ContractError::MissingLsdAsset {})?;
+        let reward_contract = config
+            .reward_contract
+            .clone()
+            .ok_or(ContractError::MissingRewardContract {})?;
+        let balance_before = BALANCE_BEFORE_DECOMPOUND
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+        execute_forward_rewards(
+            deps.branch(),
+            &env,
+            &config,
+            &lsd_asset,
+            &reward_contract,
+            &config.mode,
+            balance_before,
+        )?
+    } else {
+        let response = msg.result.into_result().map_err(StdError::generic_err)?;
+        let mut messages = vec![];
+        if let Some(reward_contract) = &config.reward_contract {
+            messages.extend(follow_up_msgs(&config.mode, reward_contract)?);
+        }
+        (
+            Response::new().add_messages(messages),
+            decompounded_amount(&response.events, &lsd_wrapper_contract)?,
+        )
+    };
+
+    let token_info: AnyTokenInfoResponse = deps.querier.query_wasm_smart(
+        lsd_wrapper_contract.clone(),
+        &LSDWrapperQueryMsg::TokenInfo {},
+    )?;
+
+    let state = STATE.update(deps.storage, |mut state| -> StdResult<State> {
+        state.exchange_rate = token_info.exchange_rate;
+        state.total_bond_amount = token_info.total_supply * token_info.exchange_rate;
+        state.prev_hub_balance = decompounded;
+        state.total_decompounded += decompounded;
+        Ok(state)
+    })?;
+
+    let hook_messages =
+        hub_updated_hook_msgs(deps.as_ref(), &env, &lsd_wrapper_contract, decompounded)?;
+    Ok(res.add_submessages(hook_messages).add_attributes(vec![
+        attr("decompounded", decompounded),
+        attr("exchange_rate", state.exchange_rate.to_string()),
+        attr("total_decompounded", state.total_decompounded),
+    ]))
+}
+
+/// Reads the LSD rewards reported by the wrapper in its decompound events.
+/// Wrappers that don't report them are considered to have decompounded nothing
+pub fn decompounded_amount(events: &[Event], lsd_wrapper_contract: &Addr) -> StdResult<Uint128> {
+    events
+        .iter()
+        .filter(|event| {
+            event.ty == "wasm"
+                && event.attributes.iter().any(|attribute| {
+                    attribute.key == "_contract_address"
+                        && attribute.value == lsd_wrapper_contract.as_str()
+                })
+        })
+        .flat_map(|event| event.attributes.iter())
+        .find(|attribute| attribute.key == "lsd_rewards")
+        .map(|attribute| Uint128::from_str(&attribute.value))
+        .unwrap_or_else(|| Ok(Uint128::zero()))
+}
+
+/// Pays the keeper fee out of the decompounded rewards received by the hub
+/// and forwards the rest to the recipients of the splits.
+/// Any LSD the hub held before the decompound is left untouched.
+/// Returns the amount that was decompounded
+pub fn execute_forward_rewards(
+    deps: DepsMut,
+    env: &Env,
+    config: &Config,
+    lsd_asset: &AssetInfo,
+    reward_contract: &Addr,
+    mode: &HubMode,
+    balance_before: Uint128,
+) -> Result<(Response, Uint128), ContractError> {
+    let decompounded = query_asset_balance(deps.as_ref(), &env.contract.address, lsd_asset)?
+        .saturating_sub(balance_before);
+    let mut balance = decompounded;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut events: Vec<Event> = vec![];
+    if let Some(fee) = &config.keeper_fee {
+        if decompounded < fee.min_decompound {
+            return Err(ContractError::NotEnoughRewards {
+                value: decompounded,
+                min: fee.min_decompound,
+            });
+        }
+        let keeper = KEEPER.load(deps.storage)?;
+        let keeper_amount = std::cmp::min(decompounded * fee.ratio, fee.cap);
+        if !keeper_amount.is_zero() {
+            messages.push(transfer_asset_msg(lsd_asset, &keeper, keeper_amount)?);
+            events.push(
+                Event::new("keeper_fee")
+                    .add_attribute("keeper", keeper.to_string())
+                    .add_attribute("amount", keeper_amount),
+            );
+        }
+        balance -= keeper_amount;
+    }
+
+    // Without any split, everything goes to the reward contract.
+    // The share of the reward contract in the config goes to the one of the asset
+    let splits = if config.reward_splits.is_empty() {
+        vec![RewardSplit {
+            recipient: reward_contract.clone(),
+            share: Decimal::one(),
+        }]
+    } else {
+        config
+            .reward_splits
+            .iter()
+            .map(|split| RewardSplit {
+                recipient: if config.reward_contract.as_ref() == Some(&split.recipient) {
+                    reward_contract.clone()
+                } else {
+                    split.recipient.clone()
+                },
+                share: split.share,
+            })
+            .collect()
+    };
+
+    let mut distributed = Uint128::zero();
+    let last_index = splits.len().saturating_sub(1);
+    for (index, split) in splits.iter().enumerate() {
+        // The last recipient gets the rounding leftovers
+        let amount = if index == last_index {
+            balance - distributed
+        } else {
+            balance * split.share
+        };
+        distributed += amount;
+        if amount.is_zero() {
+            continue;
+        }
+        messages.push(transfer_asset_msg(lsd_asset, &split.recipient, amount)?);
+        events.push(
+            Event::new("reward_split")
+                .add_attribute("recipient", split.recipient.to_string())
+                .add_attribute("share", split.share.to_string())
+                .add_attribute("amount", amount),
+        );
+    }
+
+    // The reward contract handles the rewards it received depending on the mode
+    messages.extend(follow_up_msgs(mode, reward_contract)?);
+
+    Ok((
+        Response::new()
+            .add_messages(messages)
+            .add_events(events)
+            .add_attributes(vec![
+                attr("action", "forward_rewards"),
+                attr("amount", decompounded),
+            ]),
+        decompounded,
+    ))
+}
+
+pub fn query_asset_balance(deps: Deps, address: &Addr, asset: &AssetInfo) -> StdResult<Uint128> {
+    match asset {
+        AssetInfo::NativeToken { denom } => Ok(deps
+            .querier
+            .query_balance(address.to_string(), denom.to_string())?
+            .amount),
+        AssetInfo::Token { contract_addr } => {
+            let balance: Cw20BalanceResponse = deps.querier.query_wasm_smart(
+                contract_addr.to_string(),
+                &Cw20QueryMsg::Balance {
+                    address: address.to_string(),
+                },
+            )?;
+            Ok(balance.balance)
+        }
+    }
+}
+
+pub fn transfer_asset_msg(
+    asset: &AssetInfo,
+    recipient: &Addr,
+    amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    Ok(match asset {
+        AssetInfo::NativeToken { denom } => CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(amount.u128(), denom),
+        }),
+        AssetInfo::Token { contract_addr } => CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }),
+    })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
         QueryMsg::State {} => to_binary(&query_state(deps)?),
         QueryMsg::Parameters {} => to_binary(&query_params(deps)?),
+        QueryMsg::Asset { id } => to_binary(&query_asset(deps, id)?),
+        QueryMsg::Assets { start_after, limit } => {
+            to_binary(&query_assets(deps, start_after, limit)?)
+        }
+        QueryMsg::Hooks {} => to_binary(&query_hooks(deps)?),
+        QueryMsg::SimulateUpdateGlobalIndex {} => {
+            to_binary(&query_simulate_update_global_index(deps, env)?)
+        }
+        QueryMsg::Health {} => to_binary(&query_health(deps, env)?),
+        QueryMsg::Timelock {} => to_binary(&TIMELOCK.query_config(deps.storage)?),
+        QueryMsg::PendingOwner {} => to_binary(&OWNERSHIP.query_pending(deps.storage)?),
+        QueryMsg::RoleHolders { role } => {
+            let owner = CONFIG.load(deps.storage)?.creator;
+            to_binary(&ACCESS.query_holders(deps.storage, role, &owner)?)
+        }
+        QueryMsg::PauseStatus {} => to_binary(&PAUSE.query_status(deps.storage)?),
+        QueryMsg::PendingChanges { start_after, limit } => {
+            to_binary(&TIMELOCK.query_pending(deps.storage, start_after, limit)?)
+        }
     }
 }
 
@@ -137,6 +557,17 @@ fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         owner: config.creator.to_string(),
         reward_contract: reward,
         token_contract: token,
+        lsd_asset: config.lsd_asset,
+        reward_splits: config
+            .reward_splits
+            .into_iter()
+            .map(|split| RewardSplit {
+                recipient: split.recipient.to_string(),
+                share: split.share,
+            })
+            .collect(),
+        keeper_fee: config.keeper_fee,
+        mode: config.mode,
         //airdrop_registry_contract: airdrop,
     })
 }
@@ -148,6 +579,7 @@ fn query_state(deps: Deps) -> StdResult<StateResponse> {
         total_bond_amount: state.total_bond_amount,
         last_index_modification: state.last_index_modification,
         prev_hub_balance: state.prev_hub_balance,
+        total_decompounded: state.total_decompounded,
     };
     Ok(res)
 }
@@ -157,6 +589,27 @@ fn query_params(deps: Deps) -> StdResult<Parameters> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: Empty) -> StdResult<Response> {
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let previous = migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, &[])?;
+
+    // Decompound-only hubs predate the versioning, didn't store any parameters
+    // and never swapped their rewards
+    if previous.version == LEGACY_VERSION && PARAMETERS.may_load(deps.storage)?.is_none() {
+        PARAMETERS.save(
+            deps.storage,
+            &Parameters {
+                reward_denom: msg.reward_denom.unwrap_or_default(),
+                min_decompound_value: Uint128::zero(),
+                min_update_interval: 0,
+            },
+        )?;
+        CONFIG.update(deps.storage, |mut config| -> StdResult<Config> {
+            config.mode = HubMode::DecompoundOnly;
+            Ok(config)
+        })?;
+    }
+
+    // The pairs of other hubs can be moved to this one while migrating it
+    let attributes = register_assets(deps, &env, msg.register_assets)?;
+    Ok(Response::default().add_attributes(attributes))
 }
@@ -0,0 +1,134 @@
+use crate::state::{ASSETS, CONFIG};
+use basset::contract_error::ContractError;
+use basset::hub::{HealthFinding, HealthResponse};
+use basset::reward::{QueryMsg as RewardQueryMsg, RewardDistributionMode};
+use basset::wrapper::{
+    AnyTokenInfoResponse, QueryMsg as LSDWrapperQueryMsg, HUB_CONTRACT_STORAGE_KEY,
+};
+use cosmwasm_std::{from_slice, Addr, Deps, Env, Order, StdResult};
+use serde::Deserialize;
+
+/// Fields of the reward contract config checked by the hub, the others are ignored
+#[derive(Deserialize)]
+struct RewardConfig {
+    hub_contract: Addr,
+    custody_contract: Option<Addr>,
+    #[serde(default)]
+    distribution_mode: RewardDistributionMode,
+}
+
+/// Cross-checks the configuration of the primary asset and the registered ones
+pub fn query_health(deps: Deps, env: Env) -> StdResult<HealthResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut findings = vec![];
+
+    let has_assets = ASSETS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    match (config.token_contract, config.reward_contract) {
+        (Some(wrapper), Some(reward_contract)) => {
+            findings.extend(check_asset(deps, &env, &wrapper, &reward_contract)?)
+        }
+        // A hub only updating registered assets doesn't need the primary contracts
+        (None, _) if has_assets => {}
+        (wrapper, reward_contract) => {
+            if wrapper.is_none() {
+                findings.push(HealthFinding::MissingContract {
+                    contract: "token_contract".to_string(),
+                });
+            }
+            if reward_contract.is_none() {
+                findings.push(HealthFinding::MissingContract {
+                    contract: "reward_contract".to_string(),
+                });
+            }
+        }
+    }
+
+    for asset in ASSETS.range(deps.storage, None, None, Order::Ascending) {
+        let (_, asset) = asset?;
+        findings.extend(check_asset(
+            deps,
+            &env,
+            &asset.token_contract,
+            &asset.reward_contract,
+        )?);
+    }
+
+    Ok(HealthResponse {
+        healthy: findings.is_empty(),
+        findings,
+    })
+}
+
+fn check_asset(
+    deps: Deps,
+    env: &Env,
+    wrapper: &Addr,
+    reward_contract: &Addr,
+) -> StdResult<Vec<HealthFinding>> {
+    let mut findings = vec![];
+
+    let wrapper_hub: Option<Addr> = deps
+        .querier
+        .query_wasm_raw(wrapper.to_string(), HUB_CONTRACT_STORAGE_KEY.as_bytes())?
+        .map(|hub| from_slice(&hub))
+        .transpose()?;
+    if wrapper_hub.as_ref() != Some(&env.contract.address) {
+        findings.push(HealthFinding::WrapperHubMismatch {
+            wrapper: wrapper.to_string(),
+            hub: wrapper_hub.map(|hub| hub.to_string()),
+        });
+    }
+
+    // The exchange rate of the wrapper depends on an oracle for some underlying tokens
+    let token_info: StdResult<AnyTokenInfoResponse> = deps
+        .querier
+        .query_wasm_smart(wrapper.to_string(), &LSDWrapperQueryMsg::TokenInfo {});
+    match token_info {
+        Ok(token_info) => {
+            if !token_info.total_supply.is_zero() && token_info.exchange_rate.is_zero() {
+                findings.push(HealthFinding::ZeroBacking {
+                    wrapper: wrapper.to_string(),
+                });
+            }
+        }
+        Err(err) if ContractError::is_stale_oracle(&err.to_string()) => {
+            findings.push(HealthFinding::StaleOracle {
+                wrapper: wrapper.to_string(),
+            })
+        }
+        Err(err) => findings.push(HealthFinding::WrapperQueryFailed {
+            wrapper: wrapper.to_string(),
+            error: err.to_string(),
+        }),
+    }
+
+    let reward_config: StdResult<RewardConfig> = deps
+        .querier
+        .query_wasm_smart(reward_contract.to_string(), &RewardQueryMsg::Config {});
+    match reward_config {
+        Ok(reward_config) => {
+            if reward_config.hub_contract != env.contract.address {
+                findings.push(HealthFinding::RewardHubMismatch {
+                    reward_contract: reward_contract.to_string(),
+                    hub: reward_config.hub_contract.to_string(),
+                });
+            }
+            if reward_config.distribution_mode == RewardDistributionMode::Custody
+                && reward_config.custody_contract.is_none()
+            {
+                findings.push(HealthFinding::MissingCustody {
+                    reward_contract: reward_contract.to_string(),
+                });
+            }
+        }
+        Err(err) => findings.push(HealthFinding::RewardQueryFailed {
+            reward_contract: reward_contract.to_string(),
+            error: err.to_string(),
+        }),
+    }
+
+    Ok(findings)
+}
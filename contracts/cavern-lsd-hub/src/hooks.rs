@@ -0,0 +1,73 @@
+use crate::contract::HOOK_REPLY_ID;
+use crate::state::HOOKS;
+use basset::contract_error::ContractError;
+use basset::hooks::{hook_failed_response, hook_msgs};
+use basset::hub::HubUpdatedHookMsg;
+use basset::wrapper::HooksResponse;
+
+use cosmwasm_std::{
+    attr, to_binary, Addr, Deps, DepsMut, Env, Reply, Response, StdError, StdResult, SubMsg,
+    Uint128,
+};
+
+/// Adds a hook, queued like the config updates
+pub fn execute_add_hook(deps: DepsMut, addr: String) -> Result<Response, ContractError> {
+    let hook = deps.api.addr_validate(&addr)?;
+    let mut hooks = HOOKS.may_load(deps.storage)?.unwrap_or_default();
+    if hooks.contains(&hook) {
+        return Err(ContractError::Std(StdError::generic_err(
+            "Given address already registered as a hook",
+        )));
+    }
+    hooks.push(hook.clone());
+    HOOKS.save(deps.storage, &hooks)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "add_hook"), attr("hook", hook)]))
+}
+
+/// Removes a hook, queued like the config updates
+pub fn execute_remove_hook(deps: DepsMut, addr: String) -> Result<Response, ContractError> {
+    let hook = deps.api.addr_validate(&addr)?;
+    let mut hooks = HOOKS.may_load(deps.storage)?.unwrap_or_default();
+    let position = hooks
+        .iter()
+        .position(|h| *h == hook)
+        .ok_or_else(|| StdError::generic_err("Given address not registered as a hook"))?;
+    hooks.remove(position);
+    HOOKS.save(deps.storage, &hooks)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "remove_hook"), attr("hook", hook)]))
+}
+
+pub fn query_hooks(deps: Deps) -> StdResult<HooksResponse> {
+    let hooks = HOOKS.may_load(deps.storage)?.unwrap_or_default();
+    Ok(HooksResponse {
+        hooks: hooks.into_iter().map(|h| h.to_string()).collect(),
+    })
+}
+
+/// Creates the messages notifying every hook that the rewards of `token` were handled.
+/// A failing hook doesn't revert the update
+pub fn hub_updated_hook_msgs(
+    deps: Deps,
+    env: &Env,
+    token: &Addr,
+    decompounded: Uint128,
+) -> StdResult<Vec<SubMsg>> {
+    let hooks = HOOKS.may_load(deps.storage)?.unwrap_or_default();
+    if hooks.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let msg = to_binary(&HubUpdatedHookMsg::HubUpdated {
+        token: token.to_string(),
+        decompounded,
+        timestamp: env.block.time.seconds(),
+    })?;
+    Ok(hook_msgs(hooks, &msg, HOOK_REPLY_ID))
+}
+
+/// The error of a failing hook is only reported
+pub fn execute_hook_failed(msg: Reply) -> Result<Response, ContractError> {
+    Ok(hook_failed_response(msg))
+}
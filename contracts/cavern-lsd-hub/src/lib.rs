@@ -1,7 +1,14 @@
 pub mod contract;
 pub mod state;
 
+mod assets;
 mod config;
+mod health;
+mod hooks;
+mod ownership;
+mod pause;
+mod simulate;
+mod timelock;
 
 #[cfg(test)]
 mod testing;
@@ -0,0 +1,136 @@
+use crate::contract::{forwards_rewards, update_guards};
+use crate::state::{read_assets, CONFIG, PARAMETERS, STATE};
+use basset::contract_error::ContractError;
+use basset::hub::{
+    Config, HubAsset, HubMode, Parameters, SimulateAssetUpdateResponse,
+    SimulateUpdateGlobalIndexResponse,
+};
+use basset::reward::{Asset, QueryMsg as RewardQueryMsg, SimulateSwapResponse};
+use basset::wrapper::{PendingDecompoundResponse, QueryMsg as LSDWrapperQueryMsg};
+use cosmwasm_std::{Addr, Deps, Env, StdResult, Uint128};
+
+// settings for pagination
+const MAX_ASSETS: u32 = 30;
+
+/// Dry-runs UpdateGlobalIndex on the asset set in the config and on the first 30 registered assets.
+/// The guards that would refuse or skip the update are reported instead of failing
+pub fn query_simulate_update_global_index(
+    deps: Deps,
+    env: Env,
+) -> StdResult<SimulateUpdateGlobalIndexResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let params = PARAMETERS.load(deps.storage)?;
+    let primary = HubAsset {
+        token_contract: config
+            .token_contract
+            .clone()
+            .ok_or(ContractError::MissingTokenContract {})?,
+        reward_contract: config
+            .reward_contract
+            .clone()
+            .ok_or(ContractError::MissingRewardContract {})?,
+        mode: config.mode.clone(),
+        last_index_modification: STATE.load(deps.storage)?.last_index_modification,
+        lsd_asset: config.lsd_asset.clone(),
+    };
+    let simulation = simulate_asset_update(deps, &env, &config, &params, &primary)?;
+
+    let assets = read_assets(deps, None, Some(MAX_ASSETS))?
+        .into_iter()
+        .map(|asset| {
+            let id = asset.id;
+            let asset = HubAsset {
+                token_contract: Addr::unchecked(asset.token_contract),
+                reward_contract: Addr::unchecked(asset.reward_contract),
+                mode: asset.mode,
+                last_index_modification: asset.last_index_modification,
+                lsd_asset: asset.lsd_asset,
+            };
+            let simulation = simulate_asset_update(deps, &env, &config, &params, &asset)?;
+            Ok(SimulateAssetUpdateResponse {
+                id,
+                lsd_to_decompound: simulation.lsd_to_decompound,
+                keeper_fee: simulation.keeper_fee,
+                lsd_to_reward_contract: simulation.lsd_to_reward_contract,
+                swap: simulation.swap,
+                blocked_by: simulation.blocked_by,
+            })
+        })
+        .collect::<StdResult<Vec<SimulateAssetUpdateResponse>>>()?;
+
+    Ok(SimulateUpdateGlobalIndexResponse {
+        assets,
+        ..simulation
+    })
+}
+
+/// Dry-runs the update of a single asset, its rewards are forwarded like in the decompound reply
+fn simulate_asset_update(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    params: &Parameters,
+    asset: &HubAsset,
+) -> StdResult<SimulateUpdateGlobalIndexResponse> {
+    let pending: PendingDecompoundResponse = deps.querier.query_wasm_smart(
+        asset.token_contract.to_string(),
+        &LSDWrapperQueryMsg::PendingDecompound {},
+    )?;
+    let lsd_to_decompound = pending.lsd_rewards;
+
+    // The guards are checked in the same order as during the update
+    let (min_interval, min_decompound) = update_guards(config, params);
+    let blocked_by = if env.block.time.seconds() < asset.last_index_modification + min_interval {
+        Some(ContractError::UpdateTooOften {}.to_string())
+    } else if lsd_to_decompound < min_decompound {
+        Some(format!(
+            "the pending decompound is below the minimum: {} < {}",
+            lsd_to_decompound, min_decompound
+        ))
+    } else {
+        None
+    };
+
+    // The rewards only go through the hub when the decompounded LSD is known
+    let forwarded = forwards_rewards(config) && asset.lsd_asset.is_some();
+    let keeper_fee = match &config.keeper_fee {
+        Some(fee) if forwarded => std::cmp::min(lsd_to_decompound * fee.ratio, fee.cap),
+        _ => Uint128::zero(),
+    };
+    let lsd_to_reward_contract = if !forwarded || config.reward_splits.is_empty() {
+        lsd_to_decompound - keeper_fee
+    } else {
+        config
+            .reward_splits
+            .iter()
+            .filter(|split| config.reward_contract.as_ref() == Some(&split.recipient))
+            .map(|split| (lsd_to_decompound - keeper_fee) * split.share)
+            .sum()
+    };
+
+    let swap = if asset.mode == HubMode::SwapRewards {
+        let incoming = match &asset.lsd_asset {
+            Some(asset_info) if !lsd_to_reward_contract.is_zero() => vec![Asset {
+                amount: lsd_to_reward_contract,
+                asset_info: asset_info.clone(),
+            }],
+            _ => vec![],
+        };
+        let simulation: SimulateSwapResponse = deps.querier.query_wasm_smart(
+            asset.reward_contract.to_string(),
+            &RewardQueryMsg::SimulateSwap { incoming },
+        )?;
+        Some(simulation)
+    } else {
+        None
+    };
+
+    Ok(SimulateUpdateGlobalIndexResponse {
+        lsd_to_decompound,
+        keeper_fee,
+        lsd_to_reward_contract,
+        swap,
+        blocked_by,
+        assets: vec![],
+    })
+}
@@ -1,8 +1,65 @@
 
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Deps, Order, StdResult, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
 
-use basset::hub::{Config, State, Parameters};
+use basset::access::AccessControl;
+use basset::hub::{AssetResponse, Config, ExecuteMsg, HubAsset, Parameters, State};
+use basset::ownership::Ownership;
+use basset::pause::{Operation, PauseFlags};
+use basset::timelock::Timelock;
 
 pub const CONFIG: Item<Config> = Item::new("\u{0}\u{6}config");
 pub const PARAMETERS: Item<Parameters> = Item::new("\u{0}\u{b}parameteres");
 pub const STATE: Item<State> = Item::new("\u{0}\u{5}state");
+/// Caller of the ongoing global index update, paid in the decompound reply
+pub const KEEPER: Item<Addr> = Item::new("keeper");
+/// LSD balance of the hub before the ongoing decompound, deducted from the forwarded rewards
+pub const BALANCE_BEFORE_DECOMPOUND: Item<Uint128> = Item::new("balance_before_decompound");
+/// Same for the registered assets being decompounded to the hub, by id
+pub const ASSET_BALANCES_BEFORE: Map<u64, Uint128> = Map::new("asset_balances_before");
+/// Wrapper and reward contract pairs updated on top of the primary one, by id
+pub const ASSETS: Map<u64, HubAsset> = Map::new("assets");
+pub const NEXT_ASSET_ID: Item<u64> = Item::new("next_asset_id");
+/// Contracts notified after every global index update
+pub const HOOKS: Item<Vec<Addr>> = Item::new("hooks");
+/// Config changes delayed by the timelock
+pub const TIMELOCK: Timelock<ExecuteMsg> =
+    Timelock::new("timelock", "pending_changes", "next_change_id");
+/// Owner proposed by the current one
+pub const OWNERSHIP: Ownership = Ownership::new("ownership_proposal");
+/// Guardians and keepers, by role
+pub const ACCESS: AccessControl = AccessControl::new("roles");
+/// Operations stopped by a guardian
+pub const PAUSE: PauseFlags = PauseFlags::new("paused", &[Operation::Update]);
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+pub fn read_assets(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<AssetResponse>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    ASSETS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|elem| {
+            let (id, asset) = elem?;
+            Ok(asset_response(id, asset))
+        })
+        .collect()
+}
+
+pub fn asset_response(id: u64, asset: HubAsset) -> AssetResponse {
+    AssetResponse {
+        id,
+        token_contract: asset.token_contract.to_string(),
+        reward_contract: asset.reward_contract.to_string(),
+        mode: asset.mode,
+        last_index_modification: asset.last_index_modification,
+        lsd_asset: asset.lsd_asset,
+    }
+}
@@ -1,13 +1,22 @@
-use basset::hub::Config;
+use basset::contract_error::ContractError;
+use basset::dex_router::SwapMessageType;
+use basset::hub::{Config, HubMode};
+use basset::reward::{
+    QueryMsg as RewardQueryMsg, RewardDistributionMode, SimulateSwapResponse, SwapSimulation,
+};
+use basset::wrapper::{
+    PendingDecompoundResponse, QueryMsg as WrapperQueryMsg, TokenInfoResponse,
+    TokenInfoResponseWithLimit,
+};
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::Empty;
 use cosmwasm_std::{
     from_binary, from_slice, to_binary, AllBalanceResponse, Api, BalanceResponse, BankQuery,
-    CanonicalAddr, Coin, ContractResult, Decimal, OwnedDeps, Querier, QuerierResult, QueryRequest,
-    SystemError, SystemResult, Uint128, WasmQuery,
+    Binary, CanonicalAddr, Coin, ContractResult, Decimal, OwnedDeps, Querier, QuerierResult,
+    QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
 };
 use cosmwasm_storage::to_length_prefixed;
-use cw20::TokenInfoResponse;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 
@@ -33,6 +42,21 @@ pub fn mock_dependencies(
 pub struct WasmMockQuerier {
     pub base: MockQuerier<Empty>,
     token_querier: TokenQuerier,
+    pending_decompound: Uint128,
+    exchange_rate: Decimal,
+    unlimited_wrapper: bool,
+    stale_oracle: bool,
+    wrapper_hub: Option<String>,
+    reward_config: MockRewardConfig,
+}
+
+/// Part of the reward contract config, as returned by its config query
+#[cw_serde]
+pub struct MockRewardConfig {
+    pub hub_contract: String,
+    pub custody_contract: Option<String>,
+    pub reward_denom: String,
+    pub distribution_mode: RewardDistributionMode,
 }
 
 impl Querier for WasmMockQuerier {
@@ -57,13 +81,25 @@ impl WasmMockQuerier {
             QueryRequest::Wasm(WasmQuery::Raw { contract_addr, key }) => {
                 let prefix_config = to_length_prefixed(b"config").to_vec();
                 let prefix_balance = to_length_prefixed(b"balance").to_vec();
+                let prefix_hub_contract = to_length_prefixed(b"hub_contract").to_vec();
                 let api: MockApi = MockApi::default();
 
-                if key.as_slice().to_vec() == prefix_config {
+                if key.as_slice().to_vec() == prefix_hub_contract {
+                    // hub address stored by the wrapper, empty when unset
+                    let hub = match &self.wrapper_hub {
+                        Some(hub) => to_binary(hub).unwrap(),
+                        None => Binary::default(),
+                    };
+                    SystemResult::Ok(ContractResult::Ok(hub))
+                } else if key.as_slice().to_vec() == prefix_config {
                     let config = Config {
                         creator: api.addr_validate("owner1").unwrap(),
                         reward_contract: Some(api.addr_validate("reward").unwrap()),
                         token_contract: Some(api.addr_validate("token").unwrap()),
+                        lsd_asset: None,
+                        reward_splits: vec![],
+                        keeper_fee: None,
+                        mode: HubMode::SwapRewards,
                     };
                     SystemResult::Ok(ContractResult::from(to_binary(
                         &to_binary(&config).unwrap(),
@@ -148,34 +184,78 @@ impl WasmMockQuerier {
             QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => {
                 println!("{:?}", contract_addr);
 
+                if let Ok(WrapperQueryMsg::PendingDecompound {}) = from_binary(msg) {
+                    return SystemResult::Ok(ContractResult::Ok(
+                        to_binary(&PendingDecompoundResponse {
+                            luna_rewards: self.pending_decompound,
+                            lsd_rewards: self.pending_decompound,
+                        })
+                        .unwrap(),
+                    ));
+                }
+
+                if let Ok(RewardQueryMsg::Config {}) = from_binary(msg) {
+                    return SystemResult::Ok(ContractResult::Ok(
+                        to_binary(&self.reward_config).unwrap(),
+                    ));
+                }
+
+                // the mock reward contract swaps the incoming rewards on Astroport, at 90%
+                if let Ok(RewardQueryMsg::SimulateSwap { incoming }) = from_binary(msg) {
+                    let swaps: Vec<SwapSimulation> = incoming
+                        .into_iter()
+                        .map(|asset| SwapSimulation {
+                            return_amount: asset.amount * Decimal::percent(90),
+                            offer_asset: asset,
+                            venue: Some(SwapMessageType::Astroport),
+                        })
+                        .collect();
+                    return SystemResult::Ok(ContractResult::Ok(
+                        to_binary(&SimulateSwapResponse {
+                            reward_denom: "uusd".to_string(),
+                            total_return_amount: swaps.iter().map(|swap| swap.return_amount).sum(),
+                            swaps,
+                        })
+                        .unwrap(),
+                    ));
+                }
+
                 match from_binary(msg).unwrap() {
+                    Cw20QueryMsg::TokenInfo {} if self.stale_oracle => {
+                        SystemResult::Ok(ContractResult::Err(
+                            ContractError::StaleOracle { last_updated: 1 }.to_string(),
+                        ))
+                    }
                     Cw20QueryMsg::TokenInfo {} => {
-                        let balances: &HashMap<String, Uint128> =
-                            match self.token_querier.balances.get(contract_addr) {
-                                Some(balances) => balances,
-                                None => {
-                                    return SystemResult::Err(SystemError::InvalidRequest {
-                                        error: format!(
-                                            "No balance info exists for the contract {}",
-                                            contract_addr
-                                        ),
-                                        request: msg.as_slice().into(),
-                                    })
-                                }
-                            };
-                        let mut total_supply = Uint128::zero();
-
-                        for balance in balances {
-                            total_supply += *balance.1;
-                        }
-                        let _api: MockApi = MockApi::default();
-                        let token_inf: TokenInfoResponse = TokenInfoResponse {
-                            name: "bluna".to_string(),
-                            symbol: "BLUNA".to_string(),
-                            decimals: 6,
-                            total_supply,
+                        // Contracts without any balance have no supply
+                        let total_supply = self
+                            .token_querier
+                            .balances
+                            .get(contract_addr)
+                            .map(|balances| balances.values().copied().sum())
+                            .unwrap_or_default();
+                        // The wrappers without any decompound limit return less fields
+                        let token_inf = if self.unlimited_wrapper {
+                            to_binary(&TokenInfoResponse {
+                                name: "bluna".to_string(),
+                                symbol: "BLUNA".to_string(),
+                                decimals: 6,
+                                total_supply,
+                                exchange_rate: self.exchange_rate,
+                            })
+                        } else {
+                            to_binary(&TokenInfoResponseWithLimit {
+                                name: "bluna".to_string(),
+                                symbol: "BLUNA".to_string(),
+                                decimals: 6,
+                                total_supply,
+                                exchange_rate: self.exchange_rate,
+                                expected_exchange_rate: self.exchange_rate,
+                                max_decompound_ratio: None,
+                                lsd_exchange_rate: Decimal::one(),
+                            })
                         };
-                        SystemResult::Ok(ContractResult::Ok(to_binary(&token_inf).unwrap()))
+                        SystemResult::Ok(ContractResult::Ok(token_inf.unwrap()))
                     }
                     Cw20QueryMsg::Balance { address } => {
                         let balances: &HashMap<String, Uint128> =
@@ -249,6 +329,17 @@ impl WasmMockQuerier {
         WasmMockQuerier {
             base,
             token_querier: TokenQuerier::default(),
+            pending_decompound: Uint128::zero(),
+            exchange_rate: Decimal::one(),
+            unlimited_wrapper: false,
+            stale_oracle: false,
+            wrapper_hub: Some(MOCK_CONTRACT_ADDR.to_string()),
+            reward_config: MockRewardConfig {
+                hub_contract: MOCK_CONTRACT_ADDR.to_string(),
+                custody_contract: Some("custody".to_string()),
+                reward_denom: "uusd".to_string(),
+                distribution_mode: RewardDistributionMode::Custody,
+            },
             //tax_querier: TaxQuerier::default(),
         }
     }
@@ -258,6 +349,36 @@ impl WasmMockQuerier {
         self.token_querier = TokenQuerier::new(balances);
     }
 
+    // configure the rewards the mock wrapper can decompound
+    pub fn with_pending_decompound(&mut self, pending_decompound: Uint128) {
+        self.pending_decompound = pending_decompound;
+    }
+
+    // configure the exchange rate of the mock wrapper
+    pub fn with_exchange_rate(&mut self, exchange_rate: Decimal) {
+        self.exchange_rate = exchange_rate;
+    }
+
+    // configure the mock wrapper to answer like the wrappers without any decompound limit
+    pub fn with_unlimited_wrapper(&mut self) {
+        self.unlimited_wrapper = true;
+    }
+
+    // configure the mock wrapper to fail like when its oracle price is too old
+    pub fn with_stale_oracle(&mut self) {
+        self.stale_oracle = true;
+    }
+
+    // configure the hub address stored by the mock wrapper
+    pub fn with_wrapper_hub(&mut self, wrapper_hub: Option<String>) {
+        self.wrapper_hub = wrapper_hub;
+    }
+
+    // configure the config of the mock reward contract
+    pub fn with_reward_config(&mut self, reward_config: MockRewardConfig) {
+        self.reward_config = reward_config;
+    }
+
     // configure the tax mock querier
     pub fn _with_tax(&mut self, _rate: Decimal, _caps: &[(&String, &Uint128)]) {
         //self.tax_querier = TaxQuerier::_new(rate, caps);
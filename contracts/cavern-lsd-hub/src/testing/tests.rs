@@ -20,22 +20,45 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::coins;
 use cosmwasm_std::{
-    coin, from_binary, to_binary, Api, CosmosMsg, Decimal, OwnedDeps, Querier, Response, StdError,
-    Storage, Uint128, WasmMsg,
+    attr, coin, from_binary, to_binary, Addr, Api, CosmosMsg, Decimal, Empty, Event, OwnedDeps,
+    Querier, Reply, ReplyOn, Response, StdError, Storage, SubMsgResponse, SubMsgResult, Uint128,
+    WasmMsg,
 };
 
 use cosmwasm_std::testing::{mock_env, mock_info};
 
-use crate::contract::{execute, instantiate, query};
+use crate::contract::{
+    execute, instantiate, migrate, query, reply, ASSET_DECOMPOUND_REPLY_ID, CONTRACT_NAME,
+    CONTRACT_VERSION, DECOMPOUND_REPLY_ID, HOOK_REPLY_ID, ISOLATED_ASSET_DECOMPOUND_REPLY_ID,
+};
+use crate::state::STATE;
 use basset::hub::{QueryMsg, Parameters};
-use basset::hub::{ConfigResponse, ExecuteMsg, OldInstantiateMsg, StateResponse};
+use basset::dex_router::AssetInfo;
+use basset::hub::{
+    AssetsResponse, ConfigResponse, ExecuteMsg, FollowUpMsg, HealthFinding, HealthResponse,
+    HubMode, HubUpdatedHookMsg, KeeperFee, MigrateMsg, NewHubAsset, OldInstantiateMsg, RewardSplit,
+    SimulateAssetUpdateResponse, SimulateUpdateGlobalIndexResponse, State, StateResponse,
+};
+use basset::reward::{Asset, SimulateSwapResponse, SwapSimulation};
+use basset::dex_router::SwapMessageType;
+use basset::wrapper::ExecuteMsg as LSDWrapperExecuteMsg;
+use basset::wrapper::HooksResponse;
+use basset::access::{Role, RoleHoldersResponse};
+use basset::contract_error::ContractError;
+use basset::ownership::PendingOwnerResponse;
+use basset::pause::{Operation, PauseStatusResponse, Paused};
+use basset::timelock::{PendingChange, PendingChangesResponse, TimelockResponse};
 
 use basset::hub::ExecuteMsg::UpdateConfig;
 
 use super::mock_querier::mock_dependencies as dependencies;
+use super::mock_querier::MockRewardConfig;
+use basset::reward::RewardDistributionMode;
 
 
 use basset::reward::ExecuteMsg::SwapToRewardDenom;
+use cw2::{get_contract_version, set_contract_version};
+use cw20::Cw20ExecuteMsg;
 use std::borrow::BorrowMut;
 
 pub const MOCK_CONTRACT_ADDR: &str = "cosmos2contract";
@@ -48,15 +71,21 @@ pub fn init<S: Storage, A: Api, Q: Querier>(
 ) {
     let msg = OldInstantiateMsg {
         reward_denom: "uusd".to_string(),
+        mode: None,
     };
 
     let owner_info = mock_info(owner.as_str(), &[coin(1000000, "uluna")]);
     instantiate(deps.as_mut(), mock_env(), owner_info.clone(), msg).unwrap();
 
     let register_msg = ExecuteMsg::UpdateConfig {
-        owner: None,
         reward_contract: Some(reward_contract),
         token_contract: Some(token_contract),
+        lsd_asset: None,
+        reward_splits: None,
+        keeper_fee: None,
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
     };
 
     let res = execute(deps.as_mut(), mock_env(), owner_info, register_msg).unwrap();
@@ -73,6 +102,7 @@ fn proper_initialization() {
     // successful call
     let msg = OldInstantiateMsg {
         reward_denom: "uusd".to_string(),
+        mode: None,
     };
 
     let _owner = "owner1";
@@ -97,6 +127,7 @@ fn proper_initialization() {
         total_bond_amount: Uint128::zero(),
         last_index_modification: mock_env().block.time.seconds(),
         prev_hub_balance: Default::default(),
+        total_decompounded: Uint128::zero(),
     };
     assert_eq!(query_state, expected_result);
 
@@ -108,6 +139,10 @@ fn proper_initialization() {
         owner: "owner1".to_string(),
         reward_contract: None,
         token_contract: None,
+        lsd_asset: None,
+        reward_splits: vec![],
+        keeper_fee: None,
+        mode: HubMode::SwapRewards,
         //airdrop_registry_contract: None,
     };
 
@@ -134,11 +169,11 @@ pub fn proper_update_global_index() {
     );
 
     // fails if there is no delegation
-    let reward_msg = ExecuteMsg::UpdateGlobalIndex {};
+    let reward_msg = ExecuteMsg::UpdateGlobalIndex { ids: None };
 
     let info = mock_info(&addr1, &[]);
     let res = execute(deps.as_mut(), mock_env(), info, reward_msg).unwrap();
-    assert_eq!(res.messages.len(), 2);
+    assert_eq!(res.messages.len(), 1);
 
     deps.querier
         .base
@@ -150,11 +185,13 @@ pub fn proper_update_global_index() {
 
     let reward_msg = ExecuteMsg::UpdateGlobalIndex {
         //airdrop_hooks: None,
+        ids: None,
     };
 
     let info = mock_info(&addr1, &[]);
     let res = execute(deps.as_mut(), mock_env(), info, reward_msg).unwrap();
-    assert_eq!(2, res.messages.len());
+    assert_eq!(1, res.messages.len());
+    assert_eq!(res.messages[0].id, DECOMPOUND_REPLY_ID);
 
     let last_index_query = QueryMsg::State {};
     let last_modification: StateResponse =
@@ -176,7 +213,22 @@ pub fn proper_update_global_index() {
         _ => panic!("Unexpected message: {:?}", withdraw),
     }
 
-    let swap = &res.messages[1].msg;
+    // the rewards are swapped once decompounded
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: DECOMPOUND_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+    assert_eq!(1, res.messages.len());
+
+    let swap = &res.messages[0].msg;
     match swap {
         CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr,
@@ -219,27 +271,86 @@ pub fn proper_update_config() {
 
     // only the owner can call this message
     let update_config = UpdateConfig {
-        owner: Some(new_owner.clone()),
-        reward_contract: None,
+        reward_contract: Some("new reward".to_string()),
         token_contract: None,
+        lsd_asset: None,
+        reward_splits: None,
+        keeper_fee: None,
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
     };
     let info = mock_info(&invalid_owner, &[]);
     let res = execute(deps.as_mut(), mock_env(), info, update_config);
-    assert_eq!(res.unwrap_err(), StdError::generic_err("unauthorized"));
+    assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
 
-    // only the owner can call this message
-    let update_config = UpdateConfig {
-        owner: Some(new_owner.clone()),
-        reward_contract: None,
-        token_contract: None,
+    // the ownership is transferred in two steps
+    let propose = ExecuteMsg::ProposeNewOwner {
+        owner: new_owner.clone(),
+        expires_in: 100,
     };
+    let info = mock_info(&invalid_owner, &[]);
+    let res = execute(deps.as_mut(), mock_env(), info, propose.clone());
+    assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+
     let info = mock_info(&owner, &[]);
-    let _res = execute(deps.as_mut(), mock_env(), info, update_config).unwrap();
+    let _res = execute(deps.as_mut(), mock_env(), info, propose).unwrap();
+
+    let pending_owner: PendingOwnerResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::PendingOwner {}).unwrap()).unwrap();
+    assert_eq!(
+        pending_owner,
+        PendingOwnerResponse {
+            pending_owner: Some(new_owner.clone()),
+            expires_at: Some(mock_env().block.time.seconds() + 100),
+        }
+    );
+
+    let info = mock_info(&invalid_owner, &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::ClaimOwnership {},
+    );
+    assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(101);
+    let new_owner_info = mock_info(new_owner.as_ref(), &[]);
+    let res = execute(
+        deps.as_mut(),
+        env,
+        new_owner_info.clone(),
+        ExecuteMsg::ClaimOwnership {},
+    );
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::Std(StdError::generic_err("the ownership proposal expired"))
+    );
+
+    let _res = execute(
+        deps.as_mut(),
+        mock_env(),
+        new_owner_info,
+        ExecuteMsg::ClaimOwnership {},
+    )
+    .unwrap();
+
+    let config = QueryMsg::Config {};
+    let config_query: ConfigResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), config).unwrap()).unwrap();
+    assert_eq!(config_query.owner, new_owner);
 
     let update_config = UpdateConfig {
-        owner: None,
         reward_contract: Some("new reward".to_string()),
         token_contract: None,
+        lsd_asset: None,
+        reward_splits: None,
+        keeper_fee: None,
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
     };
     let new_owner_info = mock_info(new_owner.as_ref(), &[]);
     let res = execute(deps.as_mut(), mock_env(), new_owner_info, update_config).unwrap();
@@ -254,9 +365,14 @@ pub fn proper_update_config() {
     );
 
     let update_config = UpdateConfig {
-        owner: None,
         reward_contract: None,
         token_contract: Some("new token".to_string()),
+        lsd_asset: None,
+        reward_splits: None,
+        keeper_fee: None,
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
     };
     let new_owner_info = mock_info(new_owner.as_ref(), &[]);
     let res = execute(deps.as_mut(), mock_env(), new_owner_info, update_config).unwrap();
@@ -278,15 +394,2049 @@ pub fn proper_update_config() {
     assert_eq!(config_query.owner, new_owner);
 
     let update_config = UpdateConfig {
-        owner: None,
         reward_contract: None,
         token_contract: None,
+        lsd_asset: None,
+        reward_splits: None,
+        keeper_fee: None,
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
     };
     let new_owner_info = mock_info(new_owner.as_ref(), &[]);
     let res = execute(deps.as_mut(), mock_env(), new_owner_info, update_config).unwrap();
     assert_eq!(res.messages.len(), 0);
 }
 
+/// Covers if the decompounded rewards are split between the recipients
+#[test]
+pub fn proper_reward_splits() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    let token_contract = "token".to_string();
+    let reward_contract = "reward".to_string();
+    let treasury = "treasury".to_string();
+    let lsd_token = "lsd_token".to_string();
+
+    init(
+        &mut deps,
+        owner.clone(),
+        reward_contract.clone(),
+        token_contract.clone(),
+    );
+    let owner_info = mock_info(&owner, &[]);
+
+    let splits = vec![
+        RewardSplit {
+            recipient: reward_contract.clone(),
+            share: Decimal::percent(90),
+        },
+        RewardSplit {
+            recipient: treasury.clone(),
+            share: Decimal::percent(10),
+        },
+    ];
+
+    // the lsd asset is needed to forward the rewards
+    let update_config = UpdateConfig {
+        reward_contract: None,
+        token_contract: None,
+        lsd_asset: None,
+        reward_splits: Some(splits.clone()),
+        keeper_fee: None,
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
+    };
+    let res = execute(deps.as_mut(), mock_env(), owner_info.clone(), update_config);
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::Std(StdError::generic_err(
+            "the lsd asset must be registered to forward the rewards"
+        ))
+    );
+
+    // the shares must sum to 100%
+    let update_config = UpdateConfig {
+        reward_contract: None,
+        token_contract: None,
+        lsd_asset: Some(AssetInfo::Token {
+            contract_addr: Addr::unchecked(lsd_token.clone()),
+        }),
+        reward_splits: Some(vec![RewardSplit {
+            recipient: treasury.clone(),
+            share: Decimal::percent(10),
+        }]),
+        keeper_fee: None,
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
+    };
+    let res = execute(deps.as_mut(), mock_env(), owner_info.clone(), update_config);
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::Std(StdError::generic_err("the reward shares must sum to 100%"))
+    );
+
+    let update_config = UpdateConfig {
+        reward_contract: None,
+        token_contract: None,
+        lsd_asset: Some(AssetInfo::Token {
+            contract_addr: Addr::unchecked(lsd_token.clone()),
+        }),
+        reward_splits: Some(splits.clone()),
+        keeper_fee: None,
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
+    };
+    execute(deps.as_mut(), mock_env(), owner_info, update_config).unwrap();
+
+    let config_query: ConfigResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config_query.reward_splits, splits);
+
+    // the rewards are decompounded to the hub, which already holds some lsd tokens
+    deps.querier.with_token_balances(&[(
+        &lsd_token,
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::new(7))],
+    )]);
+    let info = mock_info("addr1000", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].id, DECOMPOUND_REPLY_ID);
+
+    // and only the decompounded ones are forwarded to the recipients in the reply
+    deps.querier.with_token_balances(&[(
+        &lsd_token,
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::new(1012))],
+    )]);
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: DECOMPOUND_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 3);
+    assert_eq!(res.events.len(), 2);
+
+    for (msg, (recipient, amount)) in res.messages.iter().zip([
+        (reward_contract.clone(), Uint128::new(904)),
+        (treasury, Uint128::new(101)),
+    ]) {
+        assert_eq!(
+            msg.msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: lsd_token.clone(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer { recipient, amount }).unwrap(),
+                funds: vec![],
+            })
+        );
+    }
+
+    let swap = &res.messages[2].msg;
+    match swap {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds: _,
+        }) => {
+            assert_eq!(contract_addr, &reward_contract);
+            assert_eq!(msg, &to_binary(&SwapToRewardDenom {}).unwrap())
+        }
+        _ => panic!("Unexpected message: {:?}", swap),
+    }
+}
+
+/// Covers if the caller of UpdateGlobalIndex is paid the keeper fee
+#[test]
+pub fn proper_keeper_fee() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    let keeper = "keeper".to_string();
+    let token_contract = "token".to_string();
+    let reward_contract = "reward".to_string();
+    let lsd_token = "lsd_token".to_string();
+
+    init(
+        &mut deps,
+        owner.clone(),
+        reward_contract.clone(),
+        token_contract,
+    );
+
+    let keeper_fee = KeeperFee {
+        ratio: Decimal::percent(10),
+        cap: Uint128::new(50),
+        min_interval: 100,
+        min_decompound: Uint128::new(10),
+    };
+    let update_config = UpdateConfig {
+        reward_contract: None,
+        token_contract: None,
+        lsd_asset: Some(AssetInfo::Token {
+            contract_addr: Addr::unchecked(lsd_token.clone()),
+        }),
+        reward_splits: None,
+        keeper_fee: Some(keeper_fee.clone()),
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
+    };
+    let owner_info = mock_info(&owner, &[]);
+    execute(deps.as_mut(), mock_env(), owner_info, update_config).unwrap();
+
+    let config_query: ConfigResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config_query.keeper_fee, Some(keeper_fee));
+
+    // the global index can't be updated too often
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(&keeper, &[]),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    );
+    assert_eq!(res.unwrap_err(), ContractError::UpdateTooOften {});
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(100);
+    deps.querier.with_token_balances(&[(
+        &lsd_token,
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::new(20))],
+    )]);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(&keeper, &[]),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].id, DECOMPOUND_REPLY_ID);
+
+    let decompound_reply = Reply {
+        id: DECOMPOUND_REPLY_ID,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+        }),
+    };
+
+    // not enough rewards were decompounded, the lsd held before doesn't count
+    deps.querier.with_token_balances(&[(
+        &lsd_token,
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::new(25))],
+    )]);
+    let res = reply(deps.as_mut(), env.clone(), decompound_reply.clone());
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::NotEnoughRewards {
+            value: Uint128::new(5),
+            min: Uint128::new(10)
+        }
+    );
+
+    // the keeper fee is capped, the rest goes to the reward contract
+    deps.querier.with_token_balances(&[(
+        &lsd_token,
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::new(1020))],
+    )]);
+    let res = reply(deps.as_mut(), env, decompound_reply).unwrap();
+    assert_eq!(res.messages.len(), 3);
+
+    for (msg, (recipient, amount)) in res.messages.iter().zip([
+        (keeper, Uint128::new(50)),
+        (reward_contract.clone(), Uint128::new(950)),
+    ]) {
+        assert_eq!(
+            msg.msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: lsd_token.clone(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer { recipient, amount }).unwrap(),
+                funds: vec![],
+            })
+        );
+    }
+    assert_eq!(
+        res.messages[2].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: reward_contract,
+            msg: to_binary(&SwapToRewardDenom {}).unwrap(),
+            funds: vec![],
+        })
+    );
+}
+
+/// Covers if the global index update is skipped or refused when it isn't worth it
+#[test]
+pub fn proper_update_guards() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    let token_contract = "token".to_string();
+    let reward_contract = "reward".to_string();
+
+    init(&mut deps, owner.clone(), reward_contract, token_contract);
+
+    let update_config = UpdateConfig {
+        reward_contract: None,
+        token_contract: None,
+        lsd_asset: None,
+        reward_splits: None,
+        // the guards don't need a keeper fee
+        keeper_fee: None,
+        min_decompound_value: Some(Uint128::new(100)),
+        min_update_interval: Some(60),
+        mode: None,
+    };
+    let owner_info = mock_info(&owner, &[]);
+    execute(deps.as_mut(), mock_env(), owner_info, update_config).unwrap();
+
+    let params: Parameters =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Parameters {}).unwrap()).unwrap();
+    assert_eq!(
+        params,
+        Parameters {
+            reward_denom: "uusd".to_string(),
+            min_decompound_value: Uint128::new(100),
+            min_update_interval: 60,
+        }
+    );
+    let config: ConfigResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.keeper_fee, None);
+
+    // the minimum interval is enforced
+    let info = mock_info("addr1000", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    );
+    assert_eq!(res.unwrap_err(), ContractError::UpdateTooOften {});
+
+    // nothing is sent when there isn't enough to decompound
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(60);
+    deps.querier.with_pending_decompound(Uint128::new(99));
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 0);
+    assert!(res
+        .attributes
+        .contains(&attr("skipped", "pending_decompound_below_minimum")));
+
+    let state: StateResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::State {}).unwrap()).unwrap();
+    assert_eq!(
+        state.last_index_modification,
+        mock_env().block.time.seconds()
+    );
+
+    deps.querier.with_pending_decompound(Uint128::new(100));
+    let res = execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "token".to_string(),
+            msg: to_binary(&LSDWrapperExecuteMsg::<Empty>::Decompound {
+                recipient: Some("reward".to_string()),
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+}
+
+/// Covers if the registered assets are updated with the primary one
+#[test]
+pub fn proper_registered_assets() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    init(
+        &mut deps,
+        owner.clone(),
+        "reward".to_string(),
+        "token".to_string(),
+    );
+
+    let register_msg = ExecuteMsg::RegisterAssets {
+        assets: vec![
+            NewHubAsset {
+                token_contract: "token2".to_string(),
+                reward_contract: "reward2".to_string(),
+                mode: HubMode::SwapRewards,
+                lsd_asset: None,
+            },
+            NewHubAsset {
+                token_contract: "token3".to_string(),
+                reward_contract: "reward3".to_string(),
+                mode: HubMode::DecompoundOnly,
+                lsd_asset: None,
+            },
+        ],
+    };
+
+    // only the owner can register assets
+    let info = mock_info("invalid_owner", &[]);
+    let res = execute(deps.as_mut(), mock_env(), info, register_msg.clone());
+    assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+
+    let owner_info = mock_info(&owner, &[]);
+    execute(deps.as_mut(), mock_env(), owner_info.clone(), register_msg).unwrap();
+
+    let assets: AssetsResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Assets {
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(assets.assets.len(), 1);
+    assert_eq!(assets.assets[0].id, 0);
+    assert_eq!(assets.assets[0].token_contract, "token2".to_string());
+
+    let assets: AssetsResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Assets {
+                start_after: Some(0),
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(assets.assets.len(), 1);
+    assert_eq!(assets.assets[0].id, 1);
+    assert_eq!(assets.assets[0].mode, HubMode::DecompoundOnly);
+
+    // the rewards can't be forwarded while the lsd of an asset is unknown
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        UpdateConfig {
+            reward_contract: None,
+            token_contract: None,
+            lsd_asset: Some(AssetInfo::Token {
+                contract_addr: Addr::unchecked("lsd_token"),
+            }),
+            reward_splits: Some(vec![RewardSplit {
+                recipient: "treasury".to_string(),
+                share: Decimal::percent(20),
+            }]),
+            keeper_fee: None,
+            min_decompound_value: None,
+            min_update_interval: None,
+            mode: None,
+        },
+    );
+    assert_eq!(res.unwrap_err(), ContractError::MissingLsdAsset {});
+
+    // only the given assets are updated
+    let info = mock_info("addr1000", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateGlobalIndex { ids: Some(vec![1]) },
+    )
+    .unwrap();
+    // the reply id tells which asset was decompounded
+    assert_eq!(res.messages[0].id, ASSET_DECOMPOUND_REPLY_ID + 1);
+    assert_eq!(
+        res.messages
+            .iter()
+            .map(|m| m.msg.clone())
+            .collect::<Vec<_>>(),
+        vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "token3".to_string(),
+            msg: to_binary(&LSDWrapperExecuteMsg::<Empty>::Decompound {
+                recipient: Some("reward3".to_string()),
+            })
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+
+    // without ids, the primary asset and all the registered ones are updated
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 3);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info,
+        ExecuteMsg::DeregisterAsset { id: 0 },
+    )
+    .unwrap();
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::Asset { id: 0 });
+    assert_eq!(
+        res.unwrap_err(),
+        StdError::generic_err("asset 0 is not registered")
+    );
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::UpdateGlobalIndex { ids: Some(vec![0]) },
+    );
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::AssetNotRegistered { id: 0 }
+    );
+}
+
+/// Covers if the rewards of the registered assets are forwarded like the primary ones
+/// and if a failing asset doesn't revert the update of all the assets
+#[test]
+pub fn proper_registered_assets_forwarding() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    let lsd_token = "lsd_token".to_string();
+    let lsd_token2 = "lsd_token2".to_string();
+    init(
+        &mut deps,
+        owner.clone(),
+        "reward".to_string(),
+        "token".to_string(),
+    );
+    let owner_info = mock_info(&owner, &[]);
+
+    let update_config = UpdateConfig {
+        reward_contract: None,
+        token_contract: None,
+        lsd_asset: Some(AssetInfo::Token {
+            contract_addr: Addr::unchecked(lsd_token.clone()),
+        }),
+        reward_splits: Some(vec![
+            RewardSplit {
+                recipient: "reward".to_string(),
+                share: Decimal::percent(80),
+            },
+            RewardSplit {
+                recipient: "treasury".to_string(),
+                share: Decimal::percent(20),
+            },
+        ]),
+        keeper_fee: Some(KeeperFee {
+            ratio: Decimal::percent(10),
+            cap: Uint128::new(50),
+            min_interval: 0,
+            min_decompound: Uint128::zero(),
+        }),
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
+    };
+    execute(deps.as_mut(), mock_env(), owner_info.clone(), update_config).unwrap();
+
+    // the lsd of the assets must be known to forward their rewards
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::RegisterAssets {
+            assets: vec![NewHubAsset {
+                token_contract: "token3".to_string(),
+                reward_contract: "reward3".to_string(),
+                mode: HubMode::DecompoundOnly,
+                lsd_asset: None,
+            }],
+        },
+    );
+    assert_eq!(res.unwrap_err(), ContractError::MissingLsdAsset {});
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::RegisterAssets {
+            assets: vec![NewHubAsset {
+                token_contract: "token2".to_string(),
+                reward_contract: "reward2".to_string(),
+                mode: HubMode::DecompoundOnly,
+                lsd_asset: Some(AssetInfo::Token {
+                    contract_addr: Addr::unchecked(lsd_token2.clone()),
+                }),
+            }],
+        },
+    )
+    .unwrap();
+
+    // the rewards of the asset are decompounded to the hub
+    deps.querier.with_token_balances(&[(
+        &lsd_token2,
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::new(3))],
+    )]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("keeper", &[]),
+        ExecuteMsg::UpdateGlobalIndex { ids: Some(vec![0]) },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].reply_on, ReplyOn::Success);
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "token2".to_string(),
+            msg: to_binary(&LSDWrapperExecuteMsg::<Empty>::Decompound {
+                recipient: Some(MOCK_CONTRACT_ADDR.to_string()),
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+
+    // the keeper is paid and the share of the reward contract goes to the one of the asset
+    deps.querier.with_token_balances(&[(
+        &lsd_token2,
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::new(103))],
+    )]);
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: ASSET_DECOMPOUND_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages
+            .iter()
+            .map(|m| m.msg.clone())
+            .collect::<Vec<_>>(),
+        [
+            ("keeper", Uint128::new(10)),
+            ("reward2", Uint128::new(72)),
+            ("treasury", Uint128::new(18)),
+        ]
+        .into_iter()
+        .map(|(recipient, amount)| CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: lsd_token2.clone(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount,
+            })
+            .unwrap(),
+            funds: vec![],
+        }))
+        .collect::<Vec<_>>()
+    );
+    assert!(res.attributes.contains(&attr("decompounded", "100")));
+
+    // without ids, the failure of an asset is only reported
+    deps.querier.with_token_balances(&[
+        (
+            &lsd_token,
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+        ),
+        (
+            &lsd_token2,
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+        ),
+    ]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("keeper", &[]),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 2);
+    assert_eq!(res.messages[0].reply_on, ReplyOn::Success);
+    assert_eq!(res.messages[1].reply_on, ReplyOn::Always);
+    assert_eq!(res.messages[1].id, ISOLATED_ASSET_DECOMPOUND_REPLY_ID);
+
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: ISOLATED_ASSET_DECOMPOUND_REPLY_ID,
+            result: SubMsgResult::Err("decompound failed".to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 0);
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "asset_decompound_failed"),
+            attr("id", "0"),
+            attr("error", "decompound failed"),
+        ]
+    );
+
+    // rewards that can't be forwarded are only reported and go to the reward contract
+    let update_config = UpdateConfig {
+        reward_contract: None,
+        token_contract: None,
+        lsd_asset: None,
+        reward_splits: None,
+        keeper_fee: Some(KeeperFee {
+            ratio: Decimal::percent(10),
+            cap: Uint128::new(50),
+            min_interval: 0,
+            min_decompound: Uint128::new(50),
+        }),
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
+    };
+    execute(deps.as_mut(), mock_env(), owner_info, update_config).unwrap();
+    deps.querier.with_pending_decompound(Uint128::new(60));
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("keeper", &[]),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    deps.querier.with_token_balances(&[(
+        &lsd_token2,
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::new(20))],
+    )]);
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: ISOLATED_ASSET_DECOMPOUND_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages
+            .iter()
+            .map(|m| m.msg.clone())
+            .collect::<Vec<_>>(),
+        vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: lsd_token2.clone(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "reward2".to_string(),
+                amount: Uint128::new(20),
+            })
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "asset_decompound_failed"),
+            attr("id", "0"),
+            attr("error", "not enough rewards to decompound: 20 < 50"),
+            attr("decompounded", "20"),
+        ]
+    );
+}
+
+/// Covers if the messages sent after the decompound follow the mode
+#[test]
+pub fn proper_hub_modes() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    let reward_contract = "reward".to_string();
+    init(
+        &mut deps,
+        owner.clone(),
+        reward_contract.clone(),
+        "token".to_string(),
+    );
+
+    // the messages are sent once the rewards are decompounded
+    let decompound_reply = Reply {
+        id: DECOMPOUND_REPLY_ID,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+        }),
+    };
+
+    let info = mock_info("addr1000", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    let res = reply(deps.as_mut(), mock_env(), decompound_reply.clone()).unwrap();
+    assert_eq!(res.messages.len(), 1);
+
+    // the mode can be changed without migrating
+    let update_config = UpdateConfig {
+        reward_contract: None,
+        token_contract: None,
+        lsd_asset: None,
+        reward_splits: None,
+        keeper_fee: None,
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: Some(HubMode::DecompoundOnly),
+    };
+    let owner_info = mock_info(&owner, &[]);
+    execute(deps.as_mut(), mock_env(), owner_info.clone(), update_config).unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    let res = reply(deps.as_mut(), mock_env(), decompound_reply.clone()).unwrap();
+    assert_eq!(res.messages.len(), 0);
+
+    let claim_msg = to_binary(&MIRMsg::MIRClaim {}).unwrap();
+    let update_config = UpdateConfig {
+        reward_contract: None,
+        token_contract: None,
+        lsd_asset: None,
+        reward_splits: None,
+        keeper_fee: None,
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: Some(HubMode::Custom {
+            msgs: vec![FollowUpMsg {
+                contract_addr: reward_contract.clone(),
+                msg: claim_msg.clone(),
+            }],
+        }),
+    };
+    execute(deps.as_mut(), mock_env(), owner_info, update_config).unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    let res = reply(deps.as_mut(), mock_env(), decompound_reply).unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: reward_contract,
+            msg: claim_msg,
+            funds: vec![],
+        })
+    );
+}
+
+/// Covers if the storage of a decompound-only hub is converted by the migration
+#[test]
+pub fn proper_migrate_decompound_only_hub() {
+    let mut deps = dependencies(&[]);
+
+    // decompound-only hubs only stored their config and state
+    deps.storage.set(
+        "\u{0}\u{6}config".as_bytes(),
+        br#"{"creator":"owner1","reward_contract":"reward","token_contract":"token"}"#,
+    );
+    STATE
+        .save(
+            deps.as_mut().storage,
+            &State {
+                exchange_rate: Decimal::one(),
+                last_index_modification: mock_env().block.time.seconds(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    let msg = MigrateMsg {
+        register_assets: vec![],
+        reward_denom: Some("uwhale".to_string()),
+    };
+    migrate(deps.as_mut(), mock_env(), msg).unwrap();
+
+    let config: ConfigResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.mode, HubMode::DecompoundOnly);
+    assert_eq!(config.reward_contract, Some("reward".to_string()));
+
+    let params: Parameters =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Parameters {}).unwrap()).unwrap();
+    assert_eq!(params.reward_denom, "uwhale".to_string());
+
+    let info = mock_info("addr1000", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+}
+
+/// Covers if the migrations check the contract name and refuse downgrades
+#[test]
+pub fn proper_migrate_version() {
+    let mut deps = dependencies(&[]);
+    init(
+        &mut deps,
+        "owner1".to_string(),
+        "reward".to_string(),
+        "token".to_string(),
+    );
+
+    let version = get_contract_version(deps.as_ref().storage).unwrap();
+    assert_eq!(version.contract, CONTRACT_NAME);
+    assert_eq!(version.version, CONTRACT_VERSION);
+
+    let msg = MigrateMsg {
+        register_assets: vec![],
+        reward_denom: None,
+    };
+    migrate(deps.as_mut(), mock_env(), msg.clone()).unwrap();
+
+    set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "99.0.0").unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), msg.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CannotDowngrade {
+            previous: "99.0.0".to_string(),
+            new: CONTRACT_VERSION.to_string()
+        }
+    );
+
+    let other = "crates.io:cavern-lsd-reward";
+    set_contract_version(deps.as_mut().storage, other, "1.0.0").unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidContractName {
+            expected: CONTRACT_NAME.to_string(),
+            actual: other.to_string()
+        }
+    );
+}
+
+/// Covers if the state tracks the wrapper after each decompound
+#[test]
+pub fn proper_state_tracking() {
+    let mut deps = dependencies(&[]);
+
+    let addr1 = "addr1000".to_string();
+    let token_contract = "token".to_string();
+
+    init(
+        &mut deps,
+        "owner1".to_string(),
+        "reward".to_string(),
+        token_contract.clone(),
+    );
+    deps.querier
+        .with_token_balances(&[(&token_contract, &[(&addr1, &Uint128::new(1000))])]);
+    deps.querier.with_exchange_rate(Decimal::percent(110));
+
+    let info = mock_info(&addr1, &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    assert_eq!(res.messages[0].id, DECOMPOUND_REPLY_ID);
+
+    // only the rewards reported by the wrapper are counted
+    let decompound_reply = |lsd_rewards: &str| Reply {
+        id: DECOMPOUND_REPLY_ID,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![
+                Event::new("wasm")
+                    .add_attribute("_contract_address", "lsd_token")
+                    .add_attribute("lsd_rewards", "1"),
+                Event::new("wasm")
+                    .add_attribute("_contract_address", "token")
+                    .add_attribute("action", "execute_decompound")
+                    .add_attribute("lsd_rewards", lsd_rewards),
+            ],
+            data: None,
+        }),
+    };
+    let res = reply(deps.as_mut(), mock_env(), decompound_reply("42")).unwrap();
+    assert!(res.attributes.contains(&attr("decompounded", "42")));
+
+    let state: StateResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::State {}).unwrap()).unwrap();
+    assert_eq!(
+        state,
+        StateResponse {
+            exchange_rate: Decimal::percent(110),
+            total_bond_amount: Uint128::new(1100),
+            last_index_modification: mock_env().block.time.seconds(),
+            prev_hub_balance: Uint128::new(42),
+            total_decompounded: Uint128::new(42),
+        }
+    );
+
+    // the decompounded rewards add up
+    reply(deps.as_mut(), mock_env(), decompound_reply("8")).unwrap();
+    let state: StateResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::State {}).unwrap()).unwrap();
+    assert_eq!(state.prev_hub_balance, Uint128::new(8));
+    assert_eq!(state.total_decompounded, Uint128::new(50));
+}
+
+/// Covers if the state is tracked for the wrappers without any decompound limit
+#[test]
+pub fn proper_state_tracking_unlimited_wrapper() {
+    let mut deps = dependencies(&[]);
+
+    let addr1 = "addr1000".to_string();
+    let token_contract = "token".to_string();
+
+    init(
+        &mut deps,
+        "owner1".to_string(),
+        "reward".to_string(),
+        token_contract.clone(),
+    );
+    deps.querier
+        .with_token_balances(&[(&token_contract, &[(&addr1, &Uint128::new(1000))])]);
+    deps.querier.with_exchange_rate(Decimal::percent(120));
+    deps.querier.with_unlimited_wrapper();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(&addr1, &[]),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: DECOMPOUND_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+
+    let state: StateResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::State {}).unwrap()).unwrap();
+    assert_eq!(state.exchange_rate, Decimal::percent(120));
+    assert_eq!(state.total_bond_amount, Uint128::new(1200));
+
+    // the health check reads the same token info
+    let health: HealthResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Health {}).unwrap()).unwrap();
+    assert!(!health
+        .findings
+        .iter()
+        .any(|finding| matches!(finding, HealthFinding::WrapperQueryFailed { .. })));
+}
+
+/// Covers if the registered hooks are notified once the rewards are handled
+#[test]
+pub fn proper_hub_hooks() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    let hook = "overseer".to_string();
+    init(
+        &mut deps,
+        owner.clone(),
+        "reward".to_string(),
+        "token".to_string(),
+    );
+
+    // only the owner can add hooks
+    let info = mock_info("invalid_owner", &[]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::AddHook { addr: hook.clone() },
+    );
+    assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+
+    let owner_info = mock_info(&owner, &[]);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::AddHook { addr: hook.clone() },
+    )
+    .unwrap();
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::AddHook { addr: hook.clone() },
+    );
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::Std(StdError::generic_err(
+            "Given address already registered as a hook"
+        ))
+    );
+
+    let hooks: HooksResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Hooks {}).unwrap()).unwrap();
+    assert_eq!(hooks.hooks, vec![hook.clone()]);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::RegisterAssets {
+            assets: vec![NewHubAsset {
+                token_contract: "token2".to_string(),
+                reward_contract: "reward2".to_string(),
+                mode: HubMode::DecompoundOnly,
+                lsd_asset: None,
+            }],
+        },
+    )
+    .unwrap();
+
+    // the hooks are notified after the swap
+    let info = mock_info("addr1000", &[]);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+
+    let decompound_reply = |id: u64, token: &str| Reply {
+        id,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![Event::new("wasm")
+                .add_attribute("_contract_address", token)
+                .add_attribute("lsd_rewards", "42")],
+            data: None,
+        }),
+    };
+    for (id, token, messages) in [
+        (DECOMPOUND_REPLY_ID, "token", 2),
+        (ASSET_DECOMPOUND_REPLY_ID, "token2", 1),
+    ] {
+        let res = reply(deps.as_mut(), mock_env(), decompound_reply(id, token)).unwrap();
+        assert_eq!(res.messages.len(), messages);
+
+        let hook_msg = res.messages.last().unwrap();
+        assert_eq!(hook_msg.id, HOOK_REPLY_ID);
+        assert_eq!(hook_msg.reply_on, ReplyOn::Error);
+        assert_eq!(
+            hook_msg.msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: hook.clone(),
+                msg: to_binary(&HubUpdatedHookMsg::HubUpdated {
+                    token: token.to_string(),
+                    decompounded: Uint128::new(42),
+                    timestamp: mock_env().block.time.seconds(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+    }
+
+    // a failing hook doesn't revert the update
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: HOOK_REPLY_ID,
+            result: SubMsgResult::Err("hook error".to_string()),
+        },
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&attr("error", "hook error")));
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::RemoveHook { addr: hook.clone() },
+    )
+    .unwrap();
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info,
+        ExecuteMsg::RemoveHook { addr: hook },
+    );
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::Std(StdError::generic_err(
+            "Given address not registered as a hook"
+        ))
+    );
+}
+
+/// Covers if the simulated update reports the decompounded rewards, the swaps and the guards
+#[test]
+pub fn proper_simulate_update_global_index() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    let lsd_asset = AssetInfo::Token {
+        contract_addr: Addr::unchecked("lsd_token"),
+    };
+    init(
+        &mut deps,
+        owner.clone(),
+        "reward".to_string(),
+        "token".to_string(),
+    );
+
+    let update_config = UpdateConfig {
+        reward_contract: None,
+        token_contract: None,
+        lsd_asset: Some(lsd_asset.clone()),
+        reward_splits: None,
+        keeper_fee: Some(KeeperFee {
+            ratio: Decimal::percent(10),
+            cap: Uint128::new(50),
+            min_interval: 0,
+            min_decompound: Uint128::new(10),
+        }),
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
+    };
+    let owner_info = mock_info(&owner, &[]);
+    execute(deps.as_mut(), mock_env(), owner_info.clone(), update_config).unwrap();
+    deps.querier.with_pending_decompound(Uint128::new(1000));
+
+    // the registered assets are simulated too
+    let lsd_asset2 = AssetInfo::Token {
+        contract_addr: Addr::unchecked("lsd_token2"),
+    };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::RegisterAssets {
+            assets: vec![NewHubAsset {
+                token_contract: "token2".to_string(),
+                reward_contract: "reward2".to_string(),
+                mode: HubMode::SwapRewards,
+                lsd_asset: Some(lsd_asset2.clone()),
+            }],
+        },
+    )
+    .unwrap();
+
+    let simulation: SimulateUpdateGlobalIndexResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::SimulateUpdateGlobalIndex {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        simulation,
+        SimulateUpdateGlobalIndexResponse {
+            lsd_to_decompound: Uint128::new(1000),
+            keeper_fee: Uint128::new(50),
+            lsd_to_reward_contract: Uint128::new(950),
+            swap: Some(SimulateSwapResponse {
+                reward_denom: "uusd".to_string(),
+                swaps: vec![SwapSimulation {
+                    offer_asset: Asset {
+                        amount: Uint128::new(950),
+                        asset_info: lsd_asset,
+                    },
+                    venue: Some(SwapMessageType::Astroport),
+                    return_amount: Uint128::new(855),
+                }],
+                total_return_amount: Uint128::new(855),
+            }),
+            blocked_by: None,
+            assets: vec![SimulateAssetUpdateResponse {
+                id: 0,
+                lsd_to_decompound: Uint128::new(1000),
+                keeper_fee: Uint128::new(50),
+                lsd_to_reward_contract: Uint128::new(950),
+                swap: Some(SimulateSwapResponse {
+                    reward_denom: "uusd".to_string(),
+                    swaps: vec![SwapSimulation {
+                        offer_asset: Asset {
+                            amount: Uint128::new(950),
+                            asset_info: lsd_asset2,
+                        },
+                        venue: Some(SwapMessageType::Astroport),
+                        return_amount: Uint128::new(855),
+                    }],
+                    total_return_amount: Uint128::new(855),
+                }),
+                blocked_by: None,
+            }],
+        }
+    );
+
+    // the guards are reported
+    let update_config = UpdateConfig {
+        reward_contract: None,
+        token_contract: None,
+        lsd_asset: None,
+        reward_splits: None,
+        keeper_fee: Some(KeeperFee {
+            ratio: Decimal::percent(10),
+            cap: Uint128::new(50),
+            min_interval: 0,
+            min_decompound: Uint128::new(2000),
+        }),
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: Some(HubMode::DecompoundOnly),
+    };
+    execute(deps.as_mut(), mock_env(), owner_info, update_config).unwrap();
+
+    let simulation: SimulateUpdateGlobalIndexResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::SimulateUpdateGlobalIndex {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(simulation.swap, None);
+    assert_eq!(
+        simulation.blocked_by,
+        Some("the pending decompound is below the minimum: 1000 < 2000".to_string())
+    );
+    assert_eq!(simulation.assets[0].blocked_by, simulation.blocked_by);
+}
+
+#[test]
+pub fn proper_health() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    init(&mut deps, owner, "reward".to_string(), "token".to_string());
+
+    let health: HealthResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Health {}).unwrap()).unwrap();
+    assert_eq!(
+        health,
+        HealthResponse {
+            healthy: true,
+            findings: vec![],
+        }
+    );
+
+    // misconfigured wrapper and reward contracts
+    let addr1 = "addr0000".to_string();
+    let bond_amount = Uint128::new(100);
+    deps.querier
+        .with_token_balances(&[(&"token".to_string(), &[(&addr1, &bond_amount)])]);
+    deps.querier.with_exchange_rate(Decimal::zero());
+    deps.querier.with_wrapper_hub(Some("other_hub".to_string()));
+    deps.querier.with_reward_config(MockRewardConfig {
+        hub_contract: MOCK_CONTRACT_ADDR.to_string(),
+        custody_contract: None,
+        reward_denom: "uusd".to_string(),
+        distribution_mode: RewardDistributionMode::Custody,
+    });
+
+    let health: HealthResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Health {}).unwrap()).unwrap();
+    assert_eq!(
+        health,
+        HealthResponse {
+            healthy: false,
+            findings: vec![
+                HealthFinding::WrapperHubMismatch {
+                    wrapper: "token".to_string(),
+                    hub: Some("other_hub".to_string()),
+                },
+                HealthFinding::ZeroBacking {
+                    wrapper: "token".to_string(),
+                },
+                HealthFinding::MissingCustody {
+                    reward_contract: "reward".to_string(),
+                },
+            ],
+        }
+    );
+
+    // the wrapper reads an oracle price that is too old
+    deps.querier.with_stale_oracle();
+    let health: HealthResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Health {}).unwrap()).unwrap();
+    assert!(health.findings.contains(&HealthFinding::StaleOracle {
+        wrapper: "token".to_string(),
+    }));
+
+    // a reward contract sharing the rewards between the holders doesn't need a custody
+    deps.querier.with_reward_config(MockRewardConfig {
+        hub_contract: MOCK_CONTRACT_ADDR.to_string(),
+        custody_contract: None,
+        reward_denom: "uusd".to_string(),
+        distribution_mode: RewardDistributionMode::Holders {
+            token_contract: Addr::unchecked("token"),
+        },
+    });
+    let health: HealthResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Health {}).unwrap()).unwrap();
+    assert!(!health.findings.contains(&HealthFinding::MissingCustody {
+        reward_contract: "reward".to_string(),
+    }));
+}
+
+#[test]
+pub fn proper_timelock() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    init(
+        &mut deps,
+        owner.clone(),
+        "reward".to_string(),
+        "token".to_string(),
+    );
+    let owner_info = mock_info(&owner, &[]);
+
+    // without any delay, the roles and the timelock are updated right away
+    let grant_guardian = ExecuteMsg::GrantRole {
+        role: Role::Guardian,
+        addr: "guardian".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), owner_info.clone(), grant_guardian).unwrap();
+
+    let update_timelock = ExecuteMsg::UpdateTimelock { delay: 100 };
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        update_timelock,
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![attr("action", "update_timelock"), attr("delay", "100")]
+    );
+    let timelock: TimelockResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Timelock {}).unwrap()).unwrap();
+    assert_eq!(
+        timelock,
+        TimelockResponse { delay: 100 }
+    );
+
+    // the config changes are now queued
+    let update_config = UpdateConfig {
+        reward_contract: Some("new_reward".to_string()),
+        token_contract: None,
+        lsd_asset: None,
+        reward_splits: None,
+        keeper_fee: None,
+        min_decompound_value: None,
+        min_update_interval: None,
+        mode: None,
+    };
+    let info = mock_info("guardian", &[]);
+    let err = execute(deps.as_mut(), mock_env(), info, update_config.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let env = mock_env();
+    let eta = env.block.time.seconds() + 100;
+    let res = execute(
+        deps.as_mut(),
+        env,
+        owner_info.clone(),
+        update_config.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "queue_config_change"),
+            attr("id", "0"),
+            attr("eta", eta.to_string()),
+        ]
+    );
+
+    let config: ConfigResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.reward_contract, Some("reward".to_string()));
+
+    let pending: PendingChangesResponse<ExecuteMsg> = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PendingChanges {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        pending.changes,
+        vec![PendingChange {
+            id: 0,
+            change: update_config.clone(),
+            eta,
+        }]
+    );
+
+    // the change can't be executed before its eta
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::ExecuteConfigChange { id: 0 },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err(format!(
+            "the config change can't be executed before {}",
+            eta
+        )))
+    );
+
+    // the guardian can cancel the changes, along with the owner
+    execute(deps.as_mut(), mock_env(), owner_info.clone(), update_config).unwrap();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::CancelConfigChange { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::CancelConfigChange { id: 1 },
+    )
+    .unwrap();
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(100);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        owner_info.clone(),
+        ExecuteMsg::ExecuteConfigChange { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err("no pending config change with id 1"))
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        env,
+        owner_info.clone(),
+        ExecuteMsg::ExecuteConfigChange { id: 0 },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![attr("action", "update_config"), attr("id", "0")]
+    );
+
+    let config: ConfigResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.reward_contract, Some("new_reward".to_string()));
+
+    // the ownership proposals are queued too, so the guardian can cancel them
+    let propose = ExecuteMsg::ProposeNewOwner {
+        owner: "new_owner".to_string(),
+        expires_in: 100,
+    };
+    let res = execute(deps.as_mut(), mock_env(), owner_info.clone(), propose).unwrap();
+    assert_eq!(res.attributes[0], attr("action", "queue_config_change"));
+    let pending_owner: PendingOwnerResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::PendingOwner {}).unwrap()).unwrap();
+    assert_eq!(pending_owner.pending_owner, None);
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("new_owner", &[]),
+        ExecuteMsg::ClaimOwnership {},
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err("no ownership proposal"))
+    );
+
+    // once applied, the guardian can still drop the proposal before it is claimed
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(100);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner_info.clone(),
+        ExecuteMsg::ExecuteConfigChange { id: 2 },
+    )
+    .unwrap();
+    let pending_owner: PendingOwnerResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::PendingOwner {}).unwrap())
+            .unwrap();
+    assert_eq!(pending_owner.pending_owner, Some("new_owner".to_string()));
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::DropOwnershipProposal {},
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("new_owner", &[]),
+        ExecuteMsg::ClaimOwnership {},
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err("no ownership proposal"))
+    );
+
+    // the revocations aren't queued, so the guardian can't cancel its own
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info,
+        ExecuteMsg::RevokeRole {
+            role: Role::Guardian,
+            addr: "guardian".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(res.attributes[0], attr("action", "revoke_role"));
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::DropOwnershipProposal {},
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+pub fn proper_timelocked_assets_and_hooks() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    init(
+        &mut deps,
+        owner.clone(),
+        "reward".to_string(),
+        "token".to_string(),
+    );
+    let owner_info = mock_info(&owner, &[]);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::UpdateTimelock { delay: 100 },
+    )
+    .unwrap();
+
+    // the assets and hooks can't be changed before the delay has passed
+    let register_msg = ExecuteMsg::RegisterAssets {
+        assets: vec![NewHubAsset {
+            token_contract: "token2".to_string(),
+            reward_contract: "reward2".to_string(),
+            mode: HubMode::SwapRewards,
+            lsd_asset: None,
+        }],
+    };
+    let info = mock_info("invalid_owner", &[]);
+    let err = execute(deps.as_mut(), mock_env(), info, register_msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let res = execute(deps.as_mut(), mock_env(), owner_info.clone(), register_msg).unwrap();
+    assert_eq!(res.attributes[0], attr("action", "queue_config_change"));
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::AddHook {
+            addr: "overseer".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(res.attributes[0], attr("action", "queue_config_change"));
+
+    let query_assets = QueryMsg::Assets {
+        start_after: None,
+        limit: None,
+    };
+    let assets: AssetsResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), query_assets.clone()).unwrap()).unwrap();
+    assert_eq!(assets.assets, vec![]);
+    let hooks: HooksResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Hooks {}).unwrap()).unwrap();
+    assert_eq!(hooks.hooks, Vec::<String>::new());
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::ExecuteConfigChange { id: 0 },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err(format!(
+            "the config change can't be executed before {}",
+            mock_env().block.time.seconds() + 100
+        )))
+    );
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(100);
+    for id in 0..2 {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            ExecuteMsg::ExecuteConfigChange { id },
+        )
+        .unwrap();
+    }
+    let assets: AssetsResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), query_assets.clone()).unwrap()).unwrap();
+    assert_eq!(assets.assets.len(), 1);
+    assert_eq!(assets.assets[0].reward_contract, "reward2".to_string());
+    let hooks: HooksResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::Hooks {}).unwrap()).unwrap();
+    assert_eq!(hooks.hooks, vec!["overseer".to_string()]);
+
+    // the removals are queued as well
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner_info.clone(),
+        ExecuteMsg::DeregisterAsset { id: 0 },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner_info.clone(),
+        ExecuteMsg::RemoveHook {
+            addr: "overseer".to_string(),
+        },
+    )
+    .unwrap();
+    let assets: AssetsResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), query_assets.clone()).unwrap()).unwrap();
+    assert_eq!(assets.assets.len(), 1);
+    let hooks: HooksResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::Hooks {}).unwrap()).unwrap();
+    assert_eq!(hooks.hooks, vec!["overseer".to_string()]);
+
+    env.block.time = env.block.time.plus_seconds(100);
+    for id in 2..4 {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            ExecuteMsg::ExecuteConfigChange { id },
+        )
+        .unwrap();
+    }
+    let assets: AssetsResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), query_assets).unwrap()).unwrap();
+    assert_eq!(assets.assets, vec![]);
+    let hooks: HooksResponse =
+        from_binary(&query(deps.as_ref(), env, QueryMsg::Hooks {}).unwrap()).unwrap();
+    assert_eq!(hooks.hooks, Vec::<String>::new());
+}
+
+#[test]
+pub fn proper_roles() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    init(
+        &mut deps,
+        owner.clone(),
+        "reward".to_string(),
+        "token".to_string(),
+    );
+    let owner_info = mock_info(&owner, &[]);
+
+    // only the owner grants the roles
+    let grant_keeper = ExecuteMsg::GrantRole {
+        role: Role::Keeper,
+        addr: "keeper".to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("keeper", &[]),
+        grant_keeper.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::GrantRole {
+            role: Role::Owner,
+            addr: "keeper".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err(
+            "the owner role is transferred with an ownership proposal"
+        ))
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        grant_keeper.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "grant_role"),
+            attr("role", "keeper"),
+            attr("addr", "keeper"),
+        ]
+    );
+    let err = execute(deps.as_mut(), mock_env(), owner_info.clone(), grant_keeper).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err(
+            "keeper already holds the keeper role"
+        ))
+    );
+
+    let holders: RoleHoldersResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::RoleHolders { role: Role::Keeper },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        holders,
+        RoleHoldersResponse {
+            role: Role::Keeper,
+            holders: vec!["keeper".to_string()],
+        }
+    );
+
+    // once a keeper is set, only the keepers and the owner can update the global index
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr1000", &[]),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("keeper", &[]),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+
+    // the update is open to anyone again without any keeper
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info,
+        ExecuteMsg::RevokeRole {
+            role: Role::Keeper,
+            addr: "keeper".to_string(),
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr1000", &[]),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+}
+
+#[test]
+pub fn proper_pause() {
+    let mut deps = dependencies(&[]);
+
+    let owner = "owner1".to_string();
+    init(
+        &mut deps,
+        owner.clone(),
+        "reward".to_string(),
+        "token".to_string(),
+    );
+    let owner_info = mock_info(&owner, &[]);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        owner_info.clone(),
+        ExecuteMsg::GrantRole {
+            role: Role::Guardian,
+            addr: "guardian".to_string(),
+        },
+    )
+    .unwrap();
+
+    // only the updates can be paused on the hub
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::Pause {
+            operations: vec![Operation::Update, Operation::Mint],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err(
+            "mint can't be paused on this contract"
+        ))
+    );
+
+    let pause = ExecuteMsg::Pause {
+        operations: vec![Operation::Update],
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr1000", &[]),
+        pause.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), mock_env(), mock_info("guardian", &[]), pause).unwrap();
+
+    let status: PauseStatusResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::PauseStatus {}).unwrap()).unwrap();
+    assert_eq!(status.paused, vec![Operation::Update]);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr1000", &[]),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Paused(Paused {
+            operation: Operation::Update
+        })
+    );
+
+    // only the owner can resume the updates
+    let unpause = ExecuteMsg::Unpause {
+        operations: vec![Operation::Update],
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        unpause.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let res = execute(deps.as_mut(), mock_env(), owner_info, unpause).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![attr("action", "unpause"), attr("operations", "update")]
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr1000", &[]),
+        ExecuteMsg::UpdateGlobalIndex { ids: None },
+    )
+    .unwrap();
+}
+
 // sample MIR claim msg
 #[cw_serde]
 pub enum MIRMsg {
@@ -0,0 +1,103 @@
+use crate::assets::{assert_owner, execute_deregister_asset, execute_register_assets};
+use crate::config::execute_update_config;
+use crate::hooks::{execute_add_hook, execute_remove_hook};
+use crate::ownership::execute_propose_new_owner;
+use crate::state::{ACCESS, CONFIG, TIMELOCK};
+use basset::access::Role;
+use basset::contract_error::ContractError;
+use basset::hub::ExecuteMsg;
+use basset::timelock::TimelockConfig;
+
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, StdError};
+
+/// Applies the config change right away when no delay is set, queues it otherwise.
+/// Only the owner can change the config
+pub fn execute_queue_config_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    change: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info)?;
+    TIMELOCK.queue_or_apply(deps, env, change, apply_config_change)
+}
+
+/// Only the owner can execute a queued change, once its delay has passed
+pub fn execute_config_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info)?;
+    TIMELOCK.apply_ready(deps, env, id, apply_config_change)
+}
+
+/// The owner and the guardians can cancel a queued change
+pub fn execute_cancel_config_change(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let owner = CONFIG.load(deps.storage)?.creator;
+    TIMELOCK.cancel_by_guardian(deps.storage, &ACCESS, &info.sender, &owner, id)
+}
+
+/// Only the owner can revoke a role. The revocation isn't queued,
+/// so that a guardian can't cancel its own
+pub fn execute_revoke_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    role: Role,
+    addr: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info)?;
+    Ok(ACCESS.revoke(deps, role, addr)?)
+}
+
+fn apply_config_change(
+    deps: DepsMut,
+    env: Env,
+    change: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match change {
+        ExecuteMsg::UpdateConfig {
+            reward_contract,
+            token_contract,
+            lsd_asset,
+            reward_splits,
+            keeper_fee,
+            min_decompound_value,
+            min_update_interval,
+            mode,
+        } => execute_update_config(
+            deps,
+            env,
+            reward_contract,
+            token_contract,
+            lsd_asset,
+            reward_splits,
+            keeper_fee,
+            min_decompound_value,
+            min_update_interval,
+            mode,
+        ),
+        ExecuteMsg::UpdateTimelock { delay } => {
+            TIMELOCK.set_config(deps.storage, &TimelockConfig { delay })?;
+
+            Ok(Response::new().add_attributes(vec![
+                attr("action", "update_timelock"),
+                attr("delay", delay.to_string()),
+            ]))
+        }
+        ExecuteMsg::GrantRole { role, addr } => Ok(ACCESS.grant(deps, role, addr)?),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
+            execute_propose_new_owner(deps, env, owner, expires_in)
+        }
+        ExecuteMsg::RegisterAssets { assets } => execute_register_assets(deps, env, assets),
+        ExecuteMsg::DeregisterAsset { id } => execute_deregister_asset(deps, id),
+        ExecuteMsg::AddHook { addr } => execute_add_hook(deps, addr),
+        ExecuteMsg::RemoveHook { addr } => execute_remove_hook(deps, addr),
+        _ => Err(StdError::generic_err("not a config change").into()),
+    }
+}
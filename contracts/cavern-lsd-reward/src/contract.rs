@@ -5,16 +5,33 @@ use cosmwasm_std::{Addr, StdError, Empty};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 
-use crate::global::execute_swap;
+use crate::global::{execute_swap, execute_update_global_index, query_simulate_swap};
+use crate::ownership::{execute_claim_ownership, execute_drop_ownership_proposal};
+use crate::pause::{execute_pause, execute_unpause};
 use crate::state::{
-    read_config, store_config, store_state, Config, State, SwapConfig, SWAP_CONFIG,
+    read_config, read_state, store_config, store_state, Config, DistributionMode, State,
+    SwapConfig, ACCESS, OWNERSHIP, PAUSE, SWAP_CONFIG, TIMELOCK,
+};
+use crate::stream::checkpoint_streams;
+use crate::timelock::{
+    execute_cancel_config_change, execute_config_change, execute_queue_config_change,
+    execute_revoke_role,
+};
+use crate::user::{
+    execute_balance_change_hook, execute_claim_rewards, query_accrued_rewards, query_holder,
+    query_holders, settle_custody,
 };
-use crate::user::{execute_claim_rewards, query_accrued_rewards};
 use cosmwasm_std::{
     to_binary, Binary, Decimal256, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
 };
 
+use basset::contract_error::ContractError;
 use basset::reward::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use basset::version::migrate_version;
+use cw2::set_contract_version;
+
+pub const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 fn has_unique_elements(list: &[String]) -> bool {
     let mut uniq = HashSet::new();
@@ -27,11 +44,11 @@ pub fn instantiate(
     _env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     if !has_unique_elements(&msg.known_tokens) {
-        return Err(StdError::generic_err(
+        return Err(ContractError::Std(StdError::generic_err(
             "Known tokens shouldn't contain duplicate assets",
-        ));
+        )));
     }
 
     let conf = Config {
@@ -45,6 +62,8 @@ pub fn instantiate(
             .iter()
             .map(|addr| deps.api.addr_validate(addr))
             .collect::<StdResult<Vec<Addr>>>()?,
+        distribution_mode: DistributionMode::Custody,
+        stream_duration: None,
     };
 
     store_config(deps.storage, &conf)?;
@@ -54,6 +73,8 @@ pub fn instantiate(
             global_index: Decimal256::zero(),
             total_balance: Uint128::zero(),
             prev_reward_balance: Uint128::zero(),
+            tracked_reward_balance: Uint128::zero(),
+            reward_streams: vec![],
         },
     )?;
 
@@ -66,56 +87,56 @@ pub fn instantiate(
         },
     )?;
 
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     Ok(Response::default())
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::ClaimRewards { recipient } => execute_claim_rewards(deps, env, info, recipient),
         ExecuteMsg::SwapToRewardDenom {} => execute_swap(deps, env, info),
-        ExecuteMsg::UpdateConfig {
-            owner,
-            custody_contract,
-            known_tokens,
-            astroport_addr,
-            phoenix_addr,
-            terraswap_addr,
-        } => set_custody_contract(
-            deps,
-            info,
-            owner,
-            custody_contract,
-            known_tokens,
-            astroport_addr,
-            phoenix_addr,
-            terraswap_addr,
-        ),
+        ExecuteMsg::UpdateGlobalIndex {} => execute_update_global_index(deps, env),
+        ExecuteMsg::BalanceChangeHook { changes } => {
+            execute_balance_change_hook(deps, env, info, changes)
+        }
+        ExecuteMsg::UpdateConfig { .. }
+        | ExecuteMsg::UpdateRecipients { .. }
+        | ExecuteMsg::UpdateTimelock { .. }
+        | ExecuteMsg::GrantRole { .. }
+        | ExecuteMsg::ProposeNewOwner { .. } => execute_queue_config_change(deps, env, info, msg),
+        ExecuteMsg::ExecuteConfigChange { id } => execute_config_change(deps, env, info, id),
+        ExecuteMsg::CancelConfigChange { id } => execute_cancel_config_change(deps, info, id),
+        ExecuteMsg::RevokeRole { role, addr } => execute_revoke_role(deps, info, role, addr),
+        ExecuteMsg::DropOwnershipProposal {} => execute_drop_ownership_proposal(deps, info),
+        ExecuteMsg::ClaimOwnership {} => execute_claim_ownership(deps, env, info),
+        ExecuteMsg::Pause { operations } => execute_pause(deps, info, operations),
+        ExecuteMsg::Unpause { operations } => execute_unpause(deps, info, operations),
     }
 }
 
+/// Only the owner can update the config, which is checked when the change is queued
 #[allow(clippy::too_many_arguments)]
 pub fn set_custody_contract(
-    deps: DepsMut,
-    info: MessageInfo,
-    owner: Option<String>,
+    mut deps: DepsMut,
+    env: Env,
     custody_contract: Option<String>,
     known_tokens: Option<Vec<String>>,
     astroport_addr: Option<String>,
     phoenix_addr: Option<String>,
     terraswap_addr: Option<String>,
-) -> StdResult<Response> {
+    token_contract: Option<String>,
+    stream_duration: Option<u64>,
+) -> Result<Response, ContractError> {
     let mut config = read_config(deps.storage)?;
     let mut swap_config = SWAP_CONFIG.load(deps.storage)?;
 
-    if info.sender != config.owner {
-        return Err(StdError::generic_err("unauthorized"));
-    }
-
-    if let Some(owner) = owner {
-        config.owner = deps.api.addr_validate(&owner)?;
-    }
-
     if let Some(custody_contract) = custody_contract {
         config.custody_contract = Some(deps.api.addr_validate(&custody_contract)?);
     }
@@ -134,9 +155,9 @@ pub fn set_custody_contract(
 
     if let Some(known_tokens) = known_tokens {
         if !has_unique_elements(&known_tokens) {
-            return Err(StdError::generic_err(
+            return Err(ContractError::Std(StdError::generic_err(
                 "Known tokens shouldn't contain duplicate assets",
-            ));
+            )));
         }
         config.known_cw20_tokens = known_tokens
             .iter()
@@ -144,10 +165,42 @@ pub fn set_custody_contract(
             .collect::<StdResult<Vec<Addr>>>()?
     }
 
+    let mut messages = vec![];
+    if let Some(token_contract) = token_contract {
+        // Holder balances are only meaningful for the token contract that reported them
+        if !read_state(deps.storage)?.total_balance.is_zero() {
+            return Err(ContractError::Std(StdError::generic_err(
+                "The token contract can't be changed while holders are registered",
+            )));
+        }
+        // The rewards received until now belong to the custody contract.
+        // Sharing between holders can't be turned back to custody, as the holder balances can't be cleared in bounded gas.
+        // The token contract is expected to sync the existing balances (`SyncHook`) before rewards come in
+        messages = settle_custody(deps.branch(), &env, &config)?;
+        config.distribution_mode = DistributionMode::Holders {
+            token_contract: deps.api.addr_validate(&token_contract)?,
+        };
+    }
+
+    if let Some(stream_duration) = stream_duration {
+        // Rewards received until now are registered with the previous duration
+        let mut state = read_state(deps.storage)?;
+        checkpoint_streams(deps.as_ref(), &env, &mut state)?;
+        store_state(deps.storage, &state)?;
+
+        config.stream_duration = if stream_duration == 0 {
+            None
+        } else {
+            Some(stream_duration)
+        };
+    }
+
     store_config(deps.storage, &config)?;
     SWAP_CONFIG.save(deps.storage, &swap_config)?;
 
-    Ok(Response::new().add_attribute("action", "set_custody_contract"))
+    Ok(Response::new()
+        .add_attribute("action", "set_custody_contract")
+        .add_messages(messages))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -157,6 +210,23 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::AccruedRewards { address } => {
             to_binary(&query_accrued_rewards(deps, env, address)?)
         }
+        QueryMsg::Holder { address } => to_binary(&query_holder(deps, env, address)?),
+        QueryMsg::Holders { start_after, limit } => {
+            to_binary(&query_holders(deps, start_after, limit)?)
+        }
+        QueryMsg::SimulateSwap { incoming } => {
+            to_binary(&query_simulate_swap(deps, env, incoming)?)
+        }
+        QueryMsg::Timelock {} => to_binary(&TIMELOCK.query_config(deps.storage)?),
+        QueryMsg::PendingOwner {} => to_binary(&OWNERSHIP.query_pending(deps.storage)?),
+        QueryMsg::RoleHolders { role } => {
+            let owner = read_config(deps.storage)?.owner;
+            to_binary(&ACCESS.query_holders(deps.storage, role, &owner)?)
+        }
+        QueryMsg::PauseStatus {} => to_binary(&PAUSE.query_status(deps.storage)?),
+        QueryMsg::PendingChanges { start_after, limit } => {
+            to_binary(&TIMELOCK.query_pending(deps.storage, start_after, limit)?)
+        }
     }
 }
 
@@ -166,6 +236,7 @@ fn query_config(deps: Deps) -> StdResult<Config> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: Empty) -> StdResult<Response> {
+pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, &[])?;
     Ok(Response::default())
 }
@@ -3,7 +3,11 @@ pub mod querier;
 pub mod state;
 
 mod global;
+mod ownership;
+mod pause;
+mod stream;
 mod swap;
+mod timelock;
 mod user;
 
 #[cfg(test)]
@@ -0,0 +1,28 @@
+use crate::state::{read_config, ACCESS, PAUSE};
+use crate::timelock::assert_owner;
+use basset::access::Role;
+use basset::contract_error::ContractError;
+use basset::pause::Operation;
+
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+/// The guardians and the owner can pause operations
+pub fn execute_pause(
+    deps: DepsMut,
+    info: MessageInfo,
+    operations: Vec<Operation>,
+) -> Result<Response, ContractError> {
+    let owner = read_config(deps.storage)?.owner;
+    ACCESS.assert_role(deps.storage, Role::Guardian, &info.sender, &owner)?;
+    Ok(PAUSE.pause(deps.storage, operations)?)
+}
+
+/// Only the owner can resume paused operations
+pub fn execute_unpause(
+    deps: DepsMut,
+    info: MessageInfo,
+    operations: Vec<Operation>,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info)?;
+    Ok(PAUSE.unpause(deps.storage, operations)?)
+}
@@ -1,12 +1,25 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Decimal256, Deps, Order, StdResult, Storage, Uint128};
 
-use basset::reward::HolderResponse;
+use basset::access::AccessControl;
+use basset::ownership::Ownership;
+use basset::pause::{Operation, PauseFlags};
+use basset::reward::{ExecuteMsg, HolderResponse};
+use basset::timelock::Timelock;
 use cw_storage_plus::{Bound, Item, Map};
 
 pub const STATE: Item<State> = Item::new("\u{0}\u{5}state");
 pub const CONFIG: Item<Config> = Item::new("\u{0}\u{6}config");
 pub const HOLDERS: Map<&Addr, Holder> = Map::new("holders");
+/// Config changes delayed by the timelock
+pub const TIMELOCK: Timelock<ExecuteMsg> =
+    Timelock::new("timelock", "pending_changes", "next_change_id");
+/// Owner proposed by the current one
+pub const OWNERSHIP: Ownership = Ownership::new("ownership_proposal");
+/// Guardians and keepers, by role
+pub const ACCESS: AccessControl = AccessControl::new("roles");
+/// Operations stopped by a guardian
+pub const PAUSE: PauseFlags = PauseFlags::new("paused", &[Operation::Swap, Operation::Claim]);
 
 // New mecanism specific variables
 pub const SWAP_CONFIG: Item<SwapConfig> = Item::new("swap_config");
@@ -27,8 +40,17 @@ pub struct Config {
     pub reward_denom: String,
 
     pub known_cw20_tokens: Vec<Addr>,
+
+    #[serde(default)]
+    pub distribution_mode: DistributionMode,
+
+    /// Rewards unlock linearly over this duration (in seconds) when set
+    #[serde(default)]
+    pub stream_duration: Option<u64>,
 }
 
+pub use basset::reward::RewardDistributionMode as DistributionMode;
+
 pub fn store_config(storage: &mut dyn Storage, config: &Config) -> StdResult<()> {
     CONFIG.save(storage, config)
 }
@@ -42,6 +64,20 @@ pub struct State {
     pub global_index: Decimal256,
     pub total_balance: Uint128,
     pub prev_reward_balance: Uint128,
+
+    /// Reward balance registered at the last checkpoint
+    #[serde(default)]
+    pub tracked_reward_balance: Uint128,
+    /// Rewards that are still unlocking
+    #[serde(default)]
+    pub reward_streams: Vec<RewardStream>,
+}
+
+#[cw_serde]
+pub struct RewardStream {
+    pub amount: Uint128,
+    pub start: u64,
+    pub end: u64,
 }
 
 pub fn store_state(storage: &mut dyn Storage, state: &State) -> StdResult<()> {
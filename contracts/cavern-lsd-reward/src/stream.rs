@@ -0,0 +1,45 @@
+use crate::state::{read_config, RewardStream, State};
+use cosmwasm_std::{Deps, Env, StdResult, Uint128};
+
+/// Registers the rewards received since the last checkpoint.
+/// When a stream duration is configured, they are locked and unlock linearly over that duration.
+/// Returns the part of the reward balance that is already unlocked
+pub fn checkpoint_streams(deps: Deps, env: &Env, state: &mut State) -> StdResult<Uint128> {
+    let config = read_config(deps.storage)?;
+    let reward_balance = deps
+        .querier
+        .query_balance(env.contract.address.clone(), config.reward_denom)?
+        .amount;
+    let now = env.block.time.seconds();
+
+    let new_rewards = reward_balance.saturating_sub(state.tracked_reward_balance);
+    if let Some(stream_duration) = config.stream_duration {
+        if !new_rewards.is_zero() {
+            state.reward_streams.push(RewardStream {
+                amount: new_rewards,
+                start: now,
+                end: now + stream_duration,
+            });
+        }
+    }
+    state.tracked_reward_balance = reward_balance;
+
+    // Finished streams don't lock anything anymore
+    state.reward_streams.retain(|stream| stream.end > now);
+
+    Ok(reward_balance.saturating_sub(locked_rewards(state, now)))
+}
+
+/// Returns the rewards that are still locked at the given time
+pub fn locked_rewards(state: &State, now: u64) -> Uint128 {
+    state
+        .reward_streams
+        .iter()
+        .filter(|stream| stream.end > now)
+        .map(|stream| {
+            stream
+                .amount
+                .multiply_ratio(stream.end - now.max(stream.start), stream.end - stream.start)
+        })
+        .sum()
+}
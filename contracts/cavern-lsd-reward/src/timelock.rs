@@ -0,0 +1,106 @@
+use crate::contract::set_custody_contract;
+use crate::ownership::execute_propose_new_owner;
+use crate::state::{read_config, ACCESS, TIMELOCK};
+use crate::user::execute_update_recipients;
+use basset::access::Role;
+use basset::contract_error::ContractError;
+use basset::reward::ExecuteMsg;
+use basset::timelock::TimelockConfig;
+
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, StdError, Storage};
+
+/// Applies the config change right away when no delay is set, queues it otherwise.
+/// Only the owner can change the config
+pub fn execute_queue_config_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    change: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info)?;
+    TIMELOCK.queue_or_apply(deps, env, change, apply_config_change)
+}
+
+/// Only the owner can execute a queued change, once its delay has passed
+pub fn execute_config_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info)?;
+    TIMELOCK.apply_ready(deps, env, id, apply_config_change)
+}
+
+/// The owner and the guardians can cancel a queued change
+pub fn execute_cancel_config_change(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let owner = read_config(deps.storage)?.owner;
+    TIMELOCK.cancel_by_guardian(deps.storage, &ACCESS, &info.sender, &owner, id)
+}
+
+/// Only the owner can revoke a role. The revocation isn't queued,
+/// so that a guardian can't cancel its own
+pub fn execute_revoke_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    role: Role,
+    addr: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info)?;
+    Ok(ACCESS.revoke(deps, role, addr)?)
+}
+
+pub(crate) fn assert_owner(storage: &dyn Storage, info: &MessageInfo) -> Result<(), ContractError> {
+    if info.sender != read_config(storage)?.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+fn apply_config_change(
+    deps: DepsMut,
+    env: Env,
+    change: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match change {
+        ExecuteMsg::UpdateConfig {
+            custody_contract,
+            known_tokens,
+            astroport_addr,
+            phoenix_addr,
+            terraswap_addr,
+            token_contract,
+            stream_duration,
+        } => set_custody_contract(
+            deps,
+            env,
+            custody_contract,
+            known_tokens,
+            astroport_addr,
+            phoenix_addr,
+            terraswap_addr,
+            token_contract,
+            stream_duration,
+        ),
+        ExecuteMsg::UpdateRecipients { recipients } => {
+            execute_update_recipients(deps, env, recipients)
+        }
+        ExecuteMsg::UpdateTimelock { delay } => {
+            TIMELOCK.set_config(deps.storage, &TimelockConfig { delay })?;
+
+            Ok(Response::new().add_attributes(vec![
+                attr("action", "update_timelock"),
+                attr("delay", delay.to_string()),
+            ]))
+        }
+        ExecuteMsg::GrantRole { role, addr } => Ok(ACCESS.grant(deps, role, addr)?),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
+            execute_propose_new_owner(deps, env, owner, expires_in)
+        }
+        _ => Err(StdError::generic_err("not a config change").into()),
+    }
+}
@@ -1,18 +1,30 @@
-use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
-use cw20_base::ContractError;
+use basset::contract_error::ContractError;
+use basset::version::{migrate_version, CW20_BASE_CONTRACT_NAME};
+use cosmwasm_std::{
+    entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+};
+use cw2::set_contract_version;
 
-use basset::wrapper::{ExecuteMsg, QueryMsg};
+use basset::wrapper::QueryMsg;
 use wrapper_implementations::coin_with_limit;
-use basset::reward::MigrateMsg;
+use basset::wrapper::MigrateMsg;
+
+pub const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Contracts that can be migrated to this one
+const REPLACED: &[&str] = &[CW20_BASE_CONTRACT_NAME, "crates.io:cavern-lsd-wrapper"];
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: coin_with_limit::StrideInitMsg,
 ) -> StdResult<Response> {
-    coin_with_limit::instantiate(deps, env, info, msg)
+    let res = coin_with_limit::instantiate(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(res)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -20,7 +32,7 @@ pub fn execute(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    msg: ExecuteMsg,
+    msg: coin_with_limit::StrideExecuteMsg,
 ) -> Result<Response, ContractError> {
     coin_with_limit::execute(deps, env, info, msg)
 }
@@ -31,6 +43,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
-    coin_with_limit::migrate(_deps, _env, _msg)
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, REPLACED)?;
+    Ok(coin_with_limit::migrate(deps, env, msg)?)
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use cosmwasm_schema::write_api;
 
 use wrapper_implementations::coin::StrideInitMsg;
-use cw20_base::msg::{ExecuteMsg, QueryMsg};
+use basset::wrapper::QueryMsg;
+use cw20_base::msg::ExecuteMsg;
 fn main() {
     write_api! {
         instantiate: StrideInitMsg,
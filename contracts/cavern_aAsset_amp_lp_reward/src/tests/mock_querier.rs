@@ -15,7 +15,7 @@ use cosmwasm_std::Uint128;
 use cw20::{Cw20QueryMsg, TokenInfoResponse};
 use std::collections::HashMap;
 
-use basset::hub::Config;
+use basset::hub::{Config, HubMode};
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::Empty;
 use cosmwasm_std::{
@@ -118,6 +118,10 @@ impl WasmMockQuerier {
                                 api.addr_validate(&String::from(MOCK_TOKEN_CONTRACT_ADDR))
                                     .unwrap(),
                             ),
+                            lsd_asset: None,
+                            reward_splits: vec![],
+                            keeper_fee: None,
+                            mode: HubMode::SwapRewards,
                         };
                         SystemResult::Ok(ContractResult::from(to_binary(&config)))
                     } else {
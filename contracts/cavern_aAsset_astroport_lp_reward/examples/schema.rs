@@ -0,0 +1,14 @@
+use anchor_basset_astroport_lp_reward::AstroportLPRetrieve;
+use basset::custom_reward::InstantiateMsg;
+use basset::reward::{ExecuteMsg, MigrateMsg, QueryMsg};
+
+use cosmwasm_schema::write_api;
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg<AstroportLPRetrieve>,
+        query: QueryMsg,
+        execute: ExecuteMsg,
+        migrate: MigrateMsg,
+    };
+}
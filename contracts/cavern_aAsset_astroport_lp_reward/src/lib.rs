@@ -0,0 +1,119 @@
+use cosmwasm_std::Reply;
+use cosmwasm_std::Binary;
+use astroport::asset::PairInfo;
+use astroport::pair::QueryMsg as PairQueryMsg;
+use basset::custom_reward::InstantiateMsg;
+
+use anchor_basset_custom_reward::state::read_retrieve_config;
+use basset::contract_error::ContractError;
+use basset::custom_reward::ExecuteWithSwapReply;
+use basset::version::migrate_version;
+
+use anchor_basset_custom_reward::contract;
+use astroport::pair::Cw20HookMsg;
+use basset::reward::MigrateMsg;
+use basset::reward::QueryMsg;
+use basset::reward::ExecuteMsg;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::DepsMut;
+use cosmwasm_std::{
+    to_binary, Addr, CosmosMsg, Deps, Env, MessageInfo, QueryRequest, Response, StdResult,
+    WasmQuery, entry_point
+};
+use cw2::set_contract_version;
+use cw20::BalanceResponse;
+use cw20::Cw20ExecuteMsg;
+use cw20::Cw20QueryMsg;
+
+#[cfg(test)]
+mod tests;
+
+pub const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cw_serde]
+pub struct RetrieveConfigRaw {
+    pair: String,
+}
+
+#[cw_serde]
+pub struct RetrieveConfig {
+    pair: Addr,
+}
+
+/// Withdraws the decompounded lp tokens from the pair, the swaps then turn both assets into the reward denom.
+/// The Astroport incentives are not claimed here: the wrapper harvests them to this contract on decompound
+#[cw_serde]
+pub struct AstroportLPRetrieve {}
+impl ExecuteWithSwapReply for AstroportLPRetrieve {
+    fn get_retrieve_messages(deps: Deps, env: Env) -> StdResult<Vec<CosmosMsg>> {
+        let config = read_retrieve_config::<RetrieveConfig>(deps.storage)?;
+
+        // We send the decompounded liquidity tokens to the pair and use a withdraw liquidity message
+        let pair_info: PairInfo = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: config.pair.to_string(),
+            msg: to_binary(&PairQueryMsg::Pair {})?,
+        }))?;
+
+        let lp_balance: BalanceResponse =
+            deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                contract_addr: pair_info.liquidity_token.to_string(),
+                msg: to_binary(&Cw20QueryMsg::Balance {
+                    address: env.contract.address.to_string(),
+                })?,
+            }))?;
+
+        if lp_balance.balance.is_zero() {
+            return Ok(vec![]);
+        }
+
+        Ok(vec![CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
+            contract_addr: pair_info.liquidity_token.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Send {
+                amount: lp_balance.balance,
+                contract: config.pair.to_string(),
+                msg: to_binary(&Cw20HookMsg::WithdrawLiquidity { assets: vec![] })?,
+            })?,
+            funds: vec![],
+        })])
+    }
+
+    fn validate_retrieve_config(
+        deps: Deps,
+        config: RetrieveConfigRaw,
+    ) -> StdResult<RetrieveConfig> {
+        let pair = deps.api.addr_validate(&config.pair)?;
+        Ok(RetrieveConfig { pair })
+    }
+
+    type RetrieveConfigRaw = RetrieveConfigRaw;
+    type RetrieveConfig = RetrieveConfig;
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(mut deps: DepsMut, env: Env, info: MessageInfo, msg: InstantiateMsg<AstroportLPRetrieve>) -> Result<Response, ContractError> {
+    let res = contract::instantiate::<AstroportLPRetrieve>(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(res)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> Result<Response, ContractError> {
+    contract::execute::<AstroportLPRetrieve>(deps, env, info, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    contract::query(deps, env, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    contract::reply(deps, env, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, &[])?;
+    contract::migrate(deps, env, msg)
+}
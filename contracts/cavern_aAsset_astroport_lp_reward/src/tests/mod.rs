@@ -0,0 +1,2 @@
+pub mod tests;
+pub mod mock_querier;
\ No newline at end of file
@@ -8,10 +8,13 @@ use basset::external::SpectrumExecuteMsg;
 use basset::external::SpectrumQueryMsg;
 
 use anchor_basset_custom_reward::state::read_retrieve_config;
+use basset::contract_error::ContractError;
 use basset::custom_reward::ExecuteWithSwapReply;
+use basset::version::migrate_version;
 
 use anchor_basset_custom_reward::contract;
 use astroport::pair::Cw20HookMsg;
+use basset::reward::MigrateMsg;
 use basset::reward::QueryMsg;
 use basset::reward::ExecuteMsg;
 use cosmwasm_schema::cw_serde;
@@ -20,11 +23,15 @@ use cosmwasm_std::{
     to_binary, Addr, CosmosMsg, Deps, Env, MessageInfo, QueryRequest, Response, StdResult,
     WasmQuery, entry_point
 };
+use cw2::set_contract_version;
 use cw20::Cw20ExecuteMsg;
 
 #[cfg(test)]
 mod tests;
 
+pub const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 #[cw_serde]
 pub struct RetrieveConfigRaw {
     spectrum_token: String,
@@ -101,12 +108,14 @@ impl ExecuteWithSwapReply for SpectrumRetrieve {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn instantiate(deps: DepsMut, env: Env, info: MessageInfo, msg: InstantiateMsg<SpectrumRetrieve>) -> StdResult<Response> {
-    contract::instantiate::<SpectrumRetrieve>(deps, env, info, msg)
+pub fn instantiate(mut deps: DepsMut, env: Env, info: MessageInfo, msg: InstantiateMsg<SpectrumRetrieve>) -> Result<Response, ContractError> {
+    let res = contract::instantiate::<SpectrumRetrieve>(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(res)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> Result<Response, ContractError> {
     contract::execute::<SpectrumRetrieve>(deps, env, info, msg)
 }
 
@@ -116,6 +125,12 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
    contract::reply(deps, env, msg)
 }
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, &[])?;
+    contract::migrate(deps, env, msg)
+}
@@ -11,7 +11,7 @@ use cosmwasm_std::CanonicalAddr;
 use cosmwasm_std::Uint128;
 use std::collections::HashMap;
 
-use basset::hub::Config;
+use basset::hub::{Config, HubMode};
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::Empty;
 use cosmwasm_std::{
@@ -112,6 +112,10 @@ impl WasmMockQuerier {
                                 api.addr_validate(&String::from(MOCK_TOKEN_CONTRACT_ADDR))
                                     .unwrap(),
                             ),
+                            lsd_asset: None,
+                            reward_splits: vec![],
+                            keeper_fee: None,
+                            mode: HubMode::SwapRewards,
                         };
                         SystemResult::Ok(ContractResult::from(to_binary(&config)))
                     } else {
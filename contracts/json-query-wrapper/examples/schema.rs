@@ -0,0 +1,11 @@
+use cosmwasm_schema::write_api;
+
+use basset::wrapper::QueryMsg;
+use wrapper_implementations::json_query::{JsonQueryExecuteMsg, JsonQueryInitMsg};
+fn main() {
+    write_api! {
+        instantiate: JsonQueryInitMsg,
+        query: QueryMsg,
+        execute: JsonQueryExecuteMsg,
+    };
+}
@@ -8,14 +8,18 @@ use cosmwasm_std::{
     QueryRequest, Response, StdResult, Uint128, WasmMsg, WasmQuery,
 };
 
+use cw2::set_contract_version;
 use cw20::BalanceResponse;
 use cw20::Cw20ExecuteMsg;
 use cw20::Cw20QueryMsg;
-use cw20_base::ContractError;
 
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
 use basset::external::CTokenStateResponse;
 use basset::external::SpectrumQueryMsg;
 use basset::external::UserInfoResponse;
+use basset::wrapper::MigrateMsg;
+use basset::version::{migrate_version, CW20_BASE_CONTRACT_NAME};
 use basset::wrapper::{ExecuteMsg, QueryMsg};
 
 use cavern_lsd_wrapper_token_with_limit::msg::TokenInitMsg;
@@ -63,7 +67,7 @@ impl SpectrumHub {
 }
 
 impl LSDHub<ContractsRaw> for SpectrumHub {
-    fn instantiate_config(deps: Deps, config: ContractsRaw) -> StdResult<Self> {
+    fn instantiate_config(deps: Deps, config: ContractsRaw) -> Result<Self, ContractError> {
         Ok(Self {
             contracts: Contracts {
                 generator: deps.api.addr_validate(&config.generator)?,
@@ -73,7 +77,7 @@ impl LSDHub<ContractsRaw> for SpectrumHub {
         })
     }
 
-    fn query_exchange_rate(&self, deps: Deps, _env: Env) -> StdResult<Decimal> {
+    fn query_exchange_rate(&self, deps: Deps, _env: Env) -> Result<Decimal, ContractError> {
         // For steak based LSD, we query the corresponding underlying token value of that LSD
         // This is located in the state query of the LSD
         let total_bond_share = self.query_total_bond_share(deps, &self.contracts)?;
@@ -82,13 +86,19 @@ impl LSDHub<ContractsRaw> for SpectrumHub {
         Ok(Decimal::from_ratio(total_bond_amount, total_bond_share))
     }
 
+    fn held_asset(&self) -> AssetInfo {
+        AssetInfo::Token {
+            contract_addr: self.contracts.token.clone(),
+        }
+    }
+
     fn get_balance(
         &self,
         deps: Deps,
         _env: Env,
         address: Addr,
         _funds: Vec<Coin>,
-    ) -> StdResult<Uint128> {
+    ) -> Result<Uint128, ContractError> {
         let balance: BalanceResponse =
             deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
                 contract_addr: self.contracts.token.to_string(),
@@ -106,7 +116,7 @@ impl LSDHub<ContractsRaw> for SpectrumHub {
         _info: MessageInfo,
         amount: Uint128,
         from: Addr,
-    ) -> StdResult<Vec<CosmosMsg>> {
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
         Ok(vec![CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: self.contracts.token.to_string(),
             msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
@@ -124,7 +134,7 @@ impl LSDHub<ContractsRaw> for SpectrumHub {
         _env: Env,
         amount: Uint128,
         to: Addr,
-    ) -> StdResult<Vec<CosmosMsg>> {
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
         Ok(vec![CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: self.contracts.token.to_string(),
             msg: to_binary(&Cw20ExecuteMsg::Transfer {
@@ -138,16 +148,25 @@ impl LSDHub<ContractsRaw> for SpectrumHub {
 
 pub type SpectrumInitMsg = TokenInitMsg<ContractsRaw>;
 
+pub const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Contracts that can be migrated to this one
+const REPLACED: &[&str] = &[CW20_BASE_CONTRACT_NAME];
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: SpectrumInitMsg,
 ) -> StdResult<Response> {
-    cavern_lsd_wrapper_token_with_limit::contract::instantiate::<ContractsRaw, SpectrumHub>(
-        deps, env, info, msg,
-    )
+    let res = cavern_lsd_wrapper_token_with_limit::contract::instantiate::<
+        ContractsRaw,
+        SpectrumHub,
+    >(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(res)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -155,7 +174,7 @@ pub fn execute(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    msg: ExecuteMsg,
+    msg: ExecuteMsg<ContractsRaw>,
 ) -> Result<Response, ContractError> {
     cavern_lsd_wrapper_token_with_limit::contract::execute::<ContractsRaw, SpectrumHub>(
         deps, env, info, msg,
@@ -168,3 +187,11 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         deps, env, msg,
     )
 }
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, REPLACED)?;
+    Ok(cavern_lsd_wrapper_token_with_limit::contract::migrate(
+        deps, env, msg,
+    )?)
+}
@@ -1,6 +1,6 @@
 use crate::tests::mock_deps::MOCK_GENERATOR_ADDR;
-use crate::{execute, instantiate, query, ContractsRaw};
-use basset::wrapper::ExecuteMsg;
+use crate::{execute, instantiate, migrate, query, ContractsRaw};
+use basset::wrapper::{ExecuteMsg, MigrateMsg, QueryMsg, TotalSupplyResponse};
 use cavern_lsd_wrapper_token_with_limit::state::DECOMPOUND_CONFIG;
 use cosmwasm_std::testing::{mock_env, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::testing::{mock_info, MockApi};
@@ -8,9 +8,9 @@ use cosmwasm_std::to_binary;
 use cosmwasm_std::MemoryStorage;
 use cosmwasm_std::OwnedDeps;
 use cosmwasm_std::{from_binary, CosmosMsg, SubMsg, WasmMsg};
-use cosmwasm_std::{Decimal, Uint128, StdError};
-use cw20::{BalanceResponse, Cw20ExecuteMsg};
-use cw20_base::ContractError;
+use cosmwasm_std::{Decimal, Uint128};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg};
+use basset::contract_error::ContractError;
 use std::str::FromStr;
 
 use crate::tests::mock_deps::mock_dependencies;
@@ -65,6 +65,8 @@ fn init_env(
         symbol: "w-S".to_string(),
 
         hub_contract: "hub".to_string(),
+        admin: None,
+        enable_snapshots: None,
 
         lsd_config: ContractsRaw {
             token: MOCK_SPECTRUM_TOKEN.to_string(),
@@ -292,7 +294,7 @@ fn test_decompound_same_time() {
     )
     .unwrap_err();
 
-    assert_eq!(err, ContractError::Std(StdError::generic_err("Can't decompound too often",)));
+    assert_eq!(err, ContractError::DecompoundTooOften {});
 }
 
 // What happens when we need to decompound (exchange rate changes)
@@ -391,4 +393,122 @@ fn test_decompound_with_limit() {
             274u128 - 1u128
         )]
     );
-}
\ No newline at end of file
+}
+#[test]
+fn test_balance_snapshots() {
+    let mut deps = mock_dependencies();
+    let instantiate_msg = SpectrumInitMsg {
+        decimals: 6,
+        initial_balances: vec![Cw20Coin {
+            address: "holder".to_string(),
+            amount: 500u128.into(),
+        }],
+        types: None,
+        name: "spectrum-wrapper".to_string(),
+        symbol: "w-S".to_string(),
+
+        hub_contract: "hub".to_string(),
+        admin: None,
+        enable_snapshots: Some(true),
+
+        lsd_config: ContractsRaw {
+            token: MOCK_SPECTRUM_TOKEN.to_string(),
+            generator: MOCK_GENERATOR_ADDR.to_string(),
+            underlying_token: "astroport-token".to_string(),
+        },
+        max_decompound_ratio: None,
+    };
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        instantiate_msg,
+    )
+    .unwrap();
+
+    let mut env = mock_env();
+    env.block.height += 1;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("depositor", &[]),
+        ExecuteMsg::MintWith {
+            recipient: "depositor".to_string(),
+            lsd_amount: 1_000_000u128.into(),
+        },
+    )
+    .unwrap();
+
+    let balance_at =
+        |deps: &OwnedDeps<MemoryStorage, MockApi, WasmMockQuerier>, address: &str, height: u64| {
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::BalanceAt {
+                    address: address.to_string(),
+                    height,
+                },
+            )
+            .map(|res| from_binary::<BalanceResponse>(&res).unwrap().balance)
+        };
+
+    // Snapshots are taken at the start of each block
+    assert_eq!(
+        balance_at(&deps, "depositor", env.block.height).unwrap(),
+        Uint128::zero()
+    );
+    assert_eq!(
+        balance_at(&deps, "depositor", env.block.height + 1).unwrap(),
+        Uint128::from(1_000_000u128)
+    );
+
+    // Accounts are seeded on their first change, untouched ones keep their initial balance
+    assert_eq!(
+        balance_at(&deps, "holder", env.block.height + 1).unwrap(),
+        Uint128::from(500u128)
+    );
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("holder", &[]),
+        ExecuteMsg::Transfer {
+            recipient: "depositor".to_string(),
+            amount: 200u128.into(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        balance_at(&deps, "holder", env.block.height).unwrap(),
+        Uint128::from(500u128)
+    );
+    assert_eq!(
+        balance_at(&deps, "holder", env.block.height + 1).unwrap(),
+        Uint128::from(300u128)
+    );
+
+    // Nothing is known before the snapshots were enabled
+    balance_at(&deps, "holder", env.block.height - 2).unwrap_err();
+
+    let total_supply: TotalSupplyResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::TotalSupplyAt {
+                height: env.block.height + 1,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(total_supply.total_supply, Uint128::from(1_000_500u128));
+
+    // Disabled snapshots can't be enabled again
+    let migrate_msg = |enable_snapshots: bool| MigrateMsg {
+        max_decompound_ratio: None,
+        remove_max_decompound_ratio: None,
+        admin: None,
+        enable_snapshots: Some(enable_snapshots),
+    };
+    migrate(deps.as_mut(), env.clone(), migrate_msg(false)).unwrap();
+    migrate(deps.as_mut(), env, migrate_msg(true)).unwrap_err();
+}
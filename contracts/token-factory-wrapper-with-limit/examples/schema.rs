@@ -0,0 +1,14 @@
+use cosmwasm_schema::write_api;
+
+use basset::wrapper::{MigrateMsg, QueryMsg};
+use wrapper_implementations::token_factory_with_limit::{
+    TokenFactoryExecuteMsg, TokenFactoryInitMsg,
+};
+fn main() {
+    write_api! {
+        instantiate: TokenFactoryInitMsg,
+        query: QueryMsg,
+        execute: TokenFactoryExecuteMsg,
+        migrate: MigrateMsg,
+    };
+}
@@ -0,0 +1,49 @@
+use basset::contract_error::ContractError;
+use basset::version::migrate_version;
+use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cw2::set_contract_version;
+
+use basset::wrapper::MigrateMsg;
+use basset::wrapper::QueryMsg;
+use wrapper_implementations::token_factory::HubStateResponse;
+use wrapper_implementations::token_factory_with_limit;
+
+pub const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Contracts that can be migrated to this one
+const REPLACED: &[&str] = &["crates.io:token-factory-wrapper"];
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: token_factory_with_limit::TokenFactoryInitMsg,
+) -> StdResult<Response> {
+    let res =
+        token_factory_with_limit::instantiate::<HubStateResponse>(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(res)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: token_factory_with_limit::TokenFactoryExecuteMsg,
+) -> Result<Response, ContractError> {
+    token_factory_with_limit::execute::<HubStateResponse>(deps, env, info, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    token_factory_with_limit::query::<HubStateResponse>(deps, env, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION, REPLACED)?;
+    Ok(token_factory_with_limit::migrate(deps, env, msg)?)
+}
@@ -0,0 +1,12 @@
+use cosmwasm_schema::write_api;
+
+use basset::wrapper::{MigrateMsg, QueryMsg};
+use wrapper_implementations::token_factory::{TokenFactoryExecuteMsg, TokenFactoryInitMsg};
+fn main() {
+    write_api! {
+        instantiate: TokenFactoryInitMsg,
+        query: QueryMsg,
+        execute: TokenFactoryExecuteMsg,
+        migrate: MigrateMsg,
+    };
+}
@@ -3,14 +3,14 @@ use cw_orch::{
     prelude::*,
 };
 
-use wrapper_implementations::coin::StrideInitMsg;
-use basset::wrapper::{ExecuteMsg, QueryMsg};
+use wrapper_implementations::coin::{StrideExecuteMsg, StrideInitMsg};
+use basset::wrapper::QueryMsg;
 
 use cavern_lsd_wrapper_with_limit::{instantiate, execute, query};
 
 use crate::WASM_SUFFIX;
 
-#[interface(StrideInitMsg, ExecuteMsg, QueryMsg, Empty)]
+#[interface(StrideInitMsg, StrideExecuteMsg, QueryMsg, Empty)]
 pub struct LsdWrapper;
 
 impl<Chain: CwEnv> Uploadable for LsdWrapperWithLimit<Chain> {
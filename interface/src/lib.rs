@@ -7,12 +7,10 @@ pub use basset::wrapper::{
 pub mod lsd_hub;
 pub use lsd_hub::LsdHub;
 pub use basset::hub::{
-    ExecuteMsgFns as HubExecuteMsgFns, QueryMsgFns as HubQueryMsgFns
+    ExecuteMsgFns as HubExecuteMsgFns, QueryMsgFns as HubQueryMsgFns,
+    MigrateMsg as HubMigrateMsg
 };
 
-pub mod lsd_hub_simple;
-pub use lsd_hub_simple::LsdHubSimple;
-
 
 pub mod lsd_reward;
 pub use lsd_reward::LsdRewards;
@@ -3,14 +3,14 @@ use cw_orch::{
     prelude::*,
 };
 use basset::hub::{
-    ExecuteMsg, OldInstantiateMsg, QueryMsg,
+    ExecuteMsg, MigrateMsg, OldInstantiateMsg, QueryMsg,
 };
 
-use cavern_lsd_hub::contract::{instantiate, execute, query, migrate};
+use cavern_lsd_hub::contract::{instantiate, execute, query, migrate, reply};
 
 use crate::WASM_SUFFIX;
 
-#[interface(OldInstantiateMsg, ExecuteMsg, QueryMsg, Empty)]
+#[interface(OldInstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg)]
 pub struct LsdHub;
 
 impl<Chain: CwEnv> Uploadable for LsdHub<Chain> {
@@ -29,6 +29,7 @@ impl<Chain: CwEnv> Uploadable for LsdHub<Chain> {
                 query,
             )
             .with_migrate(migrate)
+            .with_reply(reply)
         )
     }
 }
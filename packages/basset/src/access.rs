@@ -0,0 +1,155 @@
+use crate::contract_error::ContractError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{attr, Addr, DepsMut, Empty, Order, Response, StdError, StdResult, Storage};
+use cw_storage_plus::Map;
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum Role {
+    /// Stored by each contract and transferred in two steps, holds every other role
+    Owner,
+    /// Can pause the contract and cancel the queued config changes
+    Guardian,
+    /// Can trigger the updates of the hub.
+    /// Anyone can trigger them as long as no keeper is granted
+    Keeper,
+}
+
+impl Role {
+    fn key(&self) -> &'static str {
+        match self {
+            Role::Owner => "owner",
+            Role::Guardian => "guardian",
+            Role::Keeper => "keeper",
+        }
+    }
+}
+
+#[cw_serde]
+pub struct RoleHoldersResponse {
+    pub role: Role,
+    pub holders: Vec<String>,
+}
+
+/// Guardians and keepers granted by the owner of a contract
+pub struct AccessControl<'a> {
+    holders: Map<'a, (&'a str, &'a Addr), Empty>,
+}
+
+impl<'a> AccessControl<'a> {
+    pub const fn new(holders_key: &'a str) -> Self {
+        AccessControl {
+            holders: Map::new(holders_key),
+        }
+    }
+
+    pub fn has_role(
+        &self,
+        storage: &dyn Storage,
+        role: Role,
+        addr: &Addr,
+        owner: &Addr,
+    ) -> StdResult<bool> {
+        Ok(addr == owner || self.holders.has(storage, (role.key(), addr)))
+    }
+
+    pub fn assert_role(
+        &self,
+        storage: &dyn Storage,
+        role: Role,
+        sender: &Addr,
+        owner: &Addr,
+    ) -> Result<(), ContractError> {
+        if !self.has_role(storage, role, sender, owner)? {
+            return Err(ContractError::Unauthorized {});
+        }
+        Ok(())
+    }
+
+    /// Keeper operations start open to anyone and stay so until a keeper is granted.
+    /// Revoking every keeper opens them again
+    pub fn assert_keeper(
+        &self,
+        storage: &dyn Storage,
+        sender: &Addr,
+        owner: &Addr,
+    ) -> Result<(), ContractError> {
+        let restricted = self
+            .holders
+            .prefix(Role::Keeper.key())
+            .keys(storage, None, None, Order::Ascending)
+            .next()
+            .is_some();
+        if restricted {
+            self.assert_role(storage, Role::Keeper, sender, owner)?;
+        }
+        Ok(())
+    }
+
+    /// The caller must have checked that the sender is the owner.
+    /// The owner role itself is transferred with an ownership proposal
+    pub fn grant(&self, deps: DepsMut, role: Role, addr: String) -> StdResult<Response> {
+        let addr = validate_holder(&deps, role, addr)?;
+        if self.holders.has(deps.storage, (role.key(), &addr)) {
+            return Err(StdError::generic_err(format!(
+                "{} already holds the {} role",
+                addr,
+                role.key()
+            )));
+        }
+        self.holders
+            .save(deps.storage, (role.key(), &addr), &Empty {})?;
+
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "grant_role"),
+            attr("role", role.key()),
+            attr("addr", addr),
+        ]))
+    }
+
+    /// The caller must have checked that the sender is the owner
+    pub fn revoke(&self, deps: DepsMut, role: Role, addr: String) -> StdResult<Response> {
+        let addr = validate_holder(&deps, role, addr)?;
+        if !self.holders.has(deps.storage, (role.key(), &addr)) {
+            return Err(StdError::generic_err(format!(
+                "{} doesn't hold the {} role",
+                addr,
+                role.key()
+            )));
+        }
+        self.holders.remove(deps.storage, (role.key(), &addr));
+
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "revoke_role"),
+            attr("role", role.key()),
+            attr("addr", addr),
+        ]))
+    }
+
+    pub fn query_holders(
+        &self,
+        storage: &dyn Storage,
+        role: Role,
+        owner: &Addr,
+    ) -> StdResult<RoleHoldersResponse> {
+        let holders = match role {
+            Role::Owner => vec![owner.to_string()],
+            _ => self
+                .holders
+                .prefix(role.key())
+                .keys(storage, None, None, Order::Ascending)
+                .map(|holder| Ok(holder?.to_string()))
+                .collect::<StdResult<Vec<String>>>()?,
+        };
+        Ok(RoleHoldersResponse { role, holders })
+    }
+}
+
+fn validate_holder(deps: &DepsMut, role: Role, addr: String) -> StdResult<Addr> {
+    if role == Role::Owner {
+        return Err(StdError::generic_err(
+            "the owner role is transferred with an ownership proposal",
+        ));
+    }
+    deps.api.addr_validate(&addr)
+}
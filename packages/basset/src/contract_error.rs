@@ -1,6 +1,13 @@
-use cosmwasm_std::{OverflowError, StdError};
+use crate::pause::Paused;
+use cosmwasm_std::{OverflowError, StdError, Uint128};
+#[cfg(feature = "cw20-base")]
+use cw20_base::ContractError as Cw20ContractError;
 use thiserror::Error;
 
+/// Start of the stale oracle error, also read from the errors returned by other contracts
+pub const STALE_ORACLE_ERROR: &str = "the oracle price is too old";
+
+/// Errors returned by the hub and the wrapper contracts
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
     #[error("{0}")]
@@ -9,6 +16,14 @@ pub enum ContractError {
     #[error("{0}")]
     OverflowError(#[from] OverflowError),
 
+    /// Only with the `cw20-base` feature, enabled by the wrapper packages
+    #[cfg(feature = "cw20-base")]
+    #[error("{0}")]
+    Cw20(#[from] Cw20ContractError),
+
+    #[error("{0}")]
+    Paused(#[from] Paused),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -23,4 +38,60 @@ pub enum ContractError {
 
     #[error("Asset mismatch")]
     AssetMismatch {},
+
+    #[error("the reward contract must have been registered")]
+    MissingRewardContract {},
+
+    #[error("the token contract must have been registered")]
+    MissingTokenContract {},
+
+    #[error("the lsd asset must have been registered")]
+    MissingLsdAsset {},
+
+    #[error("asset {id} is not registered")]
+    AssetNotRegistered { id: u64 },
+
+    #[error("the global index was updated too recently")]
+    UpdateTooOften {},
+
+    #[error("not enough rewards to decompound: {value} < {min}")]
+    NotEnoughRewards { value: Uint128, min: Uint128 },
+
+    /// The wrapper is worth less than its underlying asset, nothing can be decompounded
+    #[error("No rewards to decompound")]
+    Slashed {},
+
+    #[error("Can't decompound too often")]
+    DecompoundTooOften {},
+
+    /// The rewards decompounded over time are above the max decompound ratio of the wrapper
+    #[error("the max decompound ratio is exceeded")]
+    LimitExceeded {},
+
+    #[error("{}, last updated at {last_updated}", STALE_ORACLE_ERROR)]
+    StaleOracle { last_updated: u64 },
+
+    #[error("can't migrate {actual} to {expected}")]
+    InvalidContractName { expected: String, actual: String },
+
+    #[error("can't migrate from version {previous} to the older version {new}")]
+    CannotDowngrade { previous: String, new: String },
+}
+
+impl ContractError {
+    /// Tells if an error returned by another contract is a `StaleOracle` one,
+    /// typed errors only cross the contract boundaries as strings
+    pub fn is_stale_oracle(error: &str) -> bool {
+        error.contains(STALE_ORACLE_ERROR)
+    }
+}
+
+/// Lets the queries and the contracts still returning a `StdResult` use the typed errors
+impl From<ContractError> for StdError {
+    fn from(err: ContractError) -> Self {
+        match err {
+            ContractError::Std(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
 }
@@ -108,7 +108,8 @@ pub struct SimulateSwapOperationsResponse {
     pub amount: Uint128,
 }
 
-#[derive(Clone, Copy, EnumIter)]
+#[cw_serde]
+#[derive(Copy, EnumIter)]
 pub enum SwapMessageType {
     Astroport,
     Phoenix,
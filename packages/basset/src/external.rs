@@ -1,3 +1,4 @@
+use astroport::asset::Asset;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Coin;
 use cosmwasm_std::Decimal;
@@ -80,4 +81,45 @@ pub struct RewardInfoResponseItem {
     pub deposit_time: u64,
     /// The deposit cost
     pub deposit_costs: Vec<Uint128>,
-}
\ No newline at end of file
+}
+
+// Astroport incentives (generator)
+// https://github.com/astroport-fi/astroport-core/blob/main/packages/astroport/src/generator.rs
+
+#[cw_serde]
+pub enum AstroportGeneratorExecuteMsg {
+    /// Sends the pending ASTRO and proxy rewards of the lp tokens to the sender
+    ClaimRewards {
+        lp_tokens: Vec<String>,
+    },
+    Withdraw {
+        lp_token: String,
+        amount: Uint128,
+    },
+}
+
+#[cw_serde]
+pub enum AstroportGeneratorCw20HookMsg {
+    Deposit {},
+}
+
+#[cw_serde]
+pub enum AstroportGeneratorQueryMsg {
+    /// Returns the amount of lp tokens staked by `user` as a `Uint128`
+    Deposit {
+        lp_token: String,
+        user: String,
+    },
+    PendingToken {
+        lp_token: String,
+        user: String,
+    },
+}
+
+#[cw_serde]
+pub struct PendingTokenResponse {
+    /// The pending amount of ASTRO
+    pub pending: Uint128,
+    /// The pending amount of 3rd party reward tokens
+    pub pending_on_proxy: Option<Vec<Asset>>,
+}
@@ -0,0 +1,25 @@
+use cosmwasm_std::{attr, Addr, Binary, Reply, Response, SubMsg, WasmMsg};
+
+/// Creates the messages sending `msg` to every hook.
+/// The errors come back in the `reply_id` reply, so a failing hook doesn't revert the operation
+pub fn hook_msgs(hooks: Vec<Addr>, msg: &Binary, reply_id: u64) -> Vec<SubMsg> {
+    hooks
+        .into_iter()
+        .map(|hook| {
+            SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: hook.to_string(),
+                    msg: msg.clone(),
+                    funds: vec![],
+                },
+                reply_id,
+            )
+        })
+        .collect()
+}
+
+/// Only reports the error of a failing hook
+pub fn hook_failed_response(msg: Reply) -> Response {
+    let error = msg.result.into_result().err().unwrap_or_default();
+    Response::new().add_attributes(vec![attr("action", "hook_failed"), attr("error", error)])
+}
@@ -1,11 +1,21 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use cosmwasm_std::{Addr, Binary, Decimal, Uint128};
+
+use crate::access::{Role, RoleHoldersResponse};
+use crate::dex_router::AssetInfo;
+use crate::ownership::PendingOwnerResponse;
+use crate::pause::{Operation, PauseStatusResponse};
+use crate::reward::SimulateSwapResponse;
+use crate::timelock::{PendingChangesResponse, TimelockResponse};
+use crate::wrapper::HooksResponse;
 
 pub type UnbondRequest = Vec<(u64, Uint128)>;
 
 #[cw_serde]
 pub struct OldInstantiateMsg {
-    pub reward_denom: String
+    pub reward_denom: String,
+    /// Defaults to swapping the rewards
+    pub mode: Option<HubMode>,
 }
 
 #[cw_serde]
@@ -14,10 +24,16 @@ pub struct InstantiateMsg {}
 #[cw_serde]
 #[derive(Default)]
 pub struct State {
+    /// Exchange rate of the wrapper, captured after each decompound
     pub exchange_rate: Decimal,
+    /// Underlying tokens backing the wrapper supply, captured after each decompound
     pub total_bond_amount: Uint128,
     pub last_index_modification: u64,
+    /// LSD decompounded during the last global index update
     pub prev_hub_balance: Uint128,
+    /// LSD decompounded since the state started being tracked
+    #[serde(default)]
+    pub total_decompounded: Uint128,
 }
 
 #[cw_serde]
@@ -25,6 +41,83 @@ pub struct Config {
     pub creator: Addr,
     pub reward_contract: Option<Addr>,
     pub token_contract: Option<Addr>, // This is the address of the LSD Wrapper
+    /// LSD token decompounded by the wrapper. Needed to forward the rewards when they are split
+    #[serde(default)]
+    pub lsd_asset: Option<AssetInfo>,
+    /// Shares of the decompounded rewards. When empty, everything is sent to the reward contract
+    #[serde(default)]
+    pub reward_splits: Vec<RewardSplit<Addr>>,
+    /// Fee paid to the caller of UpdateGlobalIndex out of the decompounded rewards
+    #[serde(default)]
+    pub keeper_fee: Option<KeeperFee>,
+    /// How the rewards of the asset set in the config are handled
+    #[serde(default)]
+    pub mode: HubMode,
+}
+
+/// Bounty rewarding whoever keeps the global index updated
+#[cw_serde]
+pub struct KeeperFee {
+    /// Share of the decompounded LSD paid to the caller
+    pub ratio: Decimal,
+    /// Maximum amount of LSD paid to the caller
+    pub cap: Uint128,
+    /// Minimum number of seconds between two global index updates,
+    /// also applied to the registered assets
+    pub min_interval: u64,
+    /// Minimum amount of LSD that must be decompounded for the update to succeed.
+    /// The update is skipped when the wrapper has less than this pending.
+    /// The LSD already held by the hub doesn't count
+    pub min_decompound: Uint128,
+}
+
+/// Share of the decompounded LSD rewards forwarded to a recipient
+#[cw_serde]
+pub struct RewardSplit<A> {
+    pub recipient: A,
+    pub share: Decimal,
+}
+
+/// How the rewards of an asset are handled on every global index update
+#[cw_serde]
+#[derive(Default)]
+pub enum HubMode {
+    /// The rewards are decompounded to the reward contract, which swaps them to the reward denom
+    #[default]
+    SwapRewards,
+    /// The rewards are only decompounded to the reward contract
+    DecompoundOnly,
+    /// The rewards are decompounded to the reward contract, then the given messages are sent
+    Custom { msgs: Vec<FollowUpMsg> },
+}
+
+/// Message sent by the hub after the decompound, in the custom mode
+#[cw_serde]
+pub struct FollowUpMsg {
+    pub contract_addr: String,
+    pub msg: Binary,
+}
+
+/// Wrapper and reward contract pair updated by the hub, on top of the primary one in the config
+#[cw_serde]
+pub struct HubAsset {
+    pub token_contract: Addr,
+    pub reward_contract: Addr,
+    pub mode: HubMode,
+    pub last_index_modification: u64,
+    /// LSD decompounded by the wrapper, needed to pay the keeper fee and split the rewards
+    #[serde(default)]
+    pub lsd_asset: Option<AssetInfo>,
+}
+
+#[cw_serde]
+pub struct NewHubAsset {
+    pub token_contract: String,
+    pub reward_contract: String,
+    pub mode: HubMode,
+    /// Required when the hub forwards the rewards, otherwise they go to the reward contract
+    #[serde(default)]
+    pub lsd_asset: Option<AssetInfo>,
 }
 
 impl State {
@@ -45,19 +138,92 @@ pub enum ExecuteMsg {
     /// Owner's operations
     ////////////////////
 
-    /// Set the owner
+    /// Updates the config
+    /// Queued when a timelock delay is set
     UpdateConfig {
-        owner: Option<String>,
         reward_contract: Option<String>,
         token_contract: Option<String>,
+        lsd_asset: Option<AssetInfo>,
+        /// The shares must sum to 100%. An empty list sends everything to the reward contract
+        reward_splits: Option<Vec<RewardSplit<String>>>,
+        /// A zero ratio only keeps the minimum interval and decompound guards
+        keeper_fee: Option<KeeperFee>,
+        min_decompound_value: Option<Uint128>,
+        min_update_interval: Option<u64>,
+        /// How the rewards of the asset set in the config are handled
+        mode: Option<HubMode>,
+    },
+    /// Registers wrapper and reward contract pairs to update with the primary one,
+    /// queued like the config updates
+    RegisterAssets {
+        assets: Vec<NewHubAsset>,
+    },
+    /// Stops updating a registered asset, queued like the config updates
+    DeregisterAsset {
+        id: u64,
+    },
+    /// Registers a contract that gets notified after every global index update,
+    /// queued like the config updates
+    AddHook {
+        addr: String,
+    },
+    /// Stops notifying a registered hook, queued like the config updates
+    RemoveHook {
+        addr: String,
+    },
+    /// Sets the delay of the config changes.
+    /// Queued like the config updates when a delay is already set
+    UpdateTimelock {
+        delay: u64,
+    },
+    /// Applies a queued config change once its delay has passed
+    ExecuteConfigChange {
+        id: u64,
+    },
+    /// Drops a queued config change, also allowed for the guardians
+    CancelConfigChange {
+        id: u64,
+    },
+    /// Grants a guardian or keeper role, queued like the config updates
+    GrantRole {
+        role: Role,
+        addr: String,
+    },
+    /// Revokes a guardian or keeper role right away, so that the guardians can't cancel it
+    RevokeRole {
+        role: Role,
+        addr: String,
+    },
+    /// Only the guardians and the owner can call this. Stops the given operations right away
+    Pause {
+        operations: Vec<Operation>,
+    },
+    /// Only the owner can call this. Resumes the given operations
+    Unpause {
+        operations: Vec<Operation>,
+    },
+    /// Creates a request to change the contract's ownership, queued like the config updates
+    ProposeNewOwner {
+        /// The newly proposed owner
+        owner: String,
+        /// The validity period of the proposal to change the owner
+        expires_in: u64,
     },
+    /// Removes a request to change contract ownership, also allowed for the guardians
+    DropOwnershipProposal {},
+    /// Claims contract ownership
+    ClaimOwnership {},
 
     ////////////////////
     /// User's operations
     ////////////////////
 
     /// Update global index
-    UpdateGlobalIndex {},
+    /// Without ids, the primary asset and all the registered ones are updated.
+    /// Open to anyone until a keeper is granted
+    UpdateGlobalIndex {
+        ids: Option<Vec<u64>>,
+    },
     // Check whether the slashing has happened or not
     //CheckSlashing {},
 }
@@ -72,6 +238,47 @@ pub enum QueryMsg {
     State {},
     #[returns(Parameters)]
     Parameters {},
+    #[returns(AssetResponse)]
+    Asset { id: u64 },
+    #[returns(AssetsResponse)]
+    Assets {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(HooksResponse)]
+    Hooks {},
+    /// Simulates the update of the asset set in the config and of the first 30 registered assets
+    #[returns(SimulateUpdateGlobalIndexResponse)]
+    SimulateUpdateGlobalIndex {},
+    /// Checks that the wrapper and reward contracts of every asset are configured for this hub
+    #[returns(HealthResponse)]
+    Health {},
+    #[returns(TimelockResponse)]
+    Timelock {},
+    /// Addresses holding the given role
+    #[returns(RoleHoldersResponse)]
+    RoleHolders { role: Role },
+    /// Operations currently paused
+    #[returns(PauseStatusResponse)]
+    PauseStatus {},
+    /// Owner proposed by the current one, until it claims the ownership
+    #[returns(PendingOwnerResponse)]
+    PendingOwner {},
+    /// Config changes waiting for their delay to pass, by id
+    #[returns(PendingChangesResponse<ExecuteMsg>)]
+    PendingChanges {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {
+    /// Pairs previously managed by their own hub, registered during the migration
+    #[serde(default)]
+    pub register_assets: Vec<NewHubAsset>,
+    /// Only used when migrating a decompound-only hub, which doesn't store any parameters
+    pub reward_denom: Option<String>,
 }
 
 #[cw_serde]
@@ -79,6 +286,16 @@ pub enum Cw20HookMsg {
     Unbond {},
 }
 
+/// Message sent to every registered hook once the rewards of a wrapper were decompounded and handled
+#[cw_serde]
+pub enum HubUpdatedHookMsg {
+    HubUpdated {
+        token: String,
+        decompounded: Uint128,
+        timestamp: u64,
+    },
+}
+
 #[cw_serde]
 pub struct UnbondHistory {
     pub batch_id: u64,
@@ -95,6 +312,7 @@ pub struct StateResponse {
     pub total_bond_amount: Uint128,
     pub last_index_modification: u64,
     pub prev_hub_balance: Uint128,
+    pub total_decompounded: Uint128,
 }
 
 #[cw_serde]
@@ -102,6 +320,90 @@ pub struct ConfigResponse {
     pub owner: String,
     pub reward_contract: Option<String>,
     pub token_contract: Option<String>,
+    pub lsd_asset: Option<AssetInfo>,
+    pub reward_splits: Vec<RewardSplit<String>>,
+    pub keeper_fee: Option<KeeperFee>,
+    pub mode: HubMode,
+}
+
+#[cw_serde]
+pub struct SimulateUpdateGlobalIndexResponse {
+    /// LSD the wrapper would decompound
+    pub lsd_to_decompound: Uint128,
+    /// LSD paid to the caller
+    pub keeper_fee: Uint128,
+    /// LSD the reward contract would receive
+    pub lsd_to_reward_contract: Uint128,
+    /// Swaps done by the reward contract, none unless the rewards are swapped.
+    /// The received LSD is only simulated when the lsd asset is registered
+    pub swap: Option<SimulateSwapResponse>,
+    /// Reason why the update would be refused or skipped
+    pub blocked_by: Option<String>,
+    /// Same for the registered assets
+    #[serde(default)]
+    pub assets: Vec<SimulateAssetUpdateResponse>,
+}
+
+/// Dry-run of the update of a registered asset
+#[cw_serde]
+pub struct SimulateAssetUpdateResponse {
+    pub id: u64,
+    pub lsd_to_decompound: Uint128,
+    pub keeper_fee: Uint128,
+    pub lsd_to_reward_contract: Uint128,
+    pub swap: Option<SimulateSwapResponse>,
+    pub blocked_by: Option<String>,
+}
+
+#[cw_serde]
+pub struct HealthResponse {
+    pub healthy: bool,
+    pub findings: Vec<HealthFinding>,
+}
+
+/// Misconfiguration that would make the global index update fail or misbehave
+#[cw_serde]
+pub enum HealthFinding {
+    /// The reward or token contract is not set in the config
+    MissingContract { contract: String },
+    /// The wrapper only accepts decompounds from another hub
+    WrapperHubMismatch {
+        wrapper: String,
+        hub: Option<String>,
+    },
+    /// The reward contract only accepts swaps from another hub
+    RewardHubMismatch {
+        reward_contract: String,
+        hub: String,
+    },
+    /// The reward contract has no custody contract to give its rewards to
+    MissingCustody { reward_contract: String },
+    /// Wrapper tokens were minted, but they are not backed by anything
+    ZeroBacking { wrapper: String },
+    /// The oracle pricing the tokens held by the wrapper is stale
+    StaleOracle { wrapper: String },
+    /// The wrapper exchange rate can't be queried
+    WrapperQueryFailed { wrapper: String, error: String },
+    /// The reward contract config can't be queried
+    RewardQueryFailed {
+        reward_contract: String,
+        error: String,
+    },
+}
+
+#[cw_serde]
+pub struct AssetResponse {
+    pub id: u64,
+    pub token_contract: String,
+    pub reward_contract: String,
+    pub mode: HubMode,
+    pub last_index_modification: u64,
+    pub lsd_asset: Option<AssetInfo>,
+}
+
+#[cw_serde]
+pub struct AssetsResponse {
+    pub assets: Vec<AssetResponse>,
 }
 
 #[cw_serde]
@@ -134,4 +436,10 @@ pub struct AllHistoryResponse {
 #[cw_serde]
 pub struct Parameters {
     pub reward_denom: String,
+    /// The global index update is skipped when the wrapper has less LSD than this to decompound
+    #[serde(default)]
+    pub min_decompound_value: Uint128,
+    /// Minimum number of seconds between two global index updates
+    #[serde(default)]
+    pub min_update_interval: u64,
 }
\ No newline at end of file
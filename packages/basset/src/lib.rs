@@ -1,11 +1,17 @@
+pub mod access;
 pub mod contract_error;
 pub mod custom_reward;
 pub mod dex_router;
 pub mod external;
+pub mod hooks;
 pub mod hub;
 pub mod oracle;
+pub mod ownership;
+pub mod pause;
 pub mod price_querier;
 pub mod reward;
+pub mod timelock;
+pub mod version;
 pub mod wrapper;
 pub mod eris_lp;
 
@@ -0,0 +1,114 @@
+use crate::access::{AccessControl, Role};
+use crate::contract_error::ContractError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{attr, Addr, DepsMut, Env, MessageInfo, Response, StdError, StdResult, Storage};
+use cw_storage_plus::Item;
+
+/// Longest validity of an ownership proposal, 14 days
+pub const MAX_PROPOSAL_TTL: u64 = 1_209_600;
+
+#[cw_serde]
+pub struct OwnershipProposal {
+    pub owner: Addr,
+    /// Time (in seconds) after which the proposal can't be claimed anymore
+    pub expires_at: u64,
+}
+
+#[cw_serde]
+pub struct PendingOwnerResponse {
+    pub pending_owner: Option<String>,
+    pub expires_at: Option<u64>,
+}
+
+/// Two-step ownership transfer, the proposed owner has to claim the ownership before it expires.
+/// The owner itself is stored by each contract
+pub struct Ownership<'a> {
+    proposal: Item<'a, OwnershipProposal>,
+}
+
+impl<'a> Ownership<'a> {
+    pub const fn new(proposal_key: &'a str) -> Self {
+        Ownership {
+            proposal: Item::new(proposal_key),
+        }
+    }
+
+    /// Replaces any previous proposal, its validity starts when it is applied.
+    /// The caller must have checked that the sender is the owner
+    pub fn propose(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        owner: &Addr,
+        new_owner: String,
+        expires_in: u64,
+    ) -> Result<Response, ContractError> {
+        let new_owner = deps.api.addr_validate(&new_owner)?;
+        if new_owner == *owner {
+            return Err(StdError::generic_err("the new owner can't be the current one").into());
+        }
+        if expires_in > MAX_PROPOSAL_TTL {
+            return Err(StdError::generic_err(format!(
+                "the proposal can't be valid for more than {} seconds",
+                MAX_PROPOSAL_TTL
+            ))
+            .into());
+        }
+
+        let proposal = OwnershipProposal {
+            owner: new_owner,
+            expires_at: env.block.time.seconds() + expires_in,
+        };
+        self.proposal.save(deps.storage, &proposal)?;
+
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "propose_new_owner"),
+            attr("new_owner", proposal.owner),
+            attr("expires_at", proposal.expires_at.to_string()),
+        ]))
+    }
+
+    /// The owner and the guardians can drop the proposal before it is claimed
+    pub fn drop_proposal(
+        &self,
+        storage: &mut dyn Storage,
+        access: &AccessControl,
+        sender: &Addr,
+        owner: &Addr,
+    ) -> Result<Response, ContractError> {
+        access.assert_role(storage, Role::Guardian, sender, owner)?;
+        self.proposal.remove(storage);
+
+        Ok(Response::new().add_attribute("action", "drop_ownership_proposal"))
+    }
+
+    /// Returns the new owner, which the contract has to store
+    pub fn claim(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        info: &MessageInfo,
+    ) -> Result<Addr, ContractError> {
+        let proposal = self
+            .proposal
+            .may_load(storage)?
+            .ok_or_else(|| StdError::generic_err("no ownership proposal"))?;
+        if info.sender != proposal.owner {
+            return Err(ContractError::Unauthorized {});
+        }
+        if env.block.time.seconds() > proposal.expires_at {
+            return Err(StdError::generic_err("the ownership proposal expired").into());
+        }
+        self.proposal.remove(storage);
+
+        Ok(proposal.owner)
+    }
+
+    pub fn query_pending(&self, storage: &dyn Storage) -> StdResult<PendingOwnerResponse> {
+        let proposal = self.proposal.may_load(storage)?;
+        Ok(PendingOwnerResponse {
+            pending_owner: proposal.as_ref().map(|p| p.owner.to_string()),
+            expires_at: proposal.map(|p| p.expires_at),
+        })
+    }
+}
@@ -0,0 +1,152 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{attr, Empty, Response, StdError, StdResult, Storage};
+use cw_storage_plus::Map;
+use thiserror::Error;
+
+/// Operations that can be stopped during an incident
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum Operation {
+    /// Wrapper mints, with or without the lsd amount
+    Mint,
+    /// Wrapper burns, including the burns from an allowance
+    Burn,
+    /// Wrapper transfers and sends, including the ones from an allowance
+    Transfer,
+    /// Wrapper decompounds triggered by the hub
+    Decompound,
+    /// Hub global index updates
+    Update,
+    /// Reward swaps to the reward denom
+    Swap,
+    /// Reward claims
+    Claim,
+}
+
+impl Operation {
+    fn key(&self) -> &'static str {
+        match self {
+            Operation::Mint => "mint",
+            Operation::Burn => "burn",
+            Operation::Transfer => "transfer",
+            Operation::Decompound => "decompound",
+            Operation::Update => "update",
+            Operation::Swap => "swap",
+            Operation::Claim => "claim",
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+#[error("{operation} is paused")]
+pub struct Paused {
+    pub operation: Operation,
+}
+
+#[cw_serde]
+pub struct PauseStatusResponse {
+    pub paused: Vec<Operation>,
+}
+
+/// Pause flags of the operations a contract supports
+pub struct PauseFlags<'a> {
+    operations: &'a [Operation],
+    paused: Map<'a, &'a str, Empty>,
+}
+
+impl<'a> PauseFlags<'a> {
+    pub const fn new(paused_key: &'a str, operations: &'a [Operation]) -> Self {
+        PauseFlags {
+            operations,
+            paused: Map::new(paused_key),
+        }
+    }
+
+    pub fn is_paused(&self, storage: &dyn Storage, operation: Operation) -> bool {
+        self.paused.has(storage, operation.key())
+    }
+
+    pub fn assert_not_paused(
+        &self,
+        storage: &dyn Storage,
+        operation: Operation,
+    ) -> Result<(), Paused> {
+        if self.is_paused(storage, operation) {
+            return Err(Paused { operation });
+        }
+        Ok(())
+    }
+
+    /// The caller must have checked that the sender is a guardian
+    pub fn pause(
+        &self,
+        storage: &mut dyn Storage,
+        operations: Vec<Operation>,
+    ) -> StdResult<Response> {
+        self.validate(&operations)?;
+        for operation in &operations {
+            self.paused.save(storage, operation.key(), &Empty {})?;
+        }
+
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "pause"),
+            attr("operations", join(&operations)),
+        ]))
+    }
+
+    /// The caller must have checked that the sender is the owner
+    pub fn unpause(
+        &self,
+        storage: &mut dyn Storage,
+        operations: Vec<Operation>,
+    ) -> StdResult<Response> {
+        self.validate(&operations)?;
+        for operation in &operations {
+            self.paused.remove(storage, operation.key());
+        }
+
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "unpause"),
+            attr("operations", join(&operations)),
+        ]))
+    }
+
+    pub fn query_status(&self, storage: &dyn Storage) -> StdResult<PauseStatusResponse> {
+        let paused = self
+            .operations
+            .iter()
+            .filter(|operation| self.is_paused(storage, **operation))
+            .copied()
+            .collect();
+        Ok(PauseStatusResponse { paused })
+    }
+
+    fn validate(&self, operations: &[Operation]) -> StdResult<()> {
+        if operations.is_empty() {
+            return Err(StdError::generic_err("no operation given"));
+        }
+        if let Some(operation) = operations.iter().find(|o| !self.operations.contains(o)) {
+            return Err(StdError::generic_err(format!(
+                "{} can't be paused on this contract",
+                operation
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn join(operations: &[Operation]) -> String {
+    operations
+        .iter()
+        .map(|operation| operation.key())
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -1,3 +1,4 @@
+use crate::contract_error::ContractError;
 use crate::oracle::PriceResponse;
 use crate::oracle::QueryMsg as OracleQueryMsg;
 use cosmwasm_schema::cw_serde;
@@ -5,8 +6,6 @@ use cosmwasm_std::to_binary;
 use cosmwasm_std::Addr;
 use cosmwasm_std::Deps;
 use cosmwasm_std::QueryRequest;
-use cosmwasm_std::StdError;
-use cosmwasm_std::StdResult;
 use cosmwasm_std::WasmQuery;
 
 #[cw_serde]
@@ -21,7 +20,7 @@ pub fn query_price(
     base: String,
     quote: String,
     time_contraints: Option<TimeConstraints>,
-) -> StdResult<PriceResponse> {
+) -> Result<PriceResponse, ContractError> {
     let oracle_price: PriceResponse =
         deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
             contract_addr: oracle_addr.to_string(),
@@ -33,7 +32,11 @@ pub fn query_price(
         if oracle_price.last_updated_base < valid_update_time
             || oracle_price.last_updated_quote < valid_update_time
         {
-            return Err(StdError::generic_err("Price is too old"));
+            return Err(ContractError::StaleOracle {
+                last_updated: oracle_price
+                    .last_updated_base
+                    .min(oracle_price.last_updated_quote),
+            });
         }
     }
 
@@ -3,6 +3,13 @@ use cosmwasm_std::{Addr, Decimal};
 
 use cosmwasm_std::{Decimal256, Uint128};
 
+use crate::access::{Role, RoleHoldersResponse};
+use crate::dex_router::{AssetInfo, SwapMessageType};
+use crate::ownership::PendingOwnerResponse;
+use crate::pause::{Operation, PauseStatusResponse};
+use crate::timelock::{PendingChangesResponse, TimelockResponse};
+use crate::wrapper::BalanceChange;
+
 #[cw_serde]
 pub struct InstantiateMsg {
     pub hub_contract: String,
@@ -26,18 +33,65 @@ pub enum ExecuteMsg {
     SwapToRewardDenom {},
 
     /// Updates the contract config
+    /// Queued when a timelock delay is set
     UpdateConfig {
-        owner: Option<String>,
         custody_contract: Option<String>,
         known_tokens: Option<Vec<String>>,
         astroport_addr: Option<String>,
         phoenix_addr: Option<String>,
         terraswap_addr: Option<String>,
+        /// Setting the token contract shares the rewards between its holders
+        token_contract: Option<String>,
+        /// Duration (in seconds) over which received rewards unlock, 0 releases them right away
+        stream_duration: Option<u64>,
+    },
+
+    /// Shares the rewards between the recipients, proportionally to their weight.
+    /// Rewards accrued with the previous weights can still be claimed.
+    /// An empty list gives the rewards received afterwards back to the custody contract.
+    /// Queued when a timelock delay is set
+    UpdateRecipients { recipients: Vec<RewardRecipient> },
+
+    /// Sets the delay of the config changes.
+    /// Queued like the config updates when a delay is already set
+    UpdateTimelock { delay: u64 },
+    /// Applies a queued config change once its delay has passed
+    ExecuteConfigChange { id: u64 },
+    /// Drops a queued config change, also allowed for the guardians
+    CancelConfigChange { id: u64 },
+    /// Grants a guardian or keeper role, queued like the config updates
+    GrantRole { role: Role, addr: String },
+    /// Revokes a guardian or keeper role right away, so that the guardians can't cancel it
+    RevokeRole { role: Role, addr: String },
+    /// Only the guardians and the owner can call this. Stops the given operations right away
+    Pause { operations: Vec<Operation> },
+    /// Only the owner can call this. Resumes the given operations
+    Unpause { operations: Vec<Operation> },
+    /// Creates a request to change the contract's ownership, queued like the config updates
+    ProposeNewOwner {
+        /// The newly proposed owner
+        owner: String,
+        /// The validity period of the proposal to change the owner
+        expires_in: u64,
     },
+    /// Removes a request to change contract ownership, also allowed for the guardians
+    DropOwnershipProposal {},
+    /// Claims contract ownership
+    ClaimOwnership {},
+
+    ////////////////////
+    /// Token contract's operations
+    ///////////////////
+
+    /// Sets the reward balances of holders, sent by the token contract when registered as a hook
+    BalanceChangeHook { changes: Vec<BalanceChange> },
+
     ////////////////////
     /// User's operations
     ///////////////////
 
+    /// Distribute the rewards received since the last update between holders
+    UpdateGlobalIndex {},
     /// return the accrued reward in uusd to the user.
     ClaimRewards { recipient: Option<String> },
 }
@@ -51,6 +105,35 @@ pub enum QueryMsg {
     Config {},
     #[returns(AccruedRewardsResponse)]
     AccruedRewards { address: String },
+    #[returns(HolderResponse)]
+    Holder { address: String },
+    #[returns(HoldersResponse)]
+    Holders {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Simulates SwapToRewardDenom with the current balances and the given incoming rewards.
+    /// The reward contracts retrieving their rewards from other tokens first
+    /// only simulate the swap of what they already hold
+    #[returns(SimulateSwapResponse)]
+    SimulateSwap { incoming: Vec<Asset> },
+    #[returns(TimelockResponse)]
+    Timelock {},
+    /// Addresses holding the given role
+    #[returns(RoleHoldersResponse)]
+    RoleHolders { role: Role },
+    /// Operations currently paused
+    #[returns(PauseStatusResponse)]
+    PauseStatus {},
+    /// Owner proposed by the current one, until it claims the ownership
+    #[returns(PendingOwnerResponse)]
+    PendingOwner {},
+    /// Config changes waiting for their delay to pass, by id
+    #[returns(PendingChangesResponse<ExecuteMsg>)]
+    PendingChanges {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -59,9 +142,56 @@ pub struct ConfigResponse {
     pub reward_denom: String,
 }
 
+#[cw_serde]
+pub struct Asset {
+    pub amount: Uint128,
+    pub asset_info: AssetInfo,
+}
+
+/// Swap of a token held by the reward contract, on the venue offering the best output
+#[cw_serde]
+pub struct SwapSimulation {
+    pub offer_asset: Asset,
+    /// Venue used for the swap, none if no venue can swap the token
+    pub venue: Option<SwapMessageType>,
+    pub return_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct SimulateSwapResponse {
+    pub reward_denom: String,
+    pub swaps: Vec<SwapSimulation>,
+    /// Reward denom received from all the swaps
+    pub total_return_amount: Uint128,
+}
+
+/// How the reward contracts share the rewards they receive
+#[cw_serde]
+#[derive(Default)]
+pub enum RewardDistributionMode {
+    /// The whole reward balance is claimed by the custody contract
+    #[default]
+    Custody,
+    /// Rewards are shared between the holders of the token contract, proportionally to their balance.
+    /// Balances are reported by the token contract itself
+    Holders { token_contract: Addr },
+    /// Rewards are shared between recipients set by the owner, proportionally to their weight.
+    /// Weights are stored as holder balances
+    Recipients,
+}
+
+#[cw_serde]
+pub struct RewardRecipient {
+    pub address: String,
+    pub weight: Uint128,
+}
+
 #[cw_serde]
 pub struct AccruedRewardsResponse {
+    /// Rewards that can be claimed right away
     pub rewards: Uint128,
+    /// Rewards that are still unlocking
+    pub locked_rewards: Uint128,
 }
 
 #[cw_serde]
@@ -31,3 +31,59 @@ fn test_deduct_tax() {
     );
 }
 */
+
+use crate::contract_error::ContractError;
+use crate::version::{migrate_version, CW20_BASE_CONTRACT_NAME, LEGACY_VERSION};
+use cosmwasm_std::testing::MockStorage;
+use cw2::{get_contract_version, set_contract_version};
+
+const NAME: &str = "crates.io:cavern-lsd-wrapper-with-limit";
+
+#[test]
+fn legacy_contracts_are_migrated() {
+    let mut storage = MockStorage::new();
+    let previous = migrate_version(&mut storage, NAME, "1.0.0", &[]).unwrap();
+    assert_eq!(previous.contract, NAME);
+    assert_eq!(previous.version, LEGACY_VERSION);
+    assert_eq!(get_contract_version(&storage).unwrap().version, "1.0.0");
+}
+
+#[test]
+fn downgrades_are_refused() {
+    let mut storage = MockStorage::new();
+    set_contract_version(&mut storage, NAME, "1.2.0").unwrap();
+
+    let err = migrate_version(&mut storage, NAME, "1.1.9", &[]).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CannotDowngrade {
+            previous: "1.2.0".to_string(),
+            new: "1.1.9".to_string()
+        }
+    );
+
+    migrate_version(&mut storage, NAME, "1.2.0", &[]).unwrap();
+    migrate_version(&mut storage, NAME, "1.10.0", &[]).unwrap();
+}
+
+#[test]
+fn only_replaced_contracts_are_migrated() {
+    let mut storage = MockStorage::new();
+    set_contract_version(&mut storage, "crates.io:cavern-lsd-hub", "1.0.0").unwrap();
+
+    let err = migrate_version(&mut storage, NAME, "1.0.0", &[CW20_BASE_CONTRACT_NAME]).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidContractName {
+            expected: NAME.to_string(),
+            actual: "crates.io:cavern-lsd-hub".to_string()
+        }
+    );
+
+    // The replaced contract can have a higher version
+    set_contract_version(&mut storage, CW20_BASE_CONTRACT_NAME, "1.1.0").unwrap();
+    let previous =
+        migrate_version(&mut storage, NAME, "1.0.0", &[CW20_BASE_CONTRACT_NAME]).unwrap();
+    assert_eq!(previous.contract, CW20_BASE_CONTRACT_NAME);
+    assert_eq!(get_contract_version(&storage).unwrap().contract, NAME);
+}
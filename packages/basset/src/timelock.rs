@@ -0,0 +1,195 @@
+use crate::access::{AccessControl, Role};
+use crate::contract_error::ContractError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{attr, Addr, DepsMut, Env, Order, Response, StdError, StdResult, Storage};
+use cw_storage_plus::{Bound, Item, Map};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+#[cw_serde]
+#[derive(Default)]
+pub struct TimelockConfig {
+    /// Seconds between queuing a config change and executing it. Changes apply right away when zero
+    pub delay: u64,
+}
+
+/// Config change waiting for its delay to pass
+#[cw_serde]
+pub struct PendingChange<T> {
+    pub id: u64,
+    pub change: T,
+    /// Time (in seconds) from which the change can be executed
+    pub eta: u64,
+}
+
+#[cw_serde]
+pub struct TimelockResponse {
+    pub delay: u64,
+}
+
+#[cw_serde]
+pub struct PendingChangesResponse<T> {
+    pub changes: Vec<PendingChange<T>>,
+}
+
+/// Config changes queued by the owner of a contract, stored under the given keys
+pub struct Timelock<'a, T> {
+    config: Item<'a, TimelockConfig>,
+    pending: Map<'a, u64, PendingChange<T>>,
+    next_id: Item<'a, u64>,
+}
+
+impl<'a, T> Timelock<'a, T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    pub const fn new(config_key: &'a str, pending_key: &'a str, next_id_key: &'a str) -> Self {
+        Timelock {
+            config: Item::new(config_key),
+            pending: Map::new(pending_key),
+            next_id: Item::new(next_id_key),
+        }
+    }
+
+    /// Contracts that never set a timelock apply their changes right away
+    pub fn config(&self, storage: &dyn Storage) -> StdResult<TimelockConfig> {
+        Ok(self.config.may_load(storage)?.unwrap_or_default())
+    }
+
+    pub fn set_config(&self, storage: &mut dyn Storage, config: &TimelockConfig) -> StdResult<()> {
+        self.config.save(storage, config)
+    }
+
+    /// Queues the change, which can be executed once the delay has passed
+    pub fn queue(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        change: T,
+    ) -> StdResult<PendingChange<T>> {
+        let delay = self.config(storage)?.delay;
+        let id = self.next_id.may_load(storage)?.unwrap_or_default();
+        self.next_id.save(storage, &(id + 1))?;
+
+        let pending = PendingChange {
+            id,
+            change,
+            eta: env.block.time.seconds() + delay,
+        };
+        self.pending.save(storage, id, &pending)?;
+        Ok(pending)
+    }
+
+    /// Applies the change right away with `apply` when no delay is set, queues it otherwise.
+    /// The caller must have checked that the sender can change the config
+    pub fn queue_or_apply<F>(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        change: T,
+        apply: F,
+    ) -> Result<Response, ContractError>
+    where
+        F: FnOnce(DepsMut, Env, T) -> Result<Response, ContractError>,
+    {
+        if self.config(deps.storage)?.delay == 0 {
+            return apply(deps, env, change);
+        }
+
+        let pending = self.queue(deps.storage, &env, change)?;
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "queue_config_change"),
+            attr("id", pending.id.to_string()),
+            attr("eta", pending.eta.to_string()),
+        ]))
+    }
+
+    /// Applies a queued change with `apply` once its delay has passed.
+    /// The caller must have checked that the sender can change the config
+    pub fn apply_ready<F>(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        id: u64,
+        apply: F,
+    ) -> Result<Response, ContractError>
+    where
+        F: FnOnce(DepsMut, Env, T) -> Result<Response, ContractError>,
+    {
+        let change = self.take_ready(deps.storage, &env, id)?;
+        Ok(apply(deps, env, change)?.add_attribute("id", id.to_string()))
+    }
+
+    /// The owner and the guardians can cancel a queued change
+    pub fn cancel_by_guardian(
+        &self,
+        storage: &mut dyn Storage,
+        access: &AccessControl,
+        sender: &Addr,
+        owner: &Addr,
+        id: u64,
+    ) -> Result<Response, ContractError> {
+        access.assert_role(storage, Role::Guardian, sender, owner)?;
+        self.cancel(storage, id)?;
+
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "cancel_config_change"),
+            attr("id", id.to_string()),
+        ]))
+    }
+
+    /// Removes the change from the queue so that it can be applied
+    pub fn take_ready(&self, storage: &mut dyn Storage, env: &Env, id: u64) -> StdResult<T> {
+        let pending = self.load(storage, id)?;
+        if env.block.time.seconds() < pending.eta {
+            return Err(StdError::generic_err(format!(
+                "the config change can't be executed before {}",
+                pending.eta
+            )));
+        }
+        self.pending.remove(storage, id);
+        Ok(pending.change)
+    }
+
+    /// The caller must have checked that the sender can cancel the change
+    pub fn cancel(&self, storage: &mut dyn Storage, id: u64) -> StdResult<()> {
+        self.load(storage, id)?;
+        self.pending.remove(storage, id);
+        Ok(())
+    }
+
+    pub fn query_config(&self, storage: &dyn Storage) -> StdResult<TimelockResponse> {
+        let config = self.config(storage)?;
+        Ok(TimelockResponse {
+            delay: config.delay,
+        })
+    }
+
+    pub fn query_pending(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<PendingChangesResponse<T>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+
+        let changes = self
+            .pending
+            .range(storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|elem| Ok(elem?.1))
+            .collect::<StdResult<Vec<_>>>()?;
+        Ok(PendingChangesResponse { changes })
+    }
+
+    fn load(&self, storage: &dyn Storage, id: u64) -> StdResult<PendingChange<T>> {
+        self.pending.may_load(storage, id)?.ok_or_else(|| {
+            StdError::generic_err(format!("no pending config change with id {}", id))
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use crate::contract_error::ContractError;
+use cosmwasm_std::{StdError, Storage};
+use cw2::{set_contract_version, ContractVersion, CONTRACT};
+use semver::Version;
+
+/// Name recorded by cw20-base, which the wrapper tokens stored before recording their own
+pub const CW20_BASE_CONTRACT_NAME: &str = "crates.io:cw20-base";
+
+/// Version reported for the contracts instantiated before they recorded one
+pub const LEGACY_VERSION: &str = "0.0.0";
+
+/// Checks the contract being migrated and records the new version.
+///
+/// The stored contract must be `contract_name` or one of the `replaced` contracts,
+/// and a contract can't be migrated to an older version of itself.
+/// Contracts that never recorded a version are taken as `contract_name` at the `LEGACY_VERSION`.
+/// Returns the contract and version migrated from
+pub fn migrate_version(
+    storage: &mut dyn Storage,
+    contract_name: &str,
+    contract_version: &str,
+    replaced: &[&str],
+) -> Result<ContractVersion, ContractError> {
+    let previous = CONTRACT
+        .may_load(storage)?
+        .unwrap_or_else(|| ContractVersion {
+            contract: contract_name.to_string(),
+            version: LEGACY_VERSION.to_string(),
+        });
+
+    if previous.contract == contract_name {
+        if parse_version(contract_version)? < parse_version(&previous.version)? {
+            return Err(ContractError::CannotDowngrade {
+                previous: previous.version,
+                new: contract_version.to_string(),
+            });
+        }
+    } else if !replaced.contains(&previous.contract.as_str()) {
+        return Err(ContractError::InvalidContractName {
+            expected: contract_name.to_string(),
+            actual: previous.contract,
+        });
+    }
+
+    set_contract_version(storage, contract_name, contract_version)?;
+    Ok(previous)
+}
+
+fn parse_version(version: &str) -> Result<Version, ContractError> {
+    Version::parse(version).map_err(|err| {
+        StdError::generic_err(format!("invalid contract version {}: {}", version, err)).into()
+    })
+}
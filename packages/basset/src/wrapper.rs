@@ -2,14 +2,23 @@ use cosmwasm_schema::QueryResponses;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Binary;
 use cosmwasm_std::Decimal;
+use cosmwasm_std::Empty;
 use cosmwasm_std::{Uint128, Addr};
 use cw20::Expiration;
 use cw20::Logo;
 use cw20::LogoInfo;
 
+use crate::access::{Role, RoleHoldersResponse};
+use crate::dex_router::AssetInfo;
+use crate::pause::{Operation, PauseStatusResponse};
+
+/// Storage key of the hub address in the wrapper tokens, read raw by the hub
+pub const HUB_CONTRACT_STORAGE_KEY: &str = "\u{0}\u{c}hub_contract";
+
+/// `I` is the raw lsd config of the wrapper, the hub only sends decompounds and uses the default
 #[cw_serde]
 #[cfg_attr(feature="interface", derive(cw_orch::ExecuteFns))]
-pub enum ExecuteMsg {
+pub enum ExecuteMsg<I = Empty> {
     /// Transfer is a base message to move tokens to another account without triggering actions
     Transfer {
         recipient: String,
@@ -97,7 +106,50 @@ pub enum ExecuteMsg {
     /// Wrapper specific message
     Decompound {
         recipient: Option<String>,
-    }
+    },
+
+    /// Only the admin can call this. Registers a contract that gets notified of every balance change
+    /// A failing hook reverts the balance change
+    AddHook {
+        addr: String,
+    },
+    /// Only the admin can call this. Stops notifying a registered hook
+    RemoveHook {
+        addr: String,
+    },
+    /// Only the admin can call this. Sends the current balances of a page of holders to a registered hook,
+    /// so that it starts from the existing balances. It should be synced before the hook receives rewards
+    SyncHook {
+        addr: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Only the admin can call this. Grants a guardian or keeper role
+    GrantRole {
+        role: Role,
+        addr: String,
+    },
+    /// Only the admin can call this. Revokes a guardian or keeper role
+    RevokeRole {
+        role: Role,
+        addr: String,
+    },
+    /// Only the guardians and the admin can call this. Stops the given operations right away
+    Pause {
+        operations: Vec<Operation>,
+    },
+    /// Only the admin can call this. Resumes the given operations
+    Unpause {
+        operations: Vec<Operation>,
+    },
+    /// Only the admin can call this. Replaces the lsd config, validated like at instantiation
+    UpdateLsdConfig {
+        config: I,
+    },
+    /// Only the admin can call this. Changes the hub allowed to decompound the wrapper
+    UpdateHub {
+        hub: String,
+    },
 }
 
 #[cw_serde]
@@ -155,6 +207,40 @@ pub enum QueryMsg {
     GetMintAmount { amount : Uint128},
     #[returns(GetExpectedExchangeRateResponse)]
     GetExpectedExchangeRate{},
+    /// Returns the contracts notified of every balance change
+    #[returns(HooksResponse)]
+    Hooks {},
+    /// Returns the addresses holding the given role, the admin holds the owner role
+    #[returns(RoleHoldersResponse)]
+    RoleHolders { role: Role },
+    /// Returns the operations currently paused
+    #[returns(PauseStatusResponse)]
+    PauseStatus {},
+    /// Only with snapshots enabled. Returns the balance of the given address at the start of the given block
+    #[returns(cw20::BalanceResponse)]
+    BalanceAt { address: String, height: u64 },
+    /// Only with snapshots enabled. Returns the total supply at the start of the given block
+    #[returns(TotalSupplyResponse)]
+    TotalSupplyAt { height: u64 },
+    /// Returns the rewards that the next decompound would send to the hub
+    #[returns(PendingDecompoundResponse)]
+    PendingDecompound {},
+    /// Returns the asset held by the wrapper along with its lsd config
+    #[returns(LsdConfigResponse)]
+    LsdConfig {},
+}
+
+#[cw_serde]
+pub struct LsdConfigResponse {
+    pub asset: AssetInfo,
+    /// JSON encoded lsd config, its fields depend on the lsd held by the wrapper
+    pub config: Binary,
+}
+
+#[cw_serde]
+pub struct PendingDecompoundResponse {
+    pub luna_rewards: Uint128,
+    pub lsd_rewards: Uint128,
 }
 
 #[derive(Default)]
@@ -181,6 +267,23 @@ pub struct TokenInfoResponse {
     pub exchange_rate: Decimal,
 }
 
+/// Token info returned by any wrapper.
+/// Only the wrappers with a decompound limit return the last fields
+#[cw_serde]
+pub struct AnyTokenInfoResponse {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub total_supply: Uint128,
+    pub exchange_rate: Decimal,
+    #[serde(default)]
+    pub expected_exchange_rate: Option<Decimal>,
+    #[serde(default)]
+    pub max_decompound_ratio: Option<Decimal>,
+    #[serde(default)]
+    pub lsd_exchange_rate: Option<Decimal>,
+}
+
 #[cw_serde]
 pub struct TokenInfoResponseWithLimit {
     pub name: String,
@@ -213,4 +316,41 @@ pub struct MintAmountReponseWithLimit {
 #[cw_serde]
 pub struct GetExpectedExchangeRateResponse {
     pub expected_exchange_rate: Decimal,
-}
\ No newline at end of file
+}
+#[cw_serde]
+pub struct HooksResponse {
+    pub hooks: Vec<String>,
+}
+
+#[cw_serde]
+pub struct TotalSupplyResponse {
+    pub total_supply: Uint128,
+}
+
+#[cw_serde]
+pub struct BalanceChange {
+    pub address: String,
+    pub old: Uint128,
+    pub new: Uint128,
+}
+
+/// Message sent to every registered hook after a wrapper operation changed some balances
+#[cw_serde]
+pub enum BalanceChangeHookMsg {
+    BalanceChangeHook { changes: Vec<BalanceChange> },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {
+    /// Wrapper tokens with limit only, replaces the max decompound ratio.
+    /// The current ratio is kept when none is given
+    pub max_decompound_ratio: Option<Decimal>,
+    /// Wrapper tokens with limit only, removes the max decompound ratio.
+    /// Can't be set along with a new ratio
+    pub remove_max_decompound_ratio: Option<bool>,
+    /// The address allowed to manage the balance change hooks
+    pub admin: Option<String>,
+    /// Wrapper tokens with limit only, enables or disables the balance snapshots.
+    /// Disabled snapshots can't be enabled again
+    pub enable_snapshots: Option<bool>,
+}
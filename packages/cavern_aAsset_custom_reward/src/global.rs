@@ -1,14 +1,17 @@
 use crate::contract::RETRIEVE_NORMAL_TOKENS_OPERATION;
 use crate::querier::query_all_cw20_balances;
 use crate::swap::Asset;
-use cosmwasm_std::{Coin, ReplyOn, SubMsg};
+use cosmwasm_std::{to_binary, Decimal256, Deps, ReplyOn, SubMsg, Uint128, WasmMsg};
 
-use crate::state::read_config;
+use crate::state::{read_config, read_state, store_state, Config, DistributionMode, State};
+use crate::stream::checkpoint_streams;
 
+use basset::contract_error::ContractError;
+use basset::reward::{ExecuteMsg, SimulateSwapResponse, SwapSimulation};
 use basset::{custom_reward::ExecuteWithSwapReply, dex_router::AssetInfo};
-use cosmwasm_std::{attr, CosmosMsg, DepsMut, Env, MessageInfo, Response, StdError, StdResult};
+use cosmwasm_std::{attr, CosmosMsg, DepsMut, Env, MessageInfo, Response, StdResult};
 
-use crate::swap::create_swap_msgs;
+use crate::swap::{best_swap_for, create_swap_msgs};
 
 // Retrieves the reward denom from the token received from the wrapper contract
 #[allow(clippy::if_same_then_else)]
@@ -17,11 +20,11 @@ pub fn execute_retrieve_normal_tokens<T: ExecuteWithSwapReply>(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     let config = read_config(deps.storage)?;
 
     if info.sender != config.hub_contract {
-        return Err(StdError::generic_err("unauthorized"));
+        return Err(ContractError::Unauthorized {});
     }
 
     let mut messages: Vec<SubMsg> = T::get_retrieve_messages(deps.as_ref(), env.clone())?
@@ -43,29 +46,16 @@ pub fn execute_retrieve_normal_tokens<T: ExecuteWithSwapReply>(
 /// Swap all native tokens to reward_denom
 #[allow(clippy::if_same_then_else)]
 #[allow(clippy::needless_collect)]
-pub fn execute_swap(deps: DepsMut, env: Env) -> StdResult<Response> {
+pub fn execute_swap(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
     let config = read_config(deps.storage)?;
 
-    let contr_addr = env.contract.address.clone();
-
-    // We start by swapping out all native coin balances
-    let balances = deps.querier.query_all_balances(contr_addr)?;
-
-    let reward_denom = config.clone().reward_denom;
-
-    let native_swap_messages: Vec<CosmosMsg> = balances
-        .iter()
-        .filter(|x| reward_denom.clone() != x.denom)
-        .map(|coin: &Coin| {
+    let swap_messages: Vec<CosmosMsg> = assets_to_swap(deps.as_ref(), &env, &config)?
+        .into_iter()
+        .map(|asset| {
             create_swap_msgs(
                 deps.as_ref(),
                 env.clone(),
-                Asset {
-                    asset_info: AssetInfo::NativeToken {
-                        denom: coin.denom.clone(),
-                    },
-                    amount: coin.amount,
-                },
+                asset,
                 config.reward_denom.clone(),
             )
         })
@@ -75,36 +65,124 @@ pub fn execute_swap(deps: DepsMut, env: Env) -> StdResult<Response> {
         })
         .collect::<StdResult<Vec<CosmosMsg>>>()?;
 
-    // Then we want to swap all cw20 balances we know into the stable denom
-    let cw20_balances: Vec<Asset> = query_all_cw20_balances(
-        deps.as_ref(),
+    let mut res = Response::new()
+        .add_messages(swap_messages)
+        .add_attributes(vec![attr("action", "swap")]);
+
+    // When rewards are shared between holders or streamed, we register the swapped rewards right away
+    if config.distribution_mode != DistributionMode::Custody || config.stream_duration.is_some() {
+        res = res.add_message(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::UpdateGlobalIndex {})?,
+            funds: vec![],
+        });
+    }
+
+    Ok(res)
+}
+
+/// Returns the balances swapped to reward_denom:
+/// all the native coins first, then the known cw20 tokens
+fn assets_to_swap(deps: Deps, env: &Env, config: &Config) -> StdResult<Vec<Asset>> {
+    let balances = deps
+        .querier
+        .query_all_balances(env.contract.address.clone())?;
+    let mut assets: Vec<Asset> = balances
+        .into_iter()
+        .filter(|coin| coin.denom != config.reward_denom)
+        .map(|coin| Asset {
+            asset_info: AssetInfo::NativeToken { denom: coin.denom },
+            amount: coin.amount,
+        })
+        .collect();
+
+    let cw20_balances = query_all_cw20_balances(
+        deps,
         env.contract.address.clone(),
         &config.known_cw20_tokens,
     )?;
-    let cw20_messages: Vec<CosmosMsg> = cw20_balances
-        .iter()
-        .filter(|asset| !asset.amount.is_zero())
-        .map(|asset: &Asset| {
-            create_swap_msgs(
-                deps.as_ref(),
-                env.clone(),
-                Asset {
-                    asset_info: asset.asset_info.clone(),
-                    amount: asset.amount,
-                },
-                config.reward_denom.clone(),
-            )
-        })
-        .flat_map(|result| match result {
-            Ok(vec) => vec.into_iter().map(Ok).collect(),
-            Err(er) => vec![Err(er)],
+    assets.extend(
+        cw20_balances
+            .into_iter()
+            .filter(|asset| !asset.amount.is_zero()),
+    );
+    Ok(assets)
+}
+
+/// Simulates the swap done once the normal tokens were retrieved, as if the incoming rewards were already received.
+/// The tokens retrieved beforehand are not simulated.
+/// Incoming cw20 tokens that are not known are ignored, as they wouldn't be swapped
+pub fn query_simulate_swap(
+    deps: Deps,
+    env: Env,
+    incoming: Vec<Asset>,
+) -> StdResult<SimulateSwapResponse> {
+    let config = read_config(deps.storage)?;
+    let mut assets = assets_to_swap(deps, &env, &config)?;
+    for asset in incoming {
+        let swapped = match &asset.asset_info {
+            AssetInfo::NativeToken { denom } => *denom != config.reward_denom,
+            AssetInfo::Token { contract_addr } => {
+                config.known_cw20_tokens.contains(contract_addr)
+            }
+        };
+        if !swapped || asset.amount.is_zero() {
+            continue;
+        }
+        match assets
+            .iter_mut()
+            .find(|existing| existing.asset_info == asset.asset_info)
+        {
+            Some(existing) => existing.amount += asset.amount,
+            None => assets.push(asset),
+        }
+    }
+
+    let swaps: Vec<SwapSimulation> = assets
+        .into_iter()
+        .map(|asset| {
+            let best_swap = best_swap_for(deps, &asset, &config.reward_denom);
+            SwapSimulation {
+                offer_asset: asset,
+                venue: best_swap.map(|(venue, _)| venue),
+                return_amount: best_swap.map(|(_, amount)| amount).unwrap_or_default(),
+            }
         })
-        .collect::<StdResult<Vec<CosmosMsg>>>()?;
+        .collect();
+    let total_return_amount = swaps.iter().map(|swap| swap.return_amount).sum::<Uint128>();
 
-    let res = Response::new()
-        .add_messages(native_swap_messages)
-        .add_messages(cw20_messages)
-        .add_attributes(vec![attr("action", "swap")]);
+    Ok(SimulateSwapResponse {
+        reward_denom: config.reward_denom,
+        swaps,
+        total_return_amount,
+    })
+}
 
-    Ok(res)
+/// Increases the global index with the rewards unlocked since the last update.
+/// Rewards are kept for later if no holder balance is registered
+pub fn update_global_index(deps: Deps, env: &Env, state: &mut State) -> StdResult<()> {
+    let unlocked_balance = checkpoint_streams(deps, env, state)?;
+    if state.total_balance.is_zero() {
+        return Ok(());
+    }
+
+    let new_rewards = unlocked_balance.saturating_sub(state.prev_reward_balance);
+    state.global_index =
+        state.global_index + Decimal256::from_ratio(new_rewards, state.total_balance);
+    state.prev_reward_balance = unlocked_balance;
+    Ok(())
+}
+
+/// Registers the rewards received since the last update and distributes the unlocked ones between holders
+/// Permissionless
+pub fn execute_update_global_index(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let mut state = read_state(deps.storage)?;
+
+    update_global_index(deps.as_ref(), &env, &mut state)?;
+    store_state(deps.storage, &state)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_global_index"),
+        attr("global_index", state.global_index.to_string()),
+    ]))
 }
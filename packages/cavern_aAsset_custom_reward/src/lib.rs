@@ -3,7 +3,11 @@ pub mod querier;
 pub mod state;
 
 pub mod global;
+mod ownership;
+mod pause;
+mod stream;
 pub mod swap;
+mod timelock;
 mod user;
 
 #[cfg(test)]
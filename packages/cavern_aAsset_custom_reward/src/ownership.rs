@@ -0,0 +1,41 @@
+use crate::state::{read_config, store_config, ACCESS, OWNERSHIP};
+use basset::contract_error::ContractError;
+
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+
+/// Applies a proposal queued by the owner, so that the guardians can cancel it
+pub fn execute_propose_new_owner(
+    deps: DepsMut,
+    env: Env,
+    owner: String,
+    expires_in: u64,
+) -> Result<Response, ContractError> {
+    let current_owner = read_config(deps.storage)?.owner;
+    OWNERSHIP.propose(deps, &env, &current_owner, owner, expires_in)
+}
+
+/// The owner and the guardians can drop the ownership proposal
+pub fn execute_drop_ownership_proposal(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let current_owner = read_config(deps.storage)?.owner;
+    OWNERSHIP.drop_proposal(deps.storage, &ACCESS, &info.sender, &current_owner)
+}
+
+/// Only the proposed owner can claim the ownership, before the proposal expires
+pub fn execute_claim_ownership(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let new_owner = OWNERSHIP.claim(deps.storage, &env, &info)?;
+    let mut config = read_config(deps.storage)?;
+    config.owner = new_owner.clone();
+    store_config(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "claim_ownership"),
+        attr("new_owner", new_owner),
+    ]))
+}
@@ -3,7 +3,7 @@ use basset::dex_router::{
     AssetInfo, ExecuteMsg as AstroportExecuteMsg, QueryMsg as AstroportQueryMsg,
     SimulateSwapOperationsResponse, SwapMessageType, SwapOperation,
 };
-use cosmwasm_schema::cw_serde;
+pub use basset::reward::Asset;
 use cosmwasm_std::{
     to_binary, Coin, CosmosMsg, Deps, Env, QueryRequest, StdResult, Uint128, WasmMsg, WasmQuery,
 };
@@ -11,12 +11,6 @@ use cw20::Cw20ExecuteMsg;
 use serde::Serialize;
 use strum::IntoEnumIterator;
 
-#[cw_serde]
-pub struct Asset {
-    pub amount: Uint128,
-    pub asset_info: AssetInfo,
-}
-
 pub fn into_cosmos_msg<M: Serialize, T: Into<String>>(
     message: M,
     contract_addr: T,
@@ -37,28 +31,7 @@ pub fn create_swap_msgs(
     asset_to_swap: Asset,
     reward_denom: String,
 ) -> StdResult<Vec<CosmosMsg>> {
-    let best_price_result: Option<(usize, Uint128)> = SwapMessageType::iter()
-        .map(|message_type| {
-            get_swap_result_for(
-                deps,
-                asset_to_swap.clone(),
-                reward_denom.clone(),
-                message_type,
-            )
-        })
-        .enumerate()
-        .filter(|(_, best_price)| best_price.is_ok())
-        .map(|(best_price_index, best_price)| (best_price_index, best_price.unwrap()))
-        .max_by_key(|&(_, item)| item);
-
-    if let Some((best_price_index, best_price)) = best_price_result {
-        if best_price == Uint128::zero() {
-            return Ok(vec![]);
-        }
-
-        let best_price_marketplace: SwapMessageType =
-            SwapMessageType::iter().nth(best_price_index).unwrap();
-
+    if let Some((best_price_marketplace, _)) = best_swap_for(deps, &asset_to_swap, &reward_denom) {
         Ok(vec![create_swap_message_for(
             deps,
             env,
@@ -71,6 +44,28 @@ pub fn create_swap_msgs(
     }
 }
 
+/// Returns the venue offering the most reward denom for the asset, with the simulated output.
+/// Nothing is returned when no venue gives anything back
+pub fn best_swap_for(
+    deps: Deps,
+    asset_to_swap: &Asset,
+    reward_denom: &str,
+) -> Option<(SwapMessageType, Uint128)> {
+    SwapMessageType::iter()
+        .filter_map(|message_type| {
+            get_swap_result_for(
+                deps,
+                asset_to_swap.clone(),
+                reward_denom.to_string(),
+                message_type,
+            )
+            .ok()
+            .map(|best_price| (message_type, best_price))
+        })
+        .max_by_key(|&(_, best_price)| best_price)
+        .filter(|(_, best_price)| !best_price.is_zero())
+}
+
 // Astroport router smart-contract
 // https://docs.astroport.fi/astroport/smart-contracts/astroport-contract-addresses
 // Testnet : terra1na348k6rvwxje9jj6ftpsapfeyaejxjeq6tuzdmzysps20l6z23smnlv64
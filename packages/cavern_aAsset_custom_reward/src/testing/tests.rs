@@ -18,16 +18,23 @@
 //! 4. Anywhere you see query(deps.as_ref(), mock_env(),...) you must replace it with query(&mut deps, ...)
 
 use basset::dex_router::AssetInfo;
-use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-use cosmwasm_std::{coins, Empty, StdError};
-use cosmwasm_std::{from_binary, BankMsg, Coin, CosmosMsg, SubMsg, Uint128};
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockStorage, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{coins, Empty, Env, OwnedDeps, StdError};
+use cosmwasm_std::{from_binary, BankMsg, Coin, CosmosMsg, Decimal256, SubMsg, Uint128};
 
 use crate::contract::{execute, instantiate, migrate, query};
 
 use crate::swap::{create_swap_msgs, Asset};
-use crate::testing::mock_querier::{mock_dependencies, MOCK_HUB_CONTRACT_ADDR};
+use crate::testing::mock_querier::{
+    mock_dependencies, WasmMockQuerier, MOCK_HUB_CONTRACT_ADDR, MOCK_TOKEN_CONTRACT_ADDR,
+};
+use basset::contract_error::ContractError;
 use basset::custom_reward::InstantiateMsg;
-use basset::reward::{ConfigResponse, ExecuteMsg, MigrateMsg, QueryMsg};
+use basset::reward::{
+    AccruedRewardsResponse, ConfigResponse, ExecuteMsg, HolderResponse, MigrateMsg, QueryMsg,
+    RewardRecipient,
+};
+use basset::wrapper::BalanceChange;
 
 const DEFAULT_REWARD_DENOM: &str = "uusd";
 
@@ -175,12 +182,13 @@ fn claim_rewards() {
         mock_env(),
         mock_info("addr0000", &[]),
         ExecuteMsg::UpdateConfig {
-            owner: None,
             custody_contract: Some("custody".to_string()),
             known_tokens: None,
             astroport_addr: None,
             phoenix_addr: None,
             terraswap_addr: None,
+            token_contract: None,
+            stream_duration: None,
         },
     )
     .unwrap();
@@ -208,7 +216,10 @@ fn claim_rewards() {
     };
     let info = mock_info("addr0000", &[]);
     let err = execute::<Empty>(deps.as_mut(), mock_env(), info, msg).unwrap_err();
-    assert_eq!(err, StdError::generic_err("unauthorized"));
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err("No rewards have accrued yet"))
+    );
 }
 
 #[test]
@@ -236,12 +247,13 @@ fn claim_rewards_with_decimals() {
         mock_env(),
         mock_info("addr0000", &[]),
         ExecuteMsg::UpdateConfig {
-            owner: None,
             custody_contract: Some("custody".to_string()),
             known_tokens: None,
             astroport_addr: None,
             phoenix_addr: None,
             terraswap_addr: None,
+            token_contract: None,
+            stream_duration: None,
         },
     )
     .unwrap();
@@ -263,6 +275,494 @@ fn claim_rewards_with_decimals() {
     );
 }
 
+#[test]
+fn holders_share_rewards() {
+    let mut deps = mock_dependencies(&[]);
+
+    let init_msg = default_init();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), mock_env(), info, init_msg).unwrap();
+
+    execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateConfig {
+            custody_contract: None,
+            known_tokens: None,
+            astroport_addr: None,
+            phoenix_addr: None,
+            terraswap_addr: None,
+            token_contract: Some(MOCK_TOKEN_CONTRACT_ADDR.to_string()),
+            stream_duration: None,
+        },
+    )
+    .unwrap();
+
+    // Only the token contract can report balances
+    let msg = ExecuteMsg::BalanceChangeHook {
+        changes: vec![
+            BalanceChange {
+                address: "addr0001".to_string(),
+                old: Uint128::zero(),
+                new: Uint128::new(100u128),
+            },
+            BalanceChange {
+                address: "addr0002".to_string(),
+                old: Uint128::zero(),
+                new: Uint128::new(300u128),
+            },
+        ],
+    };
+    let err = execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let token_info = mock_info(MOCK_TOKEN_CONTRACT_ADDR, &[]);
+    execute::<Empty>(deps.as_mut(), mock_env(), token_info.clone(), msg).unwrap();
+
+    // 400 uusd are received and shared between holders
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(400u128, "uusd"));
+
+    let res: AccruedRewardsResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::AccruedRewards {
+                address: "addr0001".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.rewards, Uint128::new(100u128));
+
+    let res = execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::ClaimRewards { recipient: None },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("addr0001"),
+            amount: coins(100u128, "uusd"),
+        }))]
+    );
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(300u128, "uusd"));
+
+    // Nothing left to claim
+    let err = execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::ClaimRewards { recipient: None },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err("No rewards have accrued yet"))
+    );
+
+    // Pending rewards are kept when the balance decreases
+    execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        token_info,
+        ExecuteMsg::BalanceChangeHook {
+            changes: vec![BalanceChange {
+                address: "addr0002".to_string(),
+                old: Uint128::new(300u128),
+                new: Uint128::zero(),
+            }],
+        },
+    )
+    .unwrap();
+
+    let holder: HolderResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Holder {
+                address: "addr0002".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(holder.balance, Uint128::zero());
+    assert_eq!(
+        holder.pending_rewards,
+        Decimal256::from_ratio(300u128, 1u128)
+    );
+}
+
+#[test]
+fn custody_rewards_settled_before_sharing() {
+    let mut deps = mock_dependencies(&[]);
+
+    let init_msg = default_init();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), mock_env(), info, init_msg).unwrap();
+
+    let update_config = |custody_contract: Option<String>, token_contract: Option<String>| {
+        ExecuteMsg::UpdateConfig {
+            custody_contract,
+            known_tokens: None,
+            astroport_addr: None,
+            phoenix_addr: None,
+            terraswap_addr: None,
+            token_contract,
+            stream_duration: None,
+        }
+    };
+    execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        update_config(Some("custody".to_string()), None),
+    )
+    .unwrap();
+
+    // The custody contract hasn't claimed its rewards yet
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(400u128, "uusd"));
+
+    let res = execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        update_config(None, Some(MOCK_TOKEN_CONTRACT_ADDR.to_string())),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("custody"),
+            amount: coins(400u128, "uusd"),
+        }))]
+    );
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(0u128, "uusd"));
+
+    // Holders only share the rewards received afterwards
+    execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MOCK_TOKEN_CONTRACT_ADDR, &[]),
+        ExecuteMsg::BalanceChangeHook {
+            changes: vec![BalanceChange {
+                address: "addr0001".to_string(),
+                old: Uint128::new(100u128),
+                new: Uint128::new(100u128),
+            }],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(50u128, "uusd"));
+
+    let res: AccruedRewardsResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::AccruedRewards {
+                address: "addr0001".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.rewards, Uint128::new(50u128));
+}
+
+#[test]
+fn streamed_rewards() {
+    let mut deps = mock_dependencies(&[]);
+
+    let init_msg = default_init();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), mock_env(), info, init_msg).unwrap();
+
+    execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateConfig {
+            custody_contract: Some("custody".to_string()),
+            known_tokens: None,
+            astroport_addr: None,
+            phoenix_addr: None,
+            terraswap_addr: None,
+            token_contract: None,
+            stream_duration: Some(100u64),
+        },
+    )
+    .unwrap();
+
+    // 1000 uusd are received and start unlocking
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(1000u128, "uusd"));
+    execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::UpdateGlobalIndex {},
+    )
+    .unwrap();
+
+    let accrued_rewards = |deps: &OwnedDeps<MockStorage, MockApi, WasmMockQuerier>, env: Env| {
+        from_binary::<AccruedRewardsResponse>(
+            &query(
+                deps.as_ref(),
+                env,
+                QueryMsg::AccruedRewards {
+                    address: "custody".to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(25);
+    let res = accrued_rewards(&deps, env.clone());
+    assert_eq!(res.rewards, Uint128::new(250u128));
+    assert_eq!(res.locked_rewards, Uint128::new(750u128));
+
+    // Only the unlocked part is claimed
+    let res = execute::<Empty>(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("custody", &[]),
+        ExecuteMsg::ClaimRewards { recipient: None },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("custody"),
+            amount: coins(250u128, "uusd"),
+        }))]
+    );
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(750u128, "uusd"));
+
+    let res = accrued_rewards(&deps, env.clone());
+    assert_eq!(res.rewards, Uint128::zero());
+    assert_eq!(res.locked_rewards, Uint128::new(750u128));
+
+    // Everything is unlocked at the end of the stream
+    env.block.time = env.block.time.plus_seconds(75);
+    let res = accrued_rewards(&deps, env);
+    assert_eq!(res.rewards, Uint128::new(750u128));
+    assert_eq!(res.locked_rewards, Uint128::zero());
+}
+
+#[test]
+fn weighted_recipients() {
+    let mut deps = mock_dependencies(&[]);
+
+    let init_msg = default_init();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), mock_env(), info, init_msg).unwrap();
+
+    let msg = ExecuteMsg::UpdateRecipients {
+        recipients: vec![
+            RewardRecipient {
+                address: "custody1".to_string(),
+                weight: Uint128::new(1u128),
+            },
+            RewardRecipient {
+                address: "custody2".to_string(),
+                weight: Uint128::new(3u128),
+            },
+        ],
+    };
+    let err = execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("custody1", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute::<Empty>(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(400u128, "uusd"));
+
+    let accrued_rewards = |deps: &OwnedDeps<MockStorage, MockApi, WasmMockQuerier>,
+                           address: &str| {
+        from_binary::<AccruedRewardsResponse>(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::AccruedRewards {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+        .rewards
+    };
+    assert_eq!(accrued_rewards(&deps, "custody1"), Uint128::new(100u128));
+    assert_eq!(accrued_rewards(&deps, "custody2"), Uint128::new(300u128));
+
+    // Accrued rewards are kept when the weights change
+    execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateRecipients {
+            recipients: vec![RewardRecipient {
+                address: "custody1".to_string(),
+                weight: Uint128::new(1u128),
+            }],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(500u128, "uusd"));
+
+    assert_eq!(accrued_rewards(&deps, "custody1"), Uint128::new(200u128));
+    assert_eq!(accrued_rewards(&deps, "custody2"), Uint128::new(300u128));
+
+    let res = execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("custody2", &[]),
+        ExecuteMsg::ClaimRewards { recipient: None },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("custody2"),
+            amount: coins(300u128, "uusd"),
+        }))]
+    );
+}
+
+#[test]
+fn recipients_back_to_custody() {
+    let mut deps = mock_dependencies(&[]);
+
+    let init_msg = default_init();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), mock_env(), info, init_msg).unwrap();
+
+    execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateConfig {
+            custody_contract: Some("custody".to_string()),
+            known_tokens: None,
+            astroport_addr: None,
+            phoenix_addr: None,
+            terraswap_addr: None,
+            token_contract: None,
+            stream_duration: None,
+        },
+    )
+    .unwrap();
+
+    // The rewards not claimed by the custody contract are sent to it before switching
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(100u128, "uusd"));
+    let res = execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateRecipients {
+            recipients: vec![RewardRecipient {
+                address: "recipient".to_string(),
+                weight: Uint128::new(1u128),
+            }],
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("custody"),
+            amount: coins(100u128, "uusd"),
+        }))]
+    );
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(40u128, "uusd"));
+
+    // An empty list gives the next rewards back to the custody contract
+    let res = execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateRecipients { recipients: vec![] },
+    )
+    .unwrap();
+    assert_eq!(res.messages, vec![]);
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(100u128, "uusd"));
+
+    let res = execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("custody", &[]),
+        ExecuteMsg::ClaimRewards { recipient: None },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("custody"),
+            amount: coins(60u128, "uusd"),
+        }))]
+    );
+    deps.querier
+        .base
+        .update_balance(MOCK_CONTRACT_ADDR, coins(40u128, "uusd"));
+
+    // The recipient keeps what it accrued before
+    let res = execute::<Empty>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("recipient", &[]),
+        ExecuteMsg::ClaimRewards { recipient: None },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: String::from("recipient"),
+            amount: coins(40u128, "uusd"),
+        }))]
+    );
+}
+
 #[test]
 fn test_migrate() {
     let mut deps = mock_dependencies(&[Coin {
@@ -271,6 +771,7 @@ fn test_migrate() {
     }]);
 
     migrate(deps.as_mut(), mock_env(), MigrateMsg {
-        max_decompound_ratio: None
+        max_decompound_ratio: None,
+        hub_contract: None,
     }).unwrap();
 }
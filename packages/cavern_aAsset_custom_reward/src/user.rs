@@ -1,28 +1,41 @@
-use crate::state::{read_config, Config};
-use basset::reward::AccruedRewardsResponse;
+use crate::global::update_global_index;
+use crate::stream::{checkpoint_streams, locked_rewards};
+use crate::state::{
+    read_config, read_holder, read_holders, read_state, store_config, store_holder, store_state,
+    Config, DistributionMode, Holder, State, HOLDERS, PAUSE,
+};
+use basset::contract_error::ContractError;
+use basset::pause::Operation;
+use basset::reward::{AccruedRewardsResponse, HolderResponse, HoldersResponse, RewardRecipient};
+use basset::wrapper::BalanceChange;
 
 use cosmwasm_std::{
-    attr, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
-    Uint128,
+    attr, Addr, BankMsg, Coin, CosmosMsg, Decimal256, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult, Uint128, Uint256,
 };
+use std::convert::TryInto;
 
 pub fn execute_claim_rewards(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     recipient: Option<String>,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
+    PAUSE.assert_not_paused(deps.storage, Operation::Claim)?;
     let config: Config = read_config(deps.storage)?;
 
+    if config.distribution_mode != DistributionMode::Custody {
+        return execute_claim_holder_rewards(deps, env, info, config, recipient);
+    }
+
     let custody_addr = config
         .custody_contract
         .clone()
-        .ok_or_else(|| StdError::generic_err("the custody contract must have been registered"))?
-        .to_string();
+        .ok_or_else(|| StdError::generic_err("the custody contract must have been registered"))?;
 
-    // Only the custody contract can call this function
-    if info.sender != deps.api.addr_validate(&custody_addr)? {
-        return Err(StdError::generic_err("unauthorized"));
+    // Former recipients can still claim the rewards they accrued before the custody contract got them back
+    if info.sender != custody_addr {
+        return execute_claim_holder_rewards(deps, env, info, config, recipient);
     }
 
     let recipient = recipient
@@ -30,19 +43,80 @@ pub fn execute_claim_rewards(
         .transpose()?
         .unwrap_or(info.sender);
 
-    // This correspond exactly to the reward denom balance
-    // We send a message if and only if the contract indeed has a denom balance
-    let rewards: Coin = deps
-        .querier
-        .query_balance(env.contract.address, config.reward_denom)?;
+    // This correspond exactly to the unlocked part of the reward denom balance,
+    // without the rewards still owed to former recipients
+    // We send a message if and only if the contract indeed has some unlocked rewards
+    let mut state = read_state(deps.storage)?;
+    let unlocked_rewards = checkpoint_streams(deps.as_ref(), &env, &mut state)?
+        .saturating_sub(state.prev_reward_balance);
+
+    if unlocked_rewards.is_zero() {
+        return Err(ContractError::Std(StdError::generic_err(
+            "No rewards have accrued yet",
+        )));
+    }
+
+    state.tracked_reward_balance = state.tracked_reward_balance.checked_sub(unlocked_rewards)?;
+    store_state(deps.storage, &state)?;
+
+    let rewards = Coin {
+        denom: config.reward_denom,
+        amount: unlocked_rewards,
+    };
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "claim_reward"),
+            attr("rewards", rewards.to_string()),
+        ])
+        .add_message(CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![rewards],
+        })))
+}
+
+fn execute_claim_holder_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    config: Config,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let recipient = recipient
+        .map(|x| deps.api.addr_validate(&x))
+        .transpose()?
+        .unwrap_or_else(|| info.sender.clone());
+
+    let mut state = read_state(deps.storage)?;
+    update_global_index(deps.as_ref(), &env, &mut state)?;
+
+    let mut holder = read_holder(deps.storage, &info.sender)?;
+    update_holder_reward(&state, &mut holder);
 
-    if rewards.amount.is_zero() {
-        return Err(StdError::generic_err("No rewards have accrued yet"));
+    let rewards = claimable_rewards(&holder)?;
+    if rewards.is_zero() {
+        return Err(ContractError::Std(StdError::generic_err(
+            "No rewards have accrued yet",
+        )));
     }
 
+    // The decimal part of the rewards stays with the holder
+    holder.pending_rewards = holder.pending_rewards - Decimal256::from_ratio(rewards, 1u128);
+    state.prev_reward_balance = state.prev_reward_balance.checked_sub(rewards)?;
+    state.tracked_reward_balance = state.tracked_reward_balance.checked_sub(rewards)?;
+
+    store_holder(deps.storage, &info.sender, &holder)?;
+    store_state(deps.storage, &state)?;
+
+    let rewards = Coin {
+        denom: config.reward_denom,
+        amount: rewards,
+    };
+
     Ok(Response::new()
         .add_attributes(vec![
             attr("action", "claim_reward"),
+            attr("holder_address", info.sender),
             attr("rewards", rewards.to_string()),
         ])
         .add_message(CosmosMsg::Bank(BankMsg::Send {
@@ -51,19 +125,233 @@ pub fn execute_claim_rewards(
         })))
 }
 
+pub fn execute_balance_change_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    changes: Vec<BalanceChange>,
+) -> Result<Response, ContractError> {
+    assert_token_contract(deps.as_ref(), &info)?;
+
+    let mut state = read_state(deps.storage)?;
+    update_global_index(deps.as_ref(), &env, &mut state)?;
+
+    for change in changes.iter() {
+        let address = deps.api.addr_validate(&change.address)?;
+        let mut holder = read_holder(deps.storage, &address)?;
+        update_holder_reward(&state, &mut holder);
+
+        // The token contract balance is the source of truth
+        state.total_balance = state.total_balance.checked_sub(holder.balance)? + change.new;
+        holder.balance = change.new;
+
+        store_holder(deps.storage, &address, &holder)?;
+    }
+    store_state(deps.storage, &state)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "balance_change_hook"),
+        attr("changes", changes.len().to_string()),
+    ]))
+}
+
+/// Rewards held for the custody contract are sent to it before they are shared between recipients.
+/// An empty list gives the rewards back to the custody contract, what recipients accrued until then stays claimable.
+/// Only the owner can update the recipients, which is checked when the change is queued
+pub fn execute_update_recipients(
+    mut deps: DepsMut,
+    env: Env,
+    recipients: Vec<RewardRecipient>,
+) -> Result<Response, ContractError> {
+    let mut config = read_config(deps.storage)?;
+    if let DistributionMode::Holders { .. } = config.distribution_mode {
+        return Err(ContractError::Std(StdError::generic_err(
+            "Recipients can't be set while rewards are shared between token holders",
+        )));
+    }
+    if recipients.is_empty() && config.custody_contract.is_none() {
+        return Err(ContractError::Std(StdError::generic_err(
+            "the custody contract must have been registered",
+        )));
+    }
+
+    let messages = settle_custody(deps.branch(), &env, &config)?;
+
+    // Rewards are settled with the previous weights before they change
+    let mut state = read_state(deps.storage)?;
+    update_global_index(deps.as_ref(), &env, &mut state)?;
+
+    let previous_recipients = HOLDERS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Holder)>>>()?;
+    for (address, mut holder) in previous_recipients
+        .into_iter()
+        .filter(|(_, holder)| !holder.balance.is_zero())
+    {
+        update_holder_reward(&state, &mut holder);
+        holder.balance = Uint128::zero();
+        store_holder(deps.storage, &address, &holder)?;
+    }
+
+    let mut total_weight = Uint128::zero();
+    let mut addresses: Vec<Addr> = vec![];
+    for recipient in recipients.iter() {
+        let address = deps.api.addr_validate(&recipient.address)?;
+        if addresses.contains(&address) {
+            return Err(ContractError::Std(StdError::generic_err(
+                "Recipients shouldn't contain duplicate addresses",
+            )));
+        }
+        let mut holder = read_holder(deps.storage, &address)?;
+        update_holder_reward(&state, &mut holder);
+        holder.balance = recipient.weight;
+        store_holder(deps.storage, &address, &holder)?;
+
+        total_weight += recipient.weight;
+        addresses.push(address);
+    }
+    if !recipients.is_empty() && total_weight.is_zero() {
+        return Err(ContractError::Std(StdError::generic_err(
+            "The total weight can't be zero",
+        )));
+    }
+
+    state.total_balance = total_weight;
+    store_state(deps.storage, &state)?;
+
+    config.distribution_mode = if recipients.is_empty() {
+        DistributionMode::Custody
+    } else {
+        DistributionMode::Recipients
+    };
+    store_config(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "update_recipients"),
+            attr("total_weight", total_weight),
+        ])
+        .add_messages(messages))
+}
+
+/// Sends the rewards held for the custody contract, the locked ones included, before the distribution mode changes.
+/// They would otherwise be distributed to the holders or recipients that come next
+pub fn settle_custody(
+    deps: DepsMut,
+    env: &Env,
+    config: &Config,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let custody_contract = match (&config.distribution_mode, &config.custody_contract) {
+        (DistributionMode::Custody, Some(custody_contract)) => custody_contract,
+        _ => return Ok(vec![]),
+    };
+
+    let mut state = read_state(deps.storage)?;
+    checkpoint_streams(deps.as_ref(), env, &mut state)?;
+
+    // The rewards already distributed to holders stay reserved for them
+    let custody_rewards = state
+        .tracked_reward_balance
+        .checked_sub(state.prev_reward_balance)?;
+    state.tracked_reward_balance = state.prev_reward_balance;
+    state.reward_streams = vec![];
+    store_state(deps.storage, &state)?;
+
+    if custody_rewards.is_zero() {
+        return Ok(vec![]);
+    }
+    Ok(vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: custody_contract.to_string(),
+        amount: vec![Coin {
+            denom: config.reward_denom.clone(),
+            amount: custody_rewards,
+        }],
+    })])
+}
+
+fn assert_token_contract(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+    let config = read_config(deps.storage)?;
+    match config.distribution_mode {
+        DistributionMode::Holders { token_contract } if token_contract == info.sender => Ok(()),
+        _ => Err(ContractError::Unauthorized {}),
+    }
+}
+
+/// Adds the rewards accrued since the last holder update to its pending rewards
+pub fn update_holder_reward(state: &State, holder: &mut Holder) {
+    let holder_balance = Decimal256::from_ratio(holder.balance, 1u128);
+    let reward = (state.global_index - holder.index) * holder_balance;
+
+    holder.pending_rewards = holder.pending_rewards + reward;
+    holder.index = state.global_index;
+}
+
+fn claimable_rewards(holder: &Holder) -> StdResult<Uint128> {
+    let rewards: Uint256 = holder.pending_rewards * Uint256::one();
+    Ok(rewards.try_into()?)
+}
+
 pub fn query_accrued_rewards(
     deps: Deps,
     env: Env,
-    _address: String,
+    address: String,
 ) -> StdResult<AccruedRewardsResponse> {
     let config = read_config(deps.storage)?;
-    let rewards = match deps
-        .querier
-        .query_balance(env.contract.address, config.reward_denom)
+    let mut state = read_state(deps.storage)?;
+
+    let address = deps.api.addr_validate(&address)?;
+    if config.distribution_mode != DistributionMode::Custody
+        || config.custody_contract.as_ref() != Some(&address)
     {
-        Err(_) => Uint128::zero(),
-        Ok(c) => c.amount,
-    };
+        update_global_index(deps, &env, &mut state)?;
 
-    Ok(AccruedRewardsResponse { rewards })
+        let mut holder = read_holder(deps.storage, &address)?;
+        update_holder_reward(&state, &mut holder);
+
+        // Locked rewards will be shared according to the current balances
+        let locked_rewards = if state.total_balance.is_zero() {
+            Uint128::zero()
+        } else {
+            locked_rewards(&state, env.block.time.seconds())
+                .multiply_ratio(holder.balance, state.total_balance)
+        };
+
+        return Ok(AccruedRewardsResponse {
+            rewards: claimable_rewards(&holder)?,
+            locked_rewards,
+        });
+    }
+
+    let rewards =
+        checkpoint_streams(deps, &env, &mut state)?.saturating_sub(state.prev_reward_balance);
+
+    Ok(AccruedRewardsResponse {
+        rewards,
+        locked_rewards: locked_rewards(&state, env.block.time.seconds()),
+    })
+}
+
+pub fn query_holder(deps: Deps, env: Env, address: String) -> StdResult<HolderResponse> {
+    let holder_address = deps.api.addr_validate(&address)?;
+    let mut holder = read_holder(deps.storage, &holder_address)?;
+
+    let mut state = read_state(deps.storage)?;
+    update_global_index(deps, &env, &mut state)?;
+    update_holder_reward(&state, &mut holder);
+
+    Ok(HolderResponse {
+        address,
+        balance: holder.balance,
+        index: holder.index,
+        pending_rewards: holder.pending_rewards,
+    })
+}
+
+pub fn query_holders(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<HoldersResponse> {
+    let holders = read_holders(deps, start_after, limit)?;
+    Ok(HoldersResponse { holders })
 }
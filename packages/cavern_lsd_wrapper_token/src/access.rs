@@ -0,0 +1,33 @@
+use crate::hooks::assert_admin;
+use crate::state::{ACCESS, ADMIN};
+use basset::access::{Role, RoleHoldersResponse};
+
+use basset::contract_error::ContractError;
+use cosmwasm_std::{Deps, DepsMut, MessageInfo, Response, StdError, StdResult};
+
+pub fn execute_grant_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    role: Role,
+    addr: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info)?;
+    Ok(ACCESS.grant(deps, role, addr)?)
+}
+
+pub fn execute_revoke_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    role: Role,
+    addr: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info)?;
+    Ok(ACCESS.revoke(deps, role, addr)?)
+}
+
+pub fn query_role_holders(deps: Deps, role: Role) -> StdResult<RoleHoldersResponse> {
+    let admin = ADMIN
+        .may_load(deps.storage)?
+        .ok_or_else(|| StdError::generic_err("no admin set"))?;
+    ACCESS.query_holders(deps.storage, role, &admin)
+}
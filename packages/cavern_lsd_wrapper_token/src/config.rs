@@ -0,0 +1,56 @@
+use crate::hooks::assert_admin;
+use crate::state::{read_lsd_config, store_hub_contract, store_lsd_config};
+use crate::trait_def::LSDHub;
+use serde::{Deserialize, Serialize};
+
+use basset::contract_error::ContractError;
+use basset::wrapper::LsdConfigResponse;
+use cosmwasm_std::{attr, to_binary, Binary, Deps, DepsMut, MessageInfo, Response, StdResult};
+
+/// The new config goes through the same validation as the one given at instantiation.
+/// The asset held by the wrapper can't be changed
+pub fn execute_update_lsd_config<
+    I: Serialize + for<'b> Deserialize<'b>,
+    T: LSDHub<I> + for<'a> Deserialize<'a> + Serialize,
+>(
+    deps: DepsMut,
+    info: MessageInfo,
+    config: I,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info)?;
+
+    let lsd_config = T::instantiate_config(deps.as_ref(), config)?;
+    let current_config: T = read_lsd_config(deps.storage)?;
+    if lsd_config.held_asset() != current_config.held_asset() {
+        return Err(ContractError::AssetMismatch {});
+    }
+    store_lsd_config::<I, T>(deps.storage, &lsd_config)?;
+
+    Ok(Response::new().add_attribute("action", "update_lsd_config"))
+}
+
+pub fn execute_update_hub(
+    deps: DepsMut,
+    info: MessageInfo,
+    hub: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info)?;
+
+    let hub = deps.api.addr_validate(&hub)?;
+    store_hub_contract(deps.storage, &hub)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "update_hub"), attr("hub", hub)]))
+}
+
+pub fn query_lsd_config<
+    I: Serialize + for<'b> Deserialize<'b>,
+    T: LSDHub<I> + for<'a> Deserialize<'a> + Serialize,
+>(
+    deps: Deps,
+) -> StdResult<Binary> {
+    let lsd_config: T = read_lsd_config(deps.storage)?;
+    to_binary(&LsdConfigResponse {
+        asset: lsd_config.held_asset(),
+        config: to_binary(&lsd_config)?,
+    })
+}
@@ -1,12 +1,17 @@
 use crate::querier::get_current_exchange_rate;
 use crate::state::read_lsd_config;
 use crate::state::HUB_CONTRACT_KEY;
+use crate::state::{ADMIN, PAUSE};
 use serde::Serialize;
 
 use crate::trait_def::LSDHub;
-use basset::reward::MigrateMsg;
-use basset::wrapper::TokenInfoResponse;
-use cw20_base::contract::query_token_info;
+use basset::pause::Operation;
+use basset::wrapper::MigrateMsg;
+use basset::wrapper::{PendingDecompoundResponse, QueryMsg, TokenInfoResponse};
+use cw20_base::contract::{
+    query_balance, query_download_logo, query_marketing_info, query_minter, query_token_info,
+};
+use cw20_base::enumerable::{query_all_accounts, query_owner_allowances, query_spender_allowances};
 use serde::Deserialize;
 
 use crate::state::WrapperState;
@@ -25,17 +30,25 @@ use cosmwasm_std::Uint128;
 
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult};
 
-use cw20_base::allowances::{execute_decrease_allowance, execute_increase_allowance};
-use cw20_base::contract::query as cw20_query;
+use cw20_base::allowances::{
+    execute_decrease_allowance, execute_increase_allowance, query_allowance,
+};
 use cw20_base::contract::{
     execute_update_marketing, execute_update_minter, execute_upload_logo, instantiate as cw20_init,
 };
-use cw20_base::msg::{InstantiateMsg, QueryMsg};
+use cw20_base::msg::InstantiateMsg;
 
+use crate::access::{execute_grant_role, execute_revoke_role, query_role_holders};
+use crate::config::{execute_update_hub, execute_update_lsd_config, query_lsd_config};
 use crate::handler::*;
+use crate::hooks::{
+    balance_change_accounts, balance_change_hook_msgs, execute_add_hook, execute_remove_hook,
+    execute_sync_hook, query_hooks, read_balances,
+};
 use crate::msg::TokenInitMsg;
+use crate::pause::{assert_not_paused, execute_pause, execute_unpause};
+use basset::contract_error::ContractError;
 use cw20::MinterResponse;
-use cw20_base::ContractError;
 
 pub fn instantiate<
     I: Serialize + for<'b> Deserialize<'b>,
@@ -51,6 +64,13 @@ pub fn instantiate<
 
     store_hub_contract(deps.storage, &deps.api.addr_validate(&msg.hub_contract)?)?;
 
+    let admin = msg
+        .admin
+        .map(|x| deps.api.addr_validate(&x))
+        .transpose()?
+        .unwrap_or_else(|| info.sender.clone());
+    ADMIN.save(deps.storage, &admin)?;
+
     cw20_init(
         deps,
         env.clone(),
@@ -77,66 +97,95 @@ pub fn execute<
     I: Serialize + for<'b> Deserialize<'b>,
     T: LSDHub<I> + for<'a> Deserialize<'a> + Serialize,
 >(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    msg: ExecuteMsg,
+    msg: ExecuteMsg<I>,
 ) -> Result<Response, ContractError> {
-    match msg {
+    // Balances are read before and after the operation to notify the registered hooks
+    let accounts = balance_change_accounts(deps.as_ref(), &info, &msg)?;
+    let old_balances = read_balances(deps.as_ref(), &accounts)?;
+
+    let res = match msg {
         ExecuteMsg::Transfer { recipient, amount } => {
-            execute_transfer(deps, env, info, recipient, amount)
+            execute_transfer(deps.branch(), env, info, recipient, amount)
         }
-        ExecuteMsg::Burn { amount } => execute_burn::<I, T>(deps, env, info, amount),
-        ExecuteMsg::BurnAll {} => execute_burn_all::<I, T>(deps, env, info),
+        ExecuteMsg::Burn { amount } => execute_burn::<I, T>(deps.branch(), env, info, amount),
+        ExecuteMsg::BurnAll {} => execute_burn_all::<I, T>(deps.branch(), env, info),
         ExecuteMsg::Send {
             contract,
             amount,
             msg,
-        } => execute_send(deps, env, info, contract, amount, msg),
+        } => execute_send(deps.branch(), env, info, contract, amount, msg),
         ExecuteMsg::Mint { recipient, amount } => {
-            execute_mint::<I, T>(deps, env, info, recipient, amount)
+            execute_mint::<I, T>(deps.branch(), env, info, recipient, amount)
         }
         ExecuteMsg::MintWith {
             recipient,
             lsd_amount,
-        } => execute_mint_with::<I, T>(deps, env, info, recipient, lsd_amount),
+        } => execute_mint_with::<I, T>(deps.branch(), env, info, recipient, lsd_amount),
         ExecuteMsg::IncreaseAllowance {
             spender,
             amount,
             expires,
-        } => execute_increase_allowance(deps, env, info, spender, amount, expires),
+        } => execute_increase_allowance(deps.branch(), env, info, spender, amount, expires)
+            .map_err(ContractError::from),
         ExecuteMsg::DecreaseAllowance {
             spender,
             amount,
             expires,
-        } => execute_decrease_allowance(deps, env, info, spender, amount, expires),
+        } => execute_decrease_allowance(deps.branch(), env, info, spender, amount, expires)
+            .map_err(ContractError::from),
         ExecuteMsg::TransferFrom {
             owner,
             recipient,
             amount,
-        } => execute_transfer_from(deps, env, info, owner, recipient, amount),
+        } => execute_transfer_from(deps.branch(), env, info, owner, recipient, amount),
         ExecuteMsg::BurnFrom { owner, amount } => {
-            execute_burn_from::<I, T>(deps, env, info, owner, amount)
+            execute_burn_from::<I, T>(deps.branch(), env, info, owner, amount)
         }
         ExecuteMsg::SendFrom {
             owner,
             contract,
             amount,
             msg,
-        } => execute_send_from(deps, env, info, owner, contract, amount, msg),
+        } => execute_send_from(deps.branch(), env, info, owner, contract, amount, msg),
         ExecuteMsg::UpdateMinter { new_minter } => {
-            execute_update_minter(deps, env, info, new_minter)
+            execute_update_minter(deps.branch(), env, info, new_minter).map_err(ContractError::from)
         }
         ExecuteMsg::UpdateMarketing {
             project,
             description,
             marketing,
-        } => execute_update_marketing(deps, env, info, project, description, marketing),
-        ExecuteMsg::UploadLogo(logo) => execute_upload_logo(deps, env, info, logo),
+        } => execute_update_marketing(deps.branch(), env, info, project, description, marketing)
+            .map_err(ContractError::from),
+        ExecuteMsg::UploadLogo(logo) => {
+            execute_upload_logo(deps.branch(), env, info, logo).map_err(ContractError::from)
+        }
         ExecuteMsg::Decompound { recipient } => {
-            execute_decompound::<I, T>(deps, env, info, recipient)
+            execute_decompound::<I, T>(deps.branch(), env, info, recipient)
         }
-    }
+        ExecuteMsg::AddHook { addr } => execute_add_hook(deps.branch(), info, addr),
+        ExecuteMsg::GrantRole { role, addr } => execute_grant_role(deps.branch(), info, role, addr),
+        ExecuteMsg::RevokeRole { role, addr } => {
+            execute_revoke_role(deps.branch(), info, role, addr)
+        }
+        ExecuteMsg::RemoveHook { addr } => execute_remove_hook(deps.branch(), info, addr),
+        ExecuteMsg::SyncHook {
+            addr,
+            start_after,
+            limit,
+        } => execute_sync_hook(deps.branch(), info, addr, start_after, limit),
+        ExecuteMsg::Pause { operations } => execute_pause(deps.branch(), info, operations),
+        ExecuteMsg::Unpause { operations } => execute_unpause(deps.branch(), info, operations),
+        ExecuteMsg::UpdateLsdConfig { config } => {
+            execute_update_lsd_config::<I, T>(deps.branch(), info, config)
+        }
+        ExecuteMsg::UpdateHub { hub } => execute_update_hub(deps.branch(), info, hub),
+    }?;
+
+    let hook_messages = balance_change_hook_msgs(deps.as_ref(), accounts, old_balances)?;
+    Ok(res.add_submessages(hook_messages))
 }
 
 pub fn query<
@@ -157,14 +206,68 @@ pub fn query<
                 symbol: token_info.symbol,
                 decimals: token_info.decimals,
                 total_supply: token_info.total_supply,
-                exchange_rate: get_current_exchange_rate::<I, T>(deps, env, &mut state)
-                    .map_err(|err| StdError::generic_err(err.to_string()))?,
+                exchange_rate: get_current_exchange_rate::<I, T>(deps, env, &mut state)?,
             })
         }
-        _ => cw20_query(deps, env, msg),
+        QueryMsg::Hooks {} => to_binary(&query_hooks(deps)?),
+        QueryMsg::RoleHolders { role } => to_binary(&query_role_holders(deps, role)?),
+        QueryMsg::PauseStatus {} => to_binary(&PAUSE.query_status(deps.storage)?),
+        QueryMsg::PendingDecompound {} => {
+            to_binary(&query_pending_decompound::<I, T>(deps, env)?)
+        }
+        QueryMsg::LsdConfig {} => query_lsd_config::<I, T>(deps),
+        QueryMsg::BalanceAt { .. } | QueryMsg::TotalSupplyAt { .. } => Err(
+            StdError::generic_err("Snapshots are not supported by this wrapper"),
+        ),
+        QueryMsg::GetMintAmount { .. } | QueryMsg::GetExpectedExchangeRate {} => Err(
+            StdError::generic_err("Only supported by wrappers with a decompound limit"),
+        ),
+        QueryMsg::Balance { address } => to_binary(&query_balance(deps, address)?),
+        QueryMsg::Minter {} => to_binary(&query_minter(deps)?),
+        QueryMsg::Allowance { owner, spender } => {
+            to_binary(&query_allowance(deps, owner, spender)?)
+        }
+        QueryMsg::AllAllowances {
+            owner,
+            start_after,
+            limit,
+        } => to_binary(&query_owner_allowances(deps, owner, start_after, limit)?),
+        QueryMsg::AllSpenderAllowances {
+            spender,
+            start_after,
+            limit,
+        } => to_binary(&query_spender_allowances(
+            deps,
+            spender,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::AllAccounts { start_after, limit } => {
+            to_binary(&query_all_accounts(deps, start_after, limit)?)
+        }
+        QueryMsg::MarketingInfo {} => to_binary(&query_marketing_info(deps)?),
+        QueryMsg::DownloadLogo {} => to_binary(&query_download_logo(deps)?),
     }
 }
 
+/// Returns the rewards the next decompound would send, nothing when no rewards can be decompounded
+fn query_pending_decompound<
+    I: Serialize + for<'b> Deserialize<'b>,
+    T: LSDHub<I> + Serialize + for<'a> Deserialize<'a>,
+>(
+    deps: Deps,
+    env: Env,
+) -> StdResult<PendingDecompoundResponse> {
+    let rewards = match compute_accrued_rewards::<I, T>(deps, env) {
+        Err(ContractError::Slashed {}) => AccruedRewards::default(),
+        rewards => rewards?,
+    };
+    Ok(PendingDecompoundResponse {
+        luna_rewards: rewards.luna_rewards,
+        lsd_rewards: rewards.lsd_rewards,
+    })
+}
+
 fn compute_accrued_rewards<
     I: Serialize + for<'b> Deserialize<'b>,
     T: LSDHub<I> + Serialize + for<'a> Deserialize<'a>,
@@ -180,9 +283,7 @@ fn compute_accrued_rewards<
     // We can't decompound and we can't recompound
     if current_exchange_rate < Decimal::one() {
         // There is no accrued rewards to decompound.
-        return Err(ContractError::Std(StdError::generic_err(
-            "No rewards to decompound",
-        )));
+        return Err(ContractError::Slashed {});
     }
     // Else , we have some available rewards to decompound
     let mut luna_rewards = state.backing_luna * Uint128::one() - state.wlsd_supply;
@@ -228,6 +329,7 @@ pub fn execute_decompound<
     info: MessageInfo,
     recipient: Option<String>,
 ) -> Result<Response, ContractError> {
+    assert_not_paused(deps.storage, Operation::Decompound)?;
     let hub_contract = HUB_CONTRACT_KEY.load(deps.storage)?;
     if info.sender != hub_contract {
         return Err(ContractError::Unauthorized {});
@@ -239,16 +341,10 @@ pub fn execute_decompound<
         .unwrap_or(info.sender);
 
     let lsd_config: T = read_lsd_config(deps.storage)?;
-    let slashing_error = ContractError::Std(StdError::generic_err("No rewards to decompound"));
     let (out_messages, accrued_rewards) =
         match compute_accrued_rewards::<I, T>(deps.as_ref(), env.clone()) {
-            Err(err) => {
-                if err == slashing_error {
-                    Ok((vec![], AccruedRewards::default()))
-                } else {
-                    Err(err)
-                }
-            }
+            Err(ContractError::Slashed {}) => Ok((vec![], AccruedRewards::default())),
+            Err(err) => Err(err),
             Ok(rewards) => {
                 let decompound_messages = if !rewards.lsd_rewards.is_zero() {
                     lsd_config.send_funds(deps.as_ref(), env, rewards.lsd_rewards, recipient)?
@@ -266,6 +362,7 @@ pub fn execute_decompound<
                 "total_luna_rewards",
                 accrued_rewards.luna_rewards.to_string(),
             ),
+            attr("lsd_rewards", accrued_rewards.lsd_rewards.to_string()),
         ])
         .add_messages(out_messages);
 
@@ -290,7 +387,10 @@ decompound_rate: Option<Decimal>,
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
     //Err(StdError::GenericErr { msg: "No Migrate Implemented".to_string() })
+    if let Some(admin) = msg.admin {
+        ADMIN.save(deps.storage, &deps.api.addr_validate(&admin)?)?;
+    }
     Ok(Response::default())
 }
@@ -0,0 +1,203 @@
+use crate::state::{ACCESS, ADMIN, HOOKS};
+use basset::access::Role;
+use basset::wrapper::{BalanceChange, BalanceChangeHookMsg, ExecuteMsg, HooksResponse};
+
+use basset::contract_error::ContractError;
+use cosmwasm_std::{
+    attr, to_binary, Addr, Deps, DepsMut, MessageInfo, Response, StdError, StdResult, SubMsg,
+    Uint128, WasmMsg,
+};
+use cw20_base::contract::query_balance;
+use cw20_base::enumerable::query_all_accounts;
+
+pub(crate) fn assert_admin(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+    let admin = ADMIN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::Unauthorized {})?;
+    if !ACCESS.has_role(deps.storage, Role::Owner, &info.sender, &admin)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+pub fn execute_add_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info)?;
+
+    let hook = deps.api.addr_validate(&addr)?;
+    let mut hooks = HOOKS.may_load(deps.storage)?.unwrap_or_default();
+    if hooks.contains(&hook) {
+        return Err(ContractError::Std(StdError::generic_err(
+            "Given address already registered as a hook",
+        )));
+    }
+    hooks.push(hook.clone());
+    HOOKS.save(deps.storage, &hooks)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "add_hook"), attr("hook", hook)]))
+}
+
+pub fn execute_remove_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info)?;
+
+    let hook = deps.api.addr_validate(&addr)?;
+    let mut hooks = HOOKS.may_load(deps.storage)?.unwrap_or_default();
+    let position = hooks
+        .iter()
+        .position(|h| *h == hook)
+        .ok_or_else(|| StdError::generic_err("Given address not registered as a hook"))?;
+    hooks.remove(position);
+    HOOKS.save(deps.storage, &hooks)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "remove_hook"), attr("hook", hook)]))
+}
+
+/// Sends the current balance of a page of holders to a registered hook, as if they had just changed.
+/// A hook registered after tokens were minted doesn't know the existing balances until they are synced
+pub fn execute_sync_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    addr: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info)?;
+
+    let hook = deps.api.addr_validate(&addr)?;
+    if !HOOKS
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .contains(&hook)
+    {
+        return Err(ContractError::Std(StdError::generic_err(
+            "Given address not registered as a hook",
+        )));
+    }
+
+    let accounts = query_all_accounts(deps.as_ref(), start_after, limit)?.accounts;
+    let changes = accounts
+        .iter()
+        .map(|account| {
+            let balance = query_balance(deps.as_ref(), account.clone())?.balance;
+            Ok(BalanceChange {
+                address: account.clone(),
+                old: balance,
+                new: balance,
+            })
+        })
+        .collect::<StdResult<Vec<BalanceChange>>>()?;
+
+    let mut res = Response::new().add_attributes(vec![
+        attr("action", "sync_hook"),
+        attr("hook", hook.to_string()),
+        attr("accounts", accounts.len().to_string()),
+    ]);
+    if let Some(last) = accounts.last() {
+        res = res.add_attribute("last_account", last);
+    }
+    if !changes.is_empty() {
+        res = res.add_message(WasmMsg::Execute {
+            contract_addr: hook.to_string(),
+            msg: to_binary(&BalanceChangeHookMsg::BalanceChangeHook { changes })?,
+            funds: vec![],
+        });
+    }
+    Ok(res)
+}
+
+pub fn query_hooks(deps: Deps) -> StdResult<HooksResponse> {
+    let hooks = HOOKS.may_load(deps.storage)?.unwrap_or_default();
+    Ok(HooksResponse {
+        hooks: hooks.into_iter().map(|h| h.to_string()).collect(),
+    })
+}
+
+/// Returns the accounts whose balance may be changed by the message.
+/// Nothing is returned when no hook is registered, so that no balance needs to be tracked
+pub fn balance_change_accounts<I>(
+    deps: Deps,
+    info: &MessageInfo,
+    msg: &ExecuteMsg<I>,
+) -> StdResult<Vec<Addr>> {
+    if HOOKS.may_load(deps.storage)?.unwrap_or_default().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let sender = info.sender.to_string();
+    let accounts = match msg {
+        ExecuteMsg::Transfer { recipient, .. } => vec![sender, recipient.clone()],
+        ExecuteMsg::Send { contract, .. } => vec![sender, contract.clone()],
+        ExecuteMsg::Burn { .. } | ExecuteMsg::BurnAll {} => vec![sender],
+        ExecuteMsg::Mint { recipient, .. } | ExecuteMsg::MintWith { recipient, .. } => {
+            vec![recipient.clone()]
+        }
+        ExecuteMsg::TransferFrom {
+            owner, recipient, ..
+        } => vec![owner.clone(), recipient.clone()],
+        ExecuteMsg::SendFrom {
+            owner, contract, ..
+        } => vec![owner.clone(), contract.clone()],
+        ExecuteMsg::BurnFrom { owner, .. } => vec![owner.clone()],
+        _ => vec![],
+    };
+
+    let mut addresses: Vec<Addr> = vec![];
+    for account in accounts {
+        let address = deps.api.addr_validate(&account)?;
+        if !addresses.contains(&address) {
+            addresses.push(address);
+        }
+    }
+    Ok(addresses)
+}
+
+pub fn read_balances(deps: Deps, accounts: &[Addr]) -> StdResult<Vec<Uint128>> {
+    accounts
+        .iter()
+        .map(|account| Ok(query_balance(deps, account.to_string())?.balance))
+        .collect()
+}
+
+/// Creates the messages notifying every hook of the balances that changed since `old_balances` were read.
+/// The hooks keep the holders accounting of the reward contracts, so a failing hook reverts the operation
+pub fn balance_change_hook_msgs(
+    deps: Deps,
+    accounts: Vec<Addr>,
+    old_balances: Vec<Uint128>,
+) -> StdResult<Vec<SubMsg>> {
+    let new_balances = read_balances(deps, &accounts)?;
+    let changes: Vec<BalanceChange> = accounts
+        .into_iter()
+        .zip(old_balances.into_iter().zip(new_balances))
+        .filter(|(_, (old, new))| old != new)
+        .map(|(address, (old, new))| BalanceChange {
+            address: address.to_string(),
+            old,
+            new,
+        })
+        .collect();
+
+    if changes.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let msg = to_binary(&BalanceChangeHookMsg::BalanceChangeHook { changes })?;
+    let hooks = HOOKS.may_load(deps.storage)?.unwrap_or_default();
+    Ok(hooks
+        .into_iter()
+        .map(|hook| {
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: hook.to_string(),
+                msg: msg.clone(),
+                funds: vec![],
+            })
+        })
+        .collect())
+}
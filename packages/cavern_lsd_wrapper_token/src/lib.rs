@@ -2,6 +2,10 @@ pub mod contract;
 pub mod msg;
 pub mod state;
 
+mod access;
+mod config;
 mod handler;
+mod hooks;
+mod pause;
 mod querier;
 pub mod trait_def;
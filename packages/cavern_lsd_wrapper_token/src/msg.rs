@@ -13,5 +13,8 @@ pub struct TokenInitMsg<I> {
     // only hub contract can call decompound
     pub hub_contract: String,
 
+    // manages the balance change hooks, defaults to the sender
+    pub admin: Option<String>,
+
     pub lsd_config: I,
 }
@@ -6,8 +6,8 @@ use cosmwasm_std::Deps;
 use cosmwasm_std::Env;
 use cosmwasm_std::Uint128;
 
+use basset::contract_error::ContractError;
 use cw20_base::contract::query_token_info;
-use cw20_base::ContractError;
 
 use crate::state::read_lsd_config;
 use crate::state::WrapperState;
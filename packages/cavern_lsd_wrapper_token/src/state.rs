@@ -1,4 +1,7 @@
 use crate::trait_def::LSDHub;
+use basset::access::AccessControl;
+use basset::pause::{Operation, PauseFlags};
+use basset::wrapper::HUB_CONTRACT_STORAGE_KEY;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Decimal;
 use cosmwasm_std::Uint128;
@@ -9,7 +12,23 @@ use serde::Serialize;
 use cw_storage_plus::Item;
 
 pub const LSD_CONFIG_KEY: &str = "lcd_config";
-pub const HUB_CONTRACT_KEY: Item<Addr> = Item::new("\u{0}\u{c}hub_contract");
+pub const HUB_CONTRACT_KEY: Item<Addr> = Item::new(HUB_CONTRACT_STORAGE_KEY);
+
+// The admin manages the contracts notified of balance changes, the lsd config and the hub
+pub const ADMIN: Item<Addr> = Item::new("admin");
+pub const HOOKS: Item<Vec<Addr>> = Item::new("hooks");
+/// Guardians and keepers, by role. The admin holds the owner role
+pub const ACCESS: AccessControl = AccessControl::new("roles");
+/// Operations stopped by a guardian
+pub const PAUSE: PauseFlags = PauseFlags::new(
+    "paused",
+    &[
+        Operation::Mint,
+        Operation::Burn,
+        Operation::Transfer,
+        Operation::Decompound,
+    ],
+);
 
 #[cw_serde]
 pub struct LsdContracts {
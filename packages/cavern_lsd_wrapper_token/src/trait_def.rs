@@ -1,3 +1,5 @@
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
 use cosmwasm_std::MessageInfo;
 use serde::{Deserialize, Serialize};
 
@@ -6,15 +8,16 @@ use cosmwasm_std::CosmosMsg;
 use cosmwasm_std::Decimal;
 use cosmwasm_std::Deps;
 use cosmwasm_std::Env;
-use cosmwasm_std::StdResult;
 use cosmwasm_std::Uint128;
 
 pub trait LSDHub<I: for<'a> Deserialize<'a> + Serialize> {
-    fn instantiate_config(deps: Deps, t: I) -> StdResult<Self>
+    fn instantiate_config(deps: Deps, t: I) -> Result<Self, ContractError>
     where
         Self: std::marker::Sized;
-    fn query_exchange_rate(&self, deps: Deps, env: Env) -> StdResult<Decimal>;
-    fn get_balance(&self, deps: Deps, env: Env, address: Addr) -> StdResult<Uint128>;
+    fn query_exchange_rate(&self, deps: Deps, env: Env) -> Result<Decimal, ContractError>;
+    /// Asset deposited into the wrapper, it can't change once instantiated
+    fn held_asset(&self) -> AssetInfo;
+    fn get_balance(&self, deps: Deps, env: Env, address: Addr) -> Result<Uint128, ContractError>;
     fn deposit_funds(
         &self,
         deps: Deps,
@@ -22,12 +25,12 @@ pub trait LSDHub<I: for<'a> Deserialize<'a> + Serialize> {
         info: MessageInfo,
         amount: Uint128,
         from: Addr,
-    ) -> StdResult<Vec<CosmosMsg>>;
+    ) -> Result<Vec<CosmosMsg>, ContractError>;
     fn send_funds(
         &self,
         deps: Deps,
         env: Env,
         amount: Uint128,
         to: Addr,
-    ) -> StdResult<Vec<CosmosMsg>>;
+    ) -> Result<Vec<CosmosMsg>, ContractError>;
 }
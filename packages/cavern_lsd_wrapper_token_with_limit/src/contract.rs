@@ -6,11 +6,13 @@ use crate::state::read_lsd_config;
 use crate::state::read_lsd_decompound_rate;
 use crate::state::HUB_CONTRACT_KEY;
 use crate::state::{DecompoundConfig, DecompoundState, DECOMPOUND_CONFIG, DECOMPOUND_STATE};
+use crate::state::{ADMIN, PAUSE};
 use crate::trait_def::LSDHub;
-use basset::reward::MigrateMsg;
+use basset::pause::Operation;
+use basset::wrapper::MigrateMsg;
 use basset::wrapper::{
     AccruedRewardsLimited, GetExpectedExchangeRateResponse, MintAmountReponseWithLimit,
-    TokenInfoResponseWithLimit,
+    PendingDecompoundResponse, TokenInfoResponseWithLimit,
 };
 use cw20_base::enumerable::{query_all_accounts, query_owner_allowances, query_spender_allowances};
 use serde::Serialize;
@@ -32,8 +34,19 @@ use cosmwasm_std::{attr, entry_point, to_binary};
 
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult};
 
+use crate::access::{execute_grant_role, execute_revoke_role, query_role_holders};
+use crate::config::{execute_update_hub, execute_update_lsd_config, query_lsd_config};
 use crate::handler::*;
+use crate::hooks::{
+    balance_change_accounts, balance_change_hook_msgs, execute_add_hook, execute_remove_hook,
+    execute_sync_hook, query_hooks, read_balances,
+};
 use crate::msg::TokenInitMsg;
+use crate::pause::{assert_not_paused, execute_pause, execute_unpause};
+use crate::snapshots::{
+    query_balance_at, query_total_supply_at, set_snapshots_enabled, update_snapshots,
+};
+use basset::contract_error::ContractError;
 use basset::wrapper::QueryMsg;
 use cw20::MinterResponse;
 use cw20_base::allowances::{
@@ -44,7 +57,6 @@ use cw20_base::contract::{
     execute_update_marketing, execute_update_minter, execute_upload_logo, instantiate as cw20_init,
 };
 use cw20_base::msg::InstantiateMsg;
-use cw20_base::ContractError;
 
 pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
 
@@ -52,7 +64,7 @@ pub fn instantiate<
     I: Serialize + for<'b> Deserialize<'b>,
     T: LSDHub<I> + Serialize + for<'a> Deserialize<'a>,
 >(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: TokenInitMsg<I>,
@@ -62,6 +74,13 @@ pub fn instantiate<
 
     store_hub_contract(deps.storage, &deps.api.addr_validate(&msg.hub_contract)?)?;
 
+    let admin = msg
+        .admin
+        .map(|x| deps.api.addr_validate(&x))
+        .transpose()?
+        .unwrap_or_else(|| info.sender.clone());
+    ADMIN.save(deps.storage, &admin)?;
+
     DECOMPOUND_CONFIG.save(
         deps.storage,
         &DecompoundConfig {
@@ -79,7 +98,7 @@ pub fn instantiate<
     )?;
 
     cw20_init(
-        deps,
+        deps.branch(),
         env.clone(),
         info,
         InstantiateMsg {
@@ -97,6 +116,10 @@ pub fn instantiate<
     )
     .map_err(|_| StdError::generic_err("CW20 Token init error"))?;
 
+    if msg.enable_snapshots.unwrap_or(false) {
+        set_snapshots_enabled(deps, true, env.block.height)?;
+    }
+
     Ok(Response::default())
 }
 
@@ -104,66 +127,97 @@ pub fn execute<
     I: Serialize + for<'b> Deserialize<'b>,
     T: LSDHub<I> + for<'a> Deserialize<'a> + Serialize,
 >(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    msg: ExecuteMsg,
+    msg: ExecuteMsg<I>,
 ) -> Result<Response, ContractError> {
-    match msg {
+    // Balances are read before and after the operation to notify the registered hooks
+    let accounts = balance_change_accounts(deps.as_ref(), &info, &msg)?;
+    let old_balances = read_balances(deps.as_ref(), &accounts)?;
+    let height = env.block.height;
+
+    let res = match msg {
         ExecuteMsg::Transfer { recipient, amount } => {
-            execute_transfer(deps, env, info, recipient, amount)
+            execute_transfer(deps.branch(), env, info, recipient, amount)
         }
-        ExecuteMsg::Burn { amount } => execute_burn::<I, T>(deps, env, info, amount),
-        ExecuteMsg::BurnAll {} => execute_burn_all::<I, T>(deps, env, info),
+        ExecuteMsg::Burn { amount } => execute_burn::<I, T>(deps.branch(), env, info, amount),
+        ExecuteMsg::BurnAll {} => execute_burn_all::<I, T>(deps.branch(), env, info),
         ExecuteMsg::Send {
             contract,
             amount,
             msg,
-        } => execute_send(deps, env, info, contract, amount, msg),
+        } => execute_send(deps.branch(), env, info, contract, amount, msg),
         ExecuteMsg::Mint { recipient, amount } => {
-            execute_mint::<I, T>(deps, env, info, recipient, amount)
+            execute_mint::<I, T>(deps.branch(), env, info, recipient, amount)
         }
         ExecuteMsg::MintWith {
             recipient,
             lsd_amount,
-        } => execute_mint_with::<I, T>(deps, env, info, recipient, lsd_amount),
+        } => execute_mint_with::<I, T>(deps.branch(), env, info, recipient, lsd_amount),
         ExecuteMsg::IncreaseAllowance {
             spender,
             amount,
             expires,
-        } => execute_increase_allowance(deps, env, info, spender, amount, expires),
+        } => execute_increase_allowance(deps.branch(), env, info, spender, amount, expires)
+            .map_err(ContractError::from),
         ExecuteMsg::DecreaseAllowance {
             spender,
             amount,
             expires,
-        } => execute_decrease_allowance(deps, env, info, spender, amount, expires),
+        } => execute_decrease_allowance(deps.branch(), env, info, spender, amount, expires)
+            .map_err(ContractError::from),
         ExecuteMsg::TransferFrom {
             owner,
             recipient,
             amount,
-        } => execute_transfer_from(deps, env, info, owner, recipient, amount),
+        } => execute_transfer_from(deps.branch(), env, info, owner, recipient, amount),
         ExecuteMsg::BurnFrom { owner, amount } => {
-            execute_burn_from::<I, T>(deps, env, info, owner, amount)
+            execute_burn_from::<I, T>(deps.branch(), env, info, owner, amount)
         }
         ExecuteMsg::SendFrom {
             owner,
             contract,
             amount,
             msg,
-        } => execute_send_from(deps, env, info, owner, contract, amount, msg),
+        } => execute_send_from(deps.branch(), env, info, owner, contract, amount, msg),
         ExecuteMsg::UpdateMinter { new_minter } => {
-            execute_update_minter(deps, env, info, new_minter)
+            execute_update_minter(deps.branch(), env, info, new_minter).map_err(ContractError::from)
         }
         ExecuteMsg::UpdateMarketing {
             project,
             description,
             marketing,
-        } => execute_update_marketing(deps, env, info, project, description, marketing),
-        ExecuteMsg::UploadLogo(logo) => execute_upload_logo(deps, env, info, logo),
+        } => execute_update_marketing(deps.branch(), env, info, project, description, marketing)
+            .map_err(ContractError::from),
+        ExecuteMsg::UploadLogo(logo) => {
+            execute_upload_logo(deps.branch(), env, info, logo).map_err(ContractError::from)
+        }
         ExecuteMsg::Decompound { recipient } => {
-            execute_decompound::<I, T>(deps, env, info, recipient)
+            execute_decompound::<I, T>(deps.branch(), env, info, recipient)
         }
-    }
+        ExecuteMsg::AddHook { addr } => execute_add_hook(deps.branch(), info, addr),
+        ExecuteMsg::GrantRole { role, addr } => execute_grant_role(deps.branch(), info, role, addr),
+        ExecuteMsg::RevokeRole { role, addr } => {
+            execute_revoke_role(deps.branch(), info, role, addr)
+        }
+        ExecuteMsg::RemoveHook { addr } => execute_remove_hook(deps.branch(), info, addr),
+        ExecuteMsg::SyncHook {
+            addr,
+            start_after,
+            limit,
+        } => execute_sync_hook(deps.branch(), info, addr, start_after, limit),
+        ExecuteMsg::Pause { operations } => execute_pause(deps.branch(), info, operations),
+        ExecuteMsg::Unpause { operations } => execute_unpause(deps.branch(), info, operations),
+        ExecuteMsg::UpdateLsdConfig { config } => {
+            execute_update_lsd_config::<I, T>(deps.branch(), info, config)
+        }
+        ExecuteMsg::UpdateHub { hub } => execute_update_hub(deps.branch(), info, hub),
+    }?;
+
+    update_snapshots(deps.branch(), &accounts, &old_balances, height)?;
+    let hook_messages = balance_change_hook_msgs(deps.as_ref(), accounts, old_balances)?;
+    Ok(res.add_submessages(hook_messages))
 }
 
 pub fn query<
@@ -185,24 +239,24 @@ pub fn query<
                 symbol: token_info.symbol,
                 decimals: token_info.decimals,
                 total_supply: token_info.total_supply,
-                exchange_rate: get_current_exchange_rate::<I, T>(deps, env.clone(), &mut state)
-                    .map_err(|err| StdError::generic_err(err.to_string()))?,
+                exchange_rate: get_current_exchange_rate::<I, T>(deps, env.clone(), &mut state)?,
                 expected_exchange_rate: get_expected_exchange_rate::<I, T>(
                     deps,
                     env.clone(),
                     &mut state,
-                )
-                .map_err(|err| StdError::generic_err(err.to_string()))?,
+                )?,
                 max_decompound_ratio: decompound_rate.max_decompound_ratio,
-                lsd_exchange_rate: get_lsd_wrapper_exchange_rate::<I, T>(deps, env.clone(), vec![])
-                    .map_err(|err| StdError::generic_err(err.to_string()))?,
+                lsd_exchange_rate: get_lsd_wrapper_exchange_rate::<I, T>(
+                    deps,
+                    env.clone(),
+                    vec![],
+                )?,
                 //  max_decompound_ratio: get_lsd_wrapper_decompound_rate(deps, env)
                 //     .map_err(|err| StdError::generic_err(err.to_string()))?,
             })
         }
         QueryMsg::GetMintAmount { amount } => to_binary(&MintAmountReponseWithLimit {
-            mint_amount: query_mint_amount::<I, T>(deps, env.clone(), amount)
-                .map_err(|err| StdError::generic_err(err.to_string()))?,
+            mint_amount: query_mint_amount::<I, T>(deps, env.clone(), amount)?,
         }),
         QueryMsg::Balance { address } => to_binary(&query_balance(deps, address)?),
         QueryMsg::TokenInfo {} => to_binary(&query_token_info(deps)?),
@@ -230,6 +284,17 @@ pub fn query<
         }
         QueryMsg::MarketingInfo {} => to_binary(&query_marketing_info(deps)?),
         QueryMsg::DownloadLogo {} => to_binary(&query_download_logo(deps)?),
+        QueryMsg::Hooks {} => to_binary(&query_hooks(deps)?),
+        QueryMsg::RoleHolders { role } => to_binary(&query_role_holders(deps, role)?),
+        QueryMsg::PauseStatus {} => to_binary(&PAUSE.query_status(deps.storage)?),
+        QueryMsg::PendingDecompound {} => {
+            to_binary(&query_pending_decompound::<I, T>(deps, env)?)
+        }
+        QueryMsg::LsdConfig {} => query_lsd_config::<I, T>(deps),
+        QueryMsg::BalanceAt { address, height } => {
+            to_binary(&query_balance_at(deps, address, height)?)
+        }
+        QueryMsg::TotalSupplyAt { height } => to_binary(&query_total_supply_at(deps, height)?),
         QueryMsg::GetExpectedExchangeRate {} => {
             let mut state = WrapperState::default();
             to_binary(&GetExpectedExchangeRateResponse {
@@ -237,13 +302,30 @@ pub fn query<
                     deps,
                     env.clone(),
                     &mut state,
-                )
-                .map_err(|err| StdError::generic_err(err.to_string()))?,
+                )?,
             })
         }
     }
 }
 
+/// Returns the rewards the next decompound would send, nothing when no rewards can be decompounded
+fn query_pending_decompound<
+    I: Serialize + for<'b> Deserialize<'b>,
+    T: LSDHub<I> + Serialize + for<'a> Deserialize<'a>,
+>(
+    deps: Deps,
+    env: Env,
+) -> StdResult<PendingDecompoundResponse> {
+    let rewards = match compute_accrued_rewards::<I, T>(deps, env) {
+        Err(ContractError::Slashed {}) => AccruedRewardsLimited::default(),
+        rewards => rewards?,
+    };
+    Ok(PendingDecompoundResponse {
+        luna_rewards: rewards.luna_rewards,
+        lsd_rewards: rewards.lsd_rewards,
+    })
+}
+
 fn compute_accrued_rewards<
     I: Serialize + for<'b> Deserialize<'b>,
     T: LSDHub<I> + Serialize + for<'a> Deserialize<'a>,
@@ -259,9 +341,7 @@ fn compute_accrued_rewards<
     // We can't decompound and we can't recompound
     if current_exchange_rate < Decimal::one() {
         // There is no accrued rewards to decompound.
-        return Err(ContractError::Std(StdError::generic_err(
-            "No rewards to decompound",
-        )));
+        return Err(ContractError::Slashed {});
     }
 
     // Else, we have some available rewards to decompound
@@ -289,11 +369,7 @@ fn compute_accrued_rewards<
         // As soon as the ratio goes back to normal, this will stop erroring
         let max_rate = (max_decompound_ratio * Decimal::from_ratio(total_period, SECONDS_PER_YEAR))
             .checked_sub(decompound_state.ratio_sum)
-            .map_err(|_| {
-                ContractError::Std(StdError::generic_err(
-                    "Error substracting the total ratio to the current max_decompound ratio",
-                ))
-            })?;
+            .map_err(|_| ContractError::LimitExceeded {})?;
 
         println!("max_rate : {}", max_rate);
         luna_rewards = luna_rewards.min(max_rate * state.backing_luna * Uint128::one());
@@ -342,6 +418,7 @@ pub fn execute_decompound<
     info: MessageInfo,
     recipient: Option<String>,
 ) -> Result<Response, ContractError> {
+    assert_not_paused(deps.storage, Operation::Decompound)?;
     let hub_contract = HUB_CONTRACT_KEY.load(deps.storage)?;
     if info.sender != hub_contract {
         return Err(ContractError::Unauthorized {});
@@ -353,24 +430,16 @@ pub fn execute_decompound<
         .unwrap_or(info.sender);
 
     let lsd_config: T = read_lsd_config(deps.storage)?;
-    let slashing_error = ContractError::Std(StdError::generic_err("No rewards to decompound"));
     let (out_messages, accrued_rewards) =
         match compute_accrued_rewards::<I, T>(deps.as_ref(), env.clone()) {
-            Err(err) => {
-                if err == slashing_error {
-                    Ok((vec![], AccruedRewardsLimited::default()))
-                } else {
-                    Err(err)
-                }
-            }
+            Err(ContractError::Slashed {}) => Ok((vec![], AccruedRewardsLimited::default())),
+            Err(err) => Err(err),
             Ok(rewards) => {
                 // We save the decreased rewards in the configuration for later usage
                 let old_decompound = DECOMPOUND_STATE.load(deps.storage)?;
 
                 if old_decompound.last_decompound >= env.block.time {
-                    return Err(ContractError::Std(StdError::generic_err(
-                        "Can't decompound too often",
-                    )));
+                    return Err(ContractError::DecompoundTooOften {});
                 }
                 let new_decompound = DecompoundState {
                     ratio_sum: old_decompound.ratio_sum + rewards.rate_decrease,
@@ -382,7 +451,12 @@ pub fn execute_decompound<
                 DECOMPOUND_STATE.save(deps.storage, &new_decompound)?;
 
                 let decompound_messages = if !rewards.lsd_rewards.is_zero() {
-                    lsd_config.send_funds(deps.as_ref(), env, rewards.lsd_rewards, recipient)?
+                    lsd_config.send_funds(
+                        deps.as_ref(),
+                        env.clone(),
+                        rewards.lsd_rewards,
+                        recipient.clone(),
+                    )?
                 } else {
                     vec![]
                 };
@@ -390,6 +464,9 @@ pub fn execute_decompound<
             }
         }?;
 
+    // Incentives earned by the lsd are sent along with the decompounded rewards
+    let harvest_messages = lsd_config.harvest_rewards(deps.as_ref(), env, recipient)?;
+
     let res = Response::new()
         .add_attributes(vec![
             attr("action", "execute_decompound"),
@@ -397,8 +474,10 @@ pub fn execute_decompound<
                 "total_luna_rewards",
                 accrued_rewards.luna_rewards.to_string(),
             ),
+            attr("lsd_rewards", accrued_rewards.lsd_rewards.to_string()),
         ])
-        .add_messages(out_messages);
+        .add_messages(out_messages)
+        .add_messages(harvest_messages);
 
     Ok(res)
 }
@@ -420,22 +499,47 @@ pub fn update_decompound_rate(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
-    // For the spectrum LP, we need to send some LP tokens back to the person that had an error
-
-    // We update the max_decompound_ratio
+pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    // The limit is only replaced or removed when asked. Wrappers without limit start without one
+    let max_decompound_ratio = match (
+        msg.max_decompound_ratio,
+        msg.remove_max_decompound_ratio.unwrap_or(false),
+    ) {
+        (Some(_), true) => {
+            return Err(StdError::generic_err(
+                "The max decompound ratio can't be replaced and removed at once",
+            ))
+        }
+        (Some(ratio), false) => Some(ratio),
+        (None, true) => None,
+        (None, false) => DECOMPOUND_CONFIG
+            .may_load(deps.storage)?
+            .and_then(|config| config.max_decompound_ratio),
+    };
     DECOMPOUND_CONFIG.save(
         deps.storage,
         &DecompoundConfig {
-            max_decompound_ratio: msg.max_decompound_ratio,
+            max_decompound_ratio,
         },
     )?;
-    /*
+    // Wrappers without limit didn't track their decompounds, the limit starts with the migration
+    if DECOMPOUND_STATE.may_load(deps.storage)?.is_none() {
         DECOMPOUND_STATE.save(
             deps.storage,
-            &DecompoundState { ratio_sum: Decimal::zero(), total_seconds: 0, last_decompound: env.block.time })?;
-
-    */
+            &DecompoundState {
+                ratio_sum: Decimal::zero(),
+                total_seconds: 0u64,
+                last_decompound: env.block.time,
+            },
+        )?;
+    }
+    if let Some(admin) = msg.admin {
+        ADMIN.save(deps.storage, &deps.api.addr_validate(&admin)?)?;
+    }
+    // Snapshots can only be enabled once, the balances are seeded as they change
+    if let Some(enable_snapshots) = msg.enable_snapshots {
+        set_snapshots_enabled(deps.branch(), enable_snapshots, env.block.height)?;
+    }
 
     Ok(Response::default())
 }
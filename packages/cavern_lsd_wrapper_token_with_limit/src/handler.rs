@@ -1,6 +1,8 @@
+use crate::pause::assert_not_paused;
 use crate::querier::get_lsd_wrapper_exchange_rate;
 use crate::state::read_lsd_config;
 use crate::trait_def::LSDHub;
+use basset::pause::Operation;
 use cosmwasm_std::Decimal;
 use cosmwasm_std::Deps;
 
@@ -10,6 +12,7 @@ use cw20_base::contract::query_balance;
 use serde::Deserialize;
 use serde::Serialize;
 
+use basset::contract_error::ContractError;
 use cw20_base::allowances::{
     execute_burn_from as cw20_burn_from, execute_send_from as cw20_send_from,
     execute_transfer_from as cw20_transfer_from,
@@ -18,7 +21,6 @@ use cw20_base::contract::{
     execute_burn as cw20_burn, execute_mint as cw20_mint, execute_send as cw20_send,
     execute_transfer as cw20_transfer,
 };
-use cw20_base::ContractError;
 
 pub fn execute_transfer(
     deps: DepsMut,
@@ -27,7 +29,8 @@ pub fn execute_transfer(
     recipient: String,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
-    cw20_transfer(deps, env, info, recipient, amount)
+    assert_not_paused(deps.storage, Operation::Transfer)?;
+    cw20_transfer(deps, env, info, recipient, amount).map_err(ContractError::from)
 }
 
 fn _before_burn<
@@ -57,6 +60,7 @@ pub fn execute_burn<
     info: MessageInfo,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
+    assert_not_paused(deps.storage, Operation::Burn)?;
     let transfer_messages = _before_burn::<I, T>(deps.as_ref(), env.clone(), info.clone(), amount)?;
 
     let res = cw20_burn(deps, env, info, amount)?;
@@ -90,6 +94,7 @@ pub fn execute_mint<
     recipient: String,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
+    assert_not_paused(deps.storage, Operation::Mint)?;
     // In order to mint, we need to transfer the underlying lsd asset to the contract
     // Any sender can call this function as long as they have the sufficient lsd balance
     let lsd_config: T = read_lsd_config(deps.storage)?;
@@ -122,6 +127,7 @@ pub fn execute_mint_with<
     recipient: String,
     lsd_amount: Uint128,
 ) -> Result<Response, ContractError> {
+    assert_not_paused(deps.storage, Operation::Mint)?;
     // In order to mint, we need to transfer the underlying lsd asset to the contract
     // Any sender can call this function as long as they have the sufficient lsd balance
     let lsd_config: T = read_lsd_config(deps.storage)?;
@@ -152,7 +158,8 @@ pub fn execute_send(
     amount: Uint128,
     msg: Binary,
 ) -> Result<Response, ContractError> {
-    cw20_send(deps, env, info, contract, amount, msg)
+    assert_not_paused(deps.storage, Operation::Transfer)?;
+    cw20_send(deps, env, info, contract, amount, msg).map_err(ContractError::from)
 }
 
 pub fn execute_transfer_from(
@@ -163,7 +170,8 @@ pub fn execute_transfer_from(
     recipient: String,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
-    cw20_transfer_from(deps, env, info, owner, recipient, amount)
+    assert_not_paused(deps.storage, Operation::Transfer)?;
+    cw20_transfer_from(deps, env, info, owner, recipient, amount).map_err(ContractError::from)
 }
 
 pub fn execute_burn_from<
@@ -176,6 +184,7 @@ pub fn execute_burn_from<
     owner: String,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
+    assert_not_paused(deps.storage, Operation::Burn)?;
     let transfer_messages = _before_burn::<I, T>(deps.as_ref(), env.clone(), info.clone(), amount)?;
 
     let res = cw20_burn_from(deps, env, info, owner, amount)?;
@@ -192,5 +201,6 @@ pub fn execute_send_from(
     amount: Uint128,
     msg: Binary,
 ) -> Result<Response, ContractError> {
-    cw20_send_from(deps, env, info, owner, contract, amount, msg)
+    assert_not_paused(deps.storage, Operation::Transfer)?;
+    cw20_send_from(deps, env, info, owner, contract, amount, msg).map_err(ContractError::from)
 }
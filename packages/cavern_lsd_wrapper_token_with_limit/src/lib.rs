@@ -2,6 +2,11 @@ pub mod contract;
 pub mod msg;
 pub mod state;
 
+mod access;
+mod config;
 mod handler;
+mod hooks;
+mod pause;
 mod querier;
+mod snapshots;
 pub mod trait_def;
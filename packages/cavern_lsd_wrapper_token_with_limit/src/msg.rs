@@ -17,5 +17,11 @@ pub struct TokenInitMsg<I> {
     // only hub contract can call decompound
     pub hub_contract: String,
 
+    // manages the balance change hooks, defaults to the sender
+    pub admin: Option<String>,
+
+    // keeps the historical balances and total supply
+    pub enable_snapshots: Option<bool>,
+
     pub lsd_config: I,
 }
@@ -0,0 +1,44 @@
+use crate::hooks::assert_admin;
+use crate::state::{ACCESS, ADMIN, PAUSE};
+use basset::access::Role;
+use basset::pause::Operation;
+
+use basset::contract_error::ContractError;
+use cosmwasm_std::{Deps, DepsMut, MessageInfo, Response, Storage};
+
+pub(crate) fn assert_not_paused(
+    storage: &dyn Storage,
+    operation: Operation,
+) -> Result<(), ContractError> {
+    Ok(PAUSE.assert_not_paused(storage, operation)?)
+}
+
+/// The guardians and the admin can pause operations
+pub fn execute_pause(
+    deps: DepsMut,
+    info: MessageInfo,
+    operations: Vec<Operation>,
+) -> Result<Response, ContractError> {
+    assert_guardian(deps.as_ref(), &info)?;
+    Ok(PAUSE.pause(deps.storage, operations)?)
+}
+
+/// Only the admin can resume paused operations
+pub fn execute_unpause(
+    deps: DepsMut,
+    info: MessageInfo,
+    operations: Vec<Operation>,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info)?;
+    Ok(PAUSE.unpause(deps.storage, operations)?)
+}
+
+fn assert_guardian(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+    let admin = ADMIN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::Unauthorized {})?;
+    if !ACCESS.has_role(deps.storage, Role::Guardian, &info.sender, &admin)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
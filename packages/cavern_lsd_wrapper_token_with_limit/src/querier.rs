@@ -7,8 +7,8 @@ use cosmwasm_std::Deps;
 use cosmwasm_std::Env;
 use cosmwasm_std::Uint128;
 
+use basset::contract_error::ContractError;
 use cw20_base::contract::query_token_info;
-use cw20_base::ContractError;
 
 use crate::contract::SECONDS_PER_YEAR;
 use crate::state::{read_lsd_config, read_lsd_decompound_rate};
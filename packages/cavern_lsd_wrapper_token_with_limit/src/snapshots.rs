@@ -0,0 +1,95 @@
+use crate::state::{BALANCE_SNAPSHOTS, SNAPSHOTS_ENABLED, SNAPSHOTS_START, TOTAL_SUPPLY_SNAPSHOTS};
+use basset::wrapper::TotalSupplyResponse;
+
+use cosmwasm_std::{Addr, Deps, DepsMut, StdError, StdResult, Uint128};
+use cw20::BalanceResponse;
+use cw20_base::contract::{query_balance, query_token_info};
+
+pub fn snapshots_enabled(deps: Deps) -> StdResult<bool> {
+    Ok(SNAPSHOTS_ENABLED.may_load(deps.storage)?.unwrap_or(false))
+}
+
+/// Enables or disables the snapshots.
+/// Balances aren't copied when enabling them, each account is seeded on its first balance change.
+/// Once disabled, the snapshots can't be enabled again as the balance changes in between are unknown
+pub fn set_snapshots_enabled(deps: DepsMut, enabled: bool, height: u64) -> StdResult<()> {
+    if enabled && !snapshots_enabled(deps.as_ref())? {
+        if SNAPSHOTS_START.may_load(deps.storage)?.is_some() {
+            return Err(StdError::generic_err(
+                "Snapshots can't be enabled again once disabled",
+            ));
+        }
+        SNAPSHOTS_START.save(deps.storage, &height)?;
+        let total_supply = query_token_info(deps.as_ref())?.total_supply;
+        TOTAL_SUPPLY_SNAPSHOTS.save(deps.storage, &total_supply, seed_height(height))?;
+    }
+    SNAPSHOTS_ENABLED.save(deps.storage, &enabled)
+}
+
+/// Seeds are saved before the start height, so that the snapshots at the start height return them
+fn seed_height(start: u64) -> u64 {
+    start.saturating_sub(1)
+}
+
+/// Copies the current balances of the accounts and the total supply to the snapshots.
+/// Accounts without any snapshot are first seeded with their balance before the operation
+pub fn update_snapshots(
+    deps: DepsMut,
+    accounts: &[Addr],
+    old_balances: &[Uint128],
+    height: u64,
+) -> StdResult<()> {
+    if !snapshots_enabled(deps.as_ref())? {
+        return Ok(());
+    }
+    let start = SNAPSHOTS_START.may_load(deps.storage)?.unwrap_or_default();
+    for (account, old_balance) in accounts.iter().zip(old_balances) {
+        if BALANCE_SNAPSHOTS.may_load(deps.storage, account)?.is_none() {
+            BALANCE_SNAPSHOTS.save(deps.storage, account, old_balance, seed_height(start))?;
+        }
+        let balance = query_balance(deps.as_ref(), account.to_string())?.balance;
+        BALANCE_SNAPSHOTS.save(deps.storage, account, &balance, height)?;
+    }
+    let total_supply = query_token_info(deps.as_ref())?.total_supply;
+    TOTAL_SUPPLY_SNAPSHOTS.save(deps.storage, &total_supply, height)
+}
+
+/// Snapshots can only be queried when enabled, from the height they were enabled at
+fn assert_snapshots_at(deps: Deps, height: u64) -> StdResult<()> {
+    if !snapshots_enabled(deps)? {
+        return Err(StdError::generic_err("Snapshots are not enabled"));
+    }
+    let start = SNAPSHOTS_START.may_load(deps.storage)?.unwrap_or_default();
+    if height < start {
+        return Err(StdError::generic_err(format!(
+            "Snapshots start at height {}",
+            start
+        )));
+    }
+    Ok(())
+}
+
+pub fn query_balance_at(deps: Deps, address: String, height: u64) -> StdResult<BalanceResponse> {
+    assert_snapshots_at(deps, height)?;
+    let address = deps.api.addr_validate(&address)?;
+
+    // Accounts that were never seeded kept the balance they had when the snapshots were enabled
+    if BALANCE_SNAPSHOTS
+        .may_load(deps.storage, &address)?
+        .is_none()
+    {
+        return query_balance(deps, address.to_string());
+    }
+    let balance = BALANCE_SNAPSHOTS
+        .may_load_at_height(deps.storage, &address, height)?
+        .unwrap_or_default();
+    Ok(BalanceResponse { balance })
+}
+
+pub fn query_total_supply_at(deps: Deps, height: u64) -> StdResult<TotalSupplyResponse> {
+    assert_snapshots_at(deps, height)?;
+    let total_supply = TOTAL_SUPPLY_SNAPSHOTS
+        .may_load_at_height(deps.storage, height)?
+        .unwrap_or_default();
+    Ok(TotalSupplyResponse { total_supply })
+}
@@ -1,4 +1,7 @@
 use crate::trait_def::LSDHub;
+use basset::access::AccessControl;
+use basset::pause::{Operation, PauseFlags};
+use basset::wrapper::HUB_CONTRACT_STORAGE_KEY;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Uint128;
 use cosmwasm_std::{Addr, StdResult, Storage};
@@ -6,11 +9,44 @@ use cosmwasm_std::{Decimal, Timestamp};
 use serde::Deserialize;
 use serde::Serialize;
 //use cosmwasm_storage::{singleton, singleton_read};
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
 
 pub const LSD_CONFIG_KEY: &str = "lcd_config";
 pub const DECOMPOUND_CONFIG_KEY: &str = "decompound_config";
-pub const HUB_CONTRACT_KEY: Item<Addr> = Item::new("\u{0}\u{c}hub_contract");
+pub const HUB_CONTRACT_KEY: Item<Addr> = Item::new(HUB_CONTRACT_STORAGE_KEY);
+
+// The admin manages the contracts notified of balance changes, the lsd config and the hub
+pub const ADMIN: Item<Addr> = Item::new("admin");
+pub const HOOKS: Item<Vec<Addr>> = Item::new("hooks");
+/// Guardians and keepers, by role. The admin holds the owner role
+pub const ACCESS: AccessControl = AccessControl::new("roles");
+/// Operations stopped by a guardian
+pub const PAUSE: PauseFlags = PauseFlags::new(
+    "paused",
+    &[
+        Operation::Mint,
+        Operation::Burn,
+        Operation::Transfer,
+        Operation::Decompound,
+    ],
+);
+
+// Historical balances and total supply, only maintained when the snapshots are enabled
+pub const SNAPSHOTS_ENABLED: Item<bool> = Item::new("snapshots_enabled");
+/// Height the snapshots were enabled at, they can't be queried before
+pub const SNAPSHOTS_START: Item<u64> = Item::new("snapshots_start");
+pub const BALANCE_SNAPSHOTS: SnapshotMap<&Addr, Uint128> = SnapshotMap::new(
+    "balance_snapshots",
+    "balance_snapshots__checkpoints",
+    "balance_snapshots__changelog",
+    Strategy::EveryBlock,
+);
+pub const TOTAL_SUPPLY_SNAPSHOTS: SnapshotItem<Uint128> = SnapshotItem::new(
+    "total_supply_snapshots",
+    "total_supply_snapshots__checkpoints",
+    "total_supply_snapshots__changelog",
+    Strategy::EveryBlock,
+);
 
 // We need to save the last rates that were decompounded in the past
 pub const DECOMPOUND_STATE: Item<DecompoundState> = Item::new("decompound_state");
@@ -1,11 +1,11 @@
+use crate::native::{assert_native_deposit, query_native_balance_before, send_native};
 use basset::price_querier::query_price;
-use cosmwasm_std::BalanceResponse;
-use cosmwasm_std::BankMsg;
-use cosmwasm_std::BankQuery;
 use cosmwasm_std::Binary;
 use cosmwasm_std::Coin;
 use std::convert::TryInto;
 
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
 use basset::wrapper::ExecuteMsg;
 use basset::wrapper::QueryMsg;
 use cavern_lsd_wrapper_token_with_limit::msg::TokenInitMsg;
@@ -13,14 +13,12 @@ use cosmwasm_std::DepsMut;
 use cosmwasm_std::MessageInfo;
 use cosmwasm_std::Response;
 use cosmwasm_std::StdError;
-use cw20_base::ContractError;
 
-use basset::reward::MigrateMsg;
+use basset::wrapper::MigrateMsg;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Addr;
 use cosmwasm_std::CosmosMsg;
 use cosmwasm_std::Env;
-use cosmwasm_std::QueryRequest;
 use cosmwasm_std::Uint128;
 
 use cosmwasm_std::Deps;
@@ -45,7 +43,7 @@ pub struct StrideLSDConfig {
 }
 
 impl LSDHub<StrideLSDConfigRaw> for StrideLSDConfig {
-    fn instantiate_config(deps: Deps, config: StrideLSDConfigRaw) -> StdResult<Self> {
+    fn instantiate_config(deps: Deps, config: StrideLSDConfigRaw) -> Result<Self, ContractError> {
         Ok(Self {
             denom: config.denom,
             oracle_contract: deps.api.addr_validate(&config.oracle_contract)?,
@@ -53,7 +51,7 @@ impl LSDHub<StrideLSDConfigRaw> for StrideLSDConfig {
         })
     }
 
-    fn query_exchange_rate(&self, deps: Deps, _env: Env) -> StdResult<Decimal> {
+    fn query_exchange_rate(&self, deps: Deps, _env: Env) -> Result<Decimal, ContractError> {
         // For stride based tokens, the token is a native token and the exchange rate is not queryable on Terra
         // Therefore, we need to have an external oracle feed us the token prices
         let exchange_rate = query_price(
@@ -66,8 +64,16 @@ impl LSDHub<StrideLSDConfigRaw> for StrideLSDConfig {
         .rate;
         let uint128_atomics: Uint128 = exchange_rate.atomics().try_into()?;
         // Decimal256 and Decimal have the same decimal places.
-        Decimal::from_atomics(uint128_atomics, exchange_rate.decimal_places())
-            .map_err(|e| StdError::generic_err(e.to_string()))
+        Ok(
+            Decimal::from_atomics(uint128_atomics, exchange_rate.decimal_places())
+                .map_err(|e| StdError::generic_err(e.to_string()))?,
+        )
+    }
+
+    fn held_asset(&self) -> AssetInfo {
+        AssetInfo::NativeToken {
+            denom: self.denom.clone(),
+        }
     }
 
     fn get_balance(
@@ -76,21 +82,8 @@ impl LSDHub<StrideLSDConfigRaw> for StrideLSDConfig {
         _env: Env,
         address: Addr,
         funds: Vec<Coin>,
-    ) -> StdResult<Uint128> {
-        let balance: BalanceResponse =
-            deps.querier.query(&QueryRequest::Bank(BankQuery::Balance {
-                address: address.to_string(),
-                denom: self.denom.to_string(),
-            }))?;
-
-        // We need to verify that the sent funds don't contaminate the queried balance
-        let denom_balance = funds
-            .iter()
-            .find(|c| c.denom.eq(&self.denom))
-            .map(|c| c.amount)
-            .unwrap_or(Uint128::zero());
-
-        Ok(balance.amount.amount - denom_balance)
+    ) -> Result<Uint128, ContractError> {
+        query_native_balance_before(deps, &address, &self.denom, &funds)
     }
 
     fn deposit_funds(
@@ -100,18 +93,8 @@ impl LSDHub<StrideLSDConfigRaw> for StrideLSDConfig {
         info: MessageInfo,
         amount: Uint128,
         _from: Addr,
-    ) -> StdResult<Vec<CosmosMsg>> {
-        if info.funds.len() != 1
-            || info.funds[0].denom != self.denom
-            || info.funds[0].amount < amount
-        {
-            return Err(StdError::generic_err(format!(
-                "You need to deposit the right funds, deposited {:?}, needed {}{}",
-                info.funds, amount, self.denom
-            )));
-        }
-
-        Ok(vec![])
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        assert_native_deposit(&info, amount, &self.denom)
     }
 
     fn send_funds(
@@ -120,18 +103,13 @@ impl LSDHub<StrideLSDConfigRaw> for StrideLSDConfig {
         _env: Env,
         amount: Uint128,
         to: Addr,
-    ) -> StdResult<Vec<CosmosMsg>> {
-        Ok(vec![CosmosMsg::Bank(BankMsg::Send {
-            to_address: to.to_string(),
-            amount: vec![Coin {
-                denom: self.denom.clone(),
-                amount,
-            }],
-        })])
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        Ok(send_native(amount, &to, &self.denom))
     }
 }
 
 pub type StrideInitMsg = TokenInitMsg<StrideLSDConfigRaw>;
+pub type StrideExecuteMsg = ExecuteMsg<StrideLSDConfigRaw>;
 
 pub fn instantiate(
     deps: DepsMut,
@@ -148,7 +126,7 @@ pub fn execute(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    msg: ExecuteMsg,
+    msg: StrideExecuteMsg,
 ) -> Result<Response, ContractError> {
     cavern_lsd_wrapper_token_with_limit::contract::execute::<StrideLSDConfigRaw, StrideLSDConfig>(
         deps, env, info, msg,
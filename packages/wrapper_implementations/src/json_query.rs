@@ -0,0 +1,372 @@
+use std::fmt;
+use std::str::FromStr;
+
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
+use basset::wrapper::ExecuteMsg;
+use basset::wrapper::MigrateMsg;
+use basset::wrapper::QueryMsg;
+use cavern_lsd_wrapper_token_with_limit::msg::TokenInitMsg;
+use cavern_lsd_wrapper_token_with_limit::trait_def::LSDHub;
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::from_slice;
+use cosmwasm_std::to_binary;
+use cosmwasm_std::Addr;
+use cosmwasm_std::BalanceResponse as NativeBalanceResponse;
+use cosmwasm_std::BankMsg;
+use cosmwasm_std::BankQuery;
+use cosmwasm_std::Binary;
+use cosmwasm_std::Coin;
+use cosmwasm_std::CosmosMsg;
+use cosmwasm_std::Decimal;
+use cosmwasm_std::Deps;
+use cosmwasm_std::DepsMut;
+use cosmwasm_std::Env;
+use cosmwasm_std::MessageInfo;
+use cosmwasm_std::QueryRequest;
+use cosmwasm_std::Response;
+use cosmwasm_std::StdError;
+use cosmwasm_std::StdResult;
+use cosmwasm_std::Uint128;
+use cosmwasm_std::WasmMsg;
+use cosmwasm_std::WasmQuery;
+
+use cw20::BalanceResponse;
+use cw20::Cw20ExecuteMsg;
+use cw20::Cw20QueryMsg;
+
+#[cw_serde]
+pub enum LsdAssetRaw {
+    Cw20 { token: String },
+    Native { denom: String },
+}
+
+#[cw_serde]
+pub enum LsdAsset {
+    Cw20 { token: Addr },
+    Native { denom: String },
+}
+
+#[cw_serde]
+pub struct JsonQueryLSDConfigRaw {
+    /// Contract queried for the exchange rate of the lsd
+    pub contract: String,
+    /// Raw JSON query sent to the contract
+    pub query_msg: Binary,
+    /// Dot separated path to the exchange rate in the response, e.g. `state.exchange_rate`.
+    /// Array elements are selected by their index
+    pub exchange_rate_path: String,
+    /// Set when the contract quotes the underlying asset in lsd instead of the lsd in underlying asset
+    pub invert: Option<bool>,
+    /// Token deposited into the wrapper
+    pub asset: LsdAssetRaw,
+}
+
+/// Lsd wrapper config that reads the exchange rate from any contract query,
+/// so that a new lsd only needs a new instantiation
+#[cw_serde]
+pub struct JsonQueryLSDHub {
+    pub contract: Addr,
+    pub query_msg: Binary,
+    pub exchange_rate_path: Vec<String>,
+    pub invert: bool,
+    pub asset: LsdAsset,
+}
+
+impl LSDHub<JsonQueryLSDConfigRaw> for JsonQueryLSDHub {
+    fn instantiate_config(
+        deps: Deps,
+        config: JsonQueryLSDConfigRaw,
+    ) -> Result<Self, ContractError> {
+        from_slice::<JsonValue>(&config.query_msg)
+            .map_err(|err| StdError::generic_err(format!("invalid query msg: {}", err)))?;
+
+        let exchange_rate_path: Vec<String> = config
+            .exchange_rate_path
+            .split('.')
+            .map(|key| key.to_string())
+            .collect();
+        if exchange_rate_path.iter().any(|key| key.is_empty()) {
+            return Err(StdError::generic_err(format!(
+                "invalid exchange rate path: {}",
+                config.exchange_rate_path
+            ))
+            .into());
+        }
+
+        let asset = match config.asset {
+            LsdAssetRaw::Cw20 { token } => LsdAsset::Cw20 {
+                token: deps.api.addr_validate(&token)?,
+            },
+            LsdAssetRaw::Native { denom } => LsdAsset::Native { denom },
+        };
+
+        Ok(Self {
+            contract: deps.api.addr_validate(&config.contract)?,
+            query_msg: config.query_msg,
+            exchange_rate_path,
+            invert: config.invert.unwrap_or(false),
+            asset,
+        })
+    }
+
+    fn query_exchange_rate(&self, deps: Deps, _env: Env) -> Result<Decimal, ContractError> {
+        let response: JsonValue = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: self.contract.to_string(),
+            msg: self.query_msg.clone(),
+        }))?;
+
+        let exchange_rate = response.exchange_rate(&self.exchange_rate_path)?;
+        if !self.invert {
+            return Ok(exchange_rate);
+        }
+        Ok(exchange_rate
+            .inv()
+            .ok_or_else(|| StdError::generic_err("the lsd exchange rate is zero"))?)
+    }
+
+    fn held_asset(&self) -> AssetInfo {
+        match &self.asset {
+            LsdAsset::Cw20 { token } => AssetInfo::Token {
+                contract_addr: token.clone(),
+            },
+            LsdAsset::Native { denom } => AssetInfo::NativeToken {
+                denom: denom.clone(),
+            },
+        }
+    }
+
+    fn get_balance(
+        &self,
+        deps: Deps,
+        _env: Env,
+        address: Addr,
+        funds: Vec<Coin>,
+    ) -> Result<Uint128, ContractError> {
+        match &self.asset {
+            LsdAsset::Cw20 { token } => {
+                let balance: BalanceResponse =
+                    deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                        contract_addr: token.to_string(),
+                        msg: to_binary(&Cw20QueryMsg::Balance {
+                            address: address.to_string(),
+                        })?,
+                    }))?;
+                Ok(balance.balance)
+            }
+            LsdAsset::Native { denom } => {
+                let balance: NativeBalanceResponse =
+                    deps.querier.query(&QueryRequest::Bank(BankQuery::Balance {
+                        address: address.to_string(),
+                        denom: denom.to_string(),
+                    }))?;
+
+                // We need to verify that the sent funds don't contaminate the queried balance
+                let denom_balance = funds
+                    .iter()
+                    .find(|c| c.denom.eq(denom))
+                    .map(|c| c.amount)
+                    .unwrap_or(Uint128::zero());
+
+                Ok(balance.amount.amount - denom_balance)
+            }
+        }
+    }
+
+    fn deposit_funds(
+        &self,
+        _deps: Deps,
+        env: Env,
+        info: MessageInfo,
+        amount: Uint128,
+        from: Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        match &self.asset {
+            LsdAsset::Cw20 { token } => Ok(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: token.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                    owner: from.to_string(),
+                    recipient: env.contract.address.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            })]),
+            LsdAsset::Native { denom } => {
+                if info.funds.len() != 1
+                    || info.funds[0].denom != *denom
+                    || info.funds[0].amount < amount
+                {
+                    return Err(ContractError::Std(StdError::generic_err(format!(
+                        "You need to deposit the right funds, deposited {:?}, needed {}{}",
+                        info.funds, amount, denom
+                    ))));
+                }
+                Ok(vec![])
+            }
+        }
+    }
+
+    fn send_funds(
+        &self,
+        _deps: Deps,
+        _env: Env,
+        amount: Uint128,
+        to: Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        match &self.asset {
+            LsdAsset::Cw20 { token } => Ok(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: token.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: to.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            })]),
+            LsdAsset::Native { denom } => Ok(vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: to.to_string(),
+                amount: vec![Coin {
+                    denom: denom.clone(),
+                    amount,
+                }],
+            })]),
+        }
+    }
+}
+
+/// JSON document returned by the queried contract.
+/// Numbers are kept as text, the contracts don't use floats. Null and booleans can't hold a rate
+enum JsonValue {
+    Other,
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(fields) => fields
+                .iter()
+                .find(|(field, _)| field == key)
+                .map(|(_, value)| value),
+            JsonValue::Array(values) => values.get(key.parse::<usize>().ok()?),
+            _ => None,
+        }
+    }
+
+    fn exchange_rate(&self, path: &[String]) -> StdResult<Decimal> {
+        let value = path
+            .iter()
+            .try_fold(self, |value, key| value.get(key))
+            .ok_or_else(|| {
+                StdError::generic_err(format!("no exchange rate at {}", path.join(".")))
+            })?;
+        match value {
+            JsonValue::String(rate) | JsonValue::Number(rate) => Decimal::from_str(rate),
+            _ => Err(StdError::generic_err(format!(
+                "the exchange rate at {} is not a number",
+                path.join(".")
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(JsonValueVisitor)
+    }
+}
+
+struct JsonValueVisitor;
+
+impl<'de> Visitor<'de> for JsonValueVisitor {
+    type Value = JsonValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_unit<E>(self) -> Result<JsonValue, E> {
+        Ok(JsonValue::Other)
+    }
+
+    fn visit_none<E>(self) -> Result<JsonValue, E> {
+        Ok(JsonValue::Other)
+    }
+
+    fn visit_bool<E>(self, _value: bool) -> Result<JsonValue, E> {
+        Ok(JsonValue::Other)
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<JsonValue, E> {
+        Ok(JsonValue::Number(value.to_string()))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<JsonValue, E> {
+        Ok(JsonValue::Number(value.to_string()))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<JsonValue, E> {
+        Ok(JsonValue::String(value.to_string()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<JsonValue, E> {
+        Ok(JsonValue::String(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<JsonValue, A::Error> {
+        let mut values = vec![];
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(JsonValue::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonValue, A::Error> {
+        let mut fields = vec![];
+        while let Some(field) = map.next_entry()? {
+            fields.push(field);
+        }
+        Ok(JsonValue::Object(fields))
+    }
+}
+
+pub type JsonQueryInitMsg = TokenInitMsg<JsonQueryLSDConfigRaw>;
+pub type JsonQueryExecuteMsg = ExecuteMsg<JsonQueryLSDConfigRaw>;
+
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: JsonQueryInitMsg,
+) -> StdResult<Response> {
+    cavern_lsd_wrapper_token_with_limit::contract::instantiate::<
+        JsonQueryLSDConfigRaw,
+        JsonQueryLSDHub,
+    >(deps, env, info, msg)
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: JsonQueryExecuteMsg,
+) -> Result<Response, ContractError> {
+    cavern_lsd_wrapper_token_with_limit::contract::execute::<JsonQueryLSDConfigRaw, JsonQueryLSDHub>(
+        deps, env, info, msg,
+    )
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    cavern_lsd_wrapper_token_with_limit::contract::query::<JsonQueryLSDConfigRaw, JsonQueryLSDHub>(
+        deps, env, msg,
+    )
+}
+
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    cavern_lsd_wrapper_token_with_limit::contract::migrate(deps, env, msg)
+}
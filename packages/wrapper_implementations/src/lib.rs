@@ -1,6 +1,11 @@
 pub mod coin;
 pub mod coin_with_limit;
+pub mod json_query;
+pub mod native;
 pub mod steak;
+pub mod steak_with_limit;
+pub mod token_factory;
+pub mod token_factory_with_limit;
 
 #[cfg(test)]
 mod testing;
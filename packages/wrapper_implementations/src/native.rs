@@ -0,0 +1,72 @@
+use basset::contract_error::ContractError;
+use cosmwasm_std::Addr;
+use cosmwasm_std::BalanceResponse;
+use cosmwasm_std::BankMsg;
+use cosmwasm_std::BankQuery;
+use cosmwasm_std::Coin;
+use cosmwasm_std::CosmosMsg;
+use cosmwasm_std::Deps;
+use cosmwasm_std::MessageInfo;
+use cosmwasm_std::QueryRequest;
+use cosmwasm_std::StdError;
+use cosmwasm_std::Uint128;
+
+// Balance, deposits and transfers of the native lsds, shared by the coin and token factory wrappers
+
+pub fn query_native_balance(
+    deps: Deps,
+    address: &Addr,
+    denom: &str,
+) -> Result<Uint128, ContractError> {
+    let balance: BalanceResponse = deps.querier.query(&QueryRequest::Bank(BankQuery::Balance {
+        address: address.to_string(),
+        denom: denom.to_string(),
+    }))?;
+
+    Ok(balance.amount.amount)
+}
+
+/// The balance before the funds sent along with the current message
+pub fn query_native_balance_before(
+    deps: Deps,
+    address: &Addr,
+    denom: &str,
+    funds: &[Coin],
+) -> Result<Uint128, ContractError> {
+    let balance = query_native_balance(deps, address, denom)?;
+
+    // We need to verify that the sent funds don't contaminate the queried balance
+    let denom_balance = funds
+        .iter()
+        .find(|c| c.denom.eq(denom))
+        .map(|c| c.amount)
+        .unwrap_or(Uint128::zero());
+
+    Ok(balance - denom_balance)
+}
+
+/// The funds are sent along with the message, nothing has to be transferred
+pub fn assert_native_deposit(
+    info: &MessageInfo,
+    amount: Uint128,
+    denom: &str,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    if info.funds.len() != 1 || info.funds[0].denom != denom || info.funds[0].amount < amount {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "You need to deposit the right funds, deposited {:?}, needed {}{}",
+            info.funds, amount, denom
+        ))));
+    }
+
+    Ok(vec![])
+}
+
+pub fn send_native(amount: Uint128, to: &Addr, denom: &str) -> Vec<CosmosMsg> {
+    vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: to.to_string(),
+        amount: vec![Coin {
+            denom: denom.to_string(),
+            amount,
+        }],
+    })]
+}
@@ -4,8 +4,10 @@ use cosmwasm_std::Binary;
 use cosmwasm_std::DepsMut;
 use cosmwasm_std::MessageInfo;
 use cosmwasm_std::Response;
-use cw20_base::msg::QueryMsg;
-use cw20_base::ContractError;
+use basset::wrapper::MigrateMsg;
+use basset::wrapper::QueryMsg;
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
 
 use serde::Deserialize;
 use std::marker::PhantomData;
@@ -66,7 +68,7 @@ impl<T: LSDStateResponseTrait + for<'a> Deserialize<'a>> SteakLSDHub<T> {
 impl<T: LSDStateResponseTrait + for<'a> Deserialize<'a>> LSDHub<LsdContractsRaw>
     for SteakLSDHub<T>
 {
-    fn instantiate_config(deps: Deps, config: LsdContractsRaw) -> StdResult<Self> {
+    fn instantiate_config(deps: Deps, config: LsdContractsRaw) -> Result<Self, ContractError> {
         Ok(Self {
             types: None,
             lsd_contracts: LsdContracts {
@@ -76,7 +78,7 @@ impl<T: LSDStateResponseTrait + for<'a> Deserialize<'a>> LSDHub<LsdContractsRaw>
         })
     }
 
-    fn query_exchange_rate(&self, deps: Deps, _env: Env) -> StdResult<Decimal> {
+    fn query_exchange_rate(&self, deps: Deps, _env: Env) -> Result<Decimal, ContractError> {
         // For steak based LSD, we query the corresponding luna value of that LSD
         // This is located in the state query of the LSD
         let lsd_state: T = self.query_lsd_state(deps, &self.lsd_contracts)?;
@@ -84,7 +86,13 @@ impl<T: LSDStateResponseTrait + for<'a> Deserialize<'a>> LSDHub<LsdContractsRaw>
         Ok(lsd_state.exchange_rate())
     }
 
-    fn get_balance(&self, deps: Deps, _env: Env, address: Addr) -> StdResult<Uint128> {
+    fn held_asset(&self) -> AssetInfo {
+        AssetInfo::Token {
+            contract_addr: self.lsd_contracts.token.clone(),
+        }
+    }
+
+    fn get_balance(&self, deps: Deps, _env: Env, address: Addr) -> Result<Uint128, ContractError> {
         let balance: BalanceResponse =
             deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
                 contract_addr: self.lsd_contracts.token.to_string(),
@@ -102,7 +110,7 @@ impl<T: LSDStateResponseTrait + for<'a> Deserialize<'a>> LSDHub<LsdContractsRaw>
         _info: MessageInfo,
         amount: Uint128,
         from: Addr,
-    ) -> StdResult<Vec<CosmosMsg>> {
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
         Ok(vec![CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: self.lsd_contracts.token.to_string(),
             msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
@@ -120,7 +128,7 @@ impl<T: LSDStateResponseTrait + for<'a> Deserialize<'a>> LSDHub<LsdContractsRaw>
         _env: Env,
         amount: Uint128,
         to: Addr,
-    ) -> StdResult<Vec<CosmosMsg>> {
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
         Ok(vec![CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: self.lsd_contracts.token.to_string(),
             msg: to_binary(&Cw20ExecuteMsg::Transfer {
@@ -133,6 +141,7 @@ impl<T: LSDStateResponseTrait + for<'a> Deserialize<'a>> LSDHub<LsdContractsRaw>
 }
 
 pub type SteakInitMsg = TokenInitMsg<LsdContractsRaw>;
+pub type SteakExecuteMsg = ExecuteMsg<LsdContractsRaw>;
 
 pub fn instantiate<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
     deps: DepsMut,
@@ -149,7 +158,7 @@ pub fn execute<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    msg: ExecuteMsg,
+    msg: SteakExecuteMsg,
 ) -> Result<Response, ContractError> {
     cavern_lsd_wrapper_token::contract::execute::<LsdContractsRaw, SteakLSDHub<T>>(
         deps, env, info, msg,
@@ -163,3 +172,7 @@ pub fn query<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
 ) -> StdResult<Binary> {
     cavern_lsd_wrapper_token::contract::query::<LsdContractsRaw, SteakLSDHub<T>>(deps, env, msg)
 }
+
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    cavern_lsd_wrapper_token::contract::migrate(deps, env, msg)
+}
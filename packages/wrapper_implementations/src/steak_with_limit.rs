@@ -0,0 +1,123 @@
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
+use basset::external::LSDStateResponseTrait;
+use basset::wrapper::ExecuteMsg;
+use basset::wrapper::MigrateMsg;
+use basset::wrapper::QueryMsg;
+use cavern_lsd_wrapper_token::trait_def::LSDHub as LSDHubWithoutLimit;
+use cavern_lsd_wrapper_token_with_limit::msg::TokenInitMsg;
+use cavern_lsd_wrapper_token_with_limit::state::read_lsd_config;
+use cavern_lsd_wrapper_token_with_limit::trait_def::LSDHub;
+use serde::Deserialize;
+
+use cosmwasm_std::Addr;
+use cosmwasm_std::Binary;
+use cosmwasm_std::Coin;
+use cosmwasm_std::CosmosMsg;
+use cosmwasm_std::Decimal;
+use cosmwasm_std::Deps;
+use cosmwasm_std::DepsMut;
+use cosmwasm_std::Env;
+use cosmwasm_std::MessageInfo;
+use cosmwasm_std::Response;
+use cosmwasm_std::StdResult;
+use cosmwasm_std::Uint128;
+
+use crate::steak::{LsdContractsRaw, SteakLSDHub};
+
+// The steak wrappers with limit store the same lsd config as the ones without limit,
+// so that the latter can be migrated in place
+impl<T: LSDStateResponseTrait + for<'a> Deserialize<'a>> LSDHub<LsdContractsRaw>
+    for SteakLSDHub<T>
+{
+    fn instantiate_config(deps: Deps, config: LsdContractsRaw) -> Result<Self, ContractError> {
+        <Self as LSDHubWithoutLimit<LsdContractsRaw>>::instantiate_config(deps, config)
+    }
+
+    fn query_exchange_rate(&self, deps: Deps, env: Env) -> Result<Decimal, ContractError> {
+        LSDHubWithoutLimit::<LsdContractsRaw>::query_exchange_rate(self, deps, env)
+    }
+
+    fn held_asset(&self) -> AssetInfo {
+        LSDHubWithoutLimit::<LsdContractsRaw>::held_asset(self)
+    }
+
+    fn get_balance(
+        &self,
+        deps: Deps,
+        env: Env,
+        address: Addr,
+        _funds: Vec<Coin>,
+    ) -> Result<Uint128, ContractError> {
+        // The cw20 deposits are only transferred after the mint, they can't contaminate the balance
+        LSDHubWithoutLimit::<LsdContractsRaw>::get_balance(self, deps, env, address)
+    }
+
+    fn deposit_funds(
+        &self,
+        deps: Deps,
+        env: Env,
+        info: MessageInfo,
+        amount: Uint128,
+        from: Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        LSDHubWithoutLimit::<LsdContractsRaw>::deposit_funds(self, deps, env, info, amount, from)
+    }
+
+    fn send_funds(
+        &self,
+        deps: Deps,
+        env: Env,
+        amount: Uint128,
+        to: Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        LSDHubWithoutLimit::<LsdContractsRaw>::send_funds(self, deps, env, amount, to)
+    }
+}
+
+pub type SteakInitMsg = TokenInitMsg<LsdContractsRaw>;
+pub type SteakExecuteMsg = ExecuteMsg<LsdContractsRaw>;
+
+pub fn instantiate<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: SteakInitMsg,
+) -> StdResult<Response> {
+    cavern_lsd_wrapper_token_with_limit::contract::instantiate::<LsdContractsRaw, SteakLSDHub<T>>(
+        deps, env, info, msg,
+    )
+}
+
+pub fn execute<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: SteakExecuteMsg,
+) -> Result<Response, ContractError> {
+    cavern_lsd_wrapper_token_with_limit::contract::execute::<LsdContractsRaw, SteakLSDHub<T>>(
+        deps, env, info, msg,
+    )
+}
+
+pub fn query<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
+    deps: Deps,
+    env: Env,
+    msg: QueryMsg,
+) -> StdResult<Binary> {
+    cavern_lsd_wrapper_token_with_limit::contract::query::<LsdContractsRaw, SteakLSDHub<T>>(
+        deps, env, msg,
+    )
+}
+
+/// Also migrates the steak wrappers without limit.
+/// Their cw20 balances and lsd config are kept, the decompounds are tracked from the migration on
+pub fn migrate<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
+    deps: DepsMut,
+    env: Env,
+    msg: MigrateMsg,
+) -> StdResult<Response> {
+    // Fails if the stored lsd config isn't the one of a steak wrapper
+    read_lsd_config::<SteakLSDHub<T>>(deps.storage)?;
+    cavern_lsd_wrapper_token_with_limit::contract::migrate(deps, env, msg)
+}
@@ -5,20 +5,33 @@ use std::str::FromStr;
 
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    coins, to_binary, Api, CosmosMsg, Decimal, DepsMut, OwnedDeps, Storage, SubMsg, Uint128,
+    attr, coins, from_binary, to_binary, Addr, Api, CosmosMsg, Decimal, DepsMut, OwnedDeps,
+    StdError, Storage, SubMsg, Uint128, WasmMsg,
 };
 
 use cw20::{Cw20ReceiveMsg, MinterResponse, TokenInfoResponse};
 use cw20_base::contract::{query_minter, query_token_info};
 
-use basset::wrapper::ExecuteMsg;
+use basset::access::Role;
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
+use basset::pause::{Operation, PauseStatusResponse, Paused};
+use basset::wrapper::MigrateMsg;
+use basset::wrapper::{
+    BalanceChange, BalanceChangeHookMsg, ExecuteMsg, HooksResponse, LsdConfigResponse, QueryMsg,
+};
 
 use crate::testing::mock_querier::MOCK_ORACLE_CONTRACT_ADDR;
 use crate::testing::mock_querier::{mock_dependencies, MOCK_LSD_DENOM};
-use cavern_lsd_wrapper_token::contract::{execute, instantiate};
+use cavern_lsd_wrapper_token::contract::{execute, instantiate, query};
 use cavern_lsd_wrapper_token::msg::TokenInitMsg;
+use cavern_lsd_wrapper_token::state::read_hub_contract;
+use cavern_lsd_wrapper_token_with_limit::state::{
+    DecompoundConfig, DecompoundState, DECOMPOUND_CONFIG, DECOMPOUND_STATE,
+};
 
 use crate::coin::{StrideLSDConfig, StrideLSDConfigRaw};
+use crate::coin_with_limit;
 use crate::testing::mock_querier::{WasmMockQuerier, MOCK_HUB_CONTRACT_ADDR};
 
 // this will set up the init for other tests
@@ -54,6 +67,7 @@ fn _do_init<S: Storage, A: Api>(
         decimals: 6,
         initial_balances: vec![],
         hub_contract: MOCK_HUB_CONTRACT_ADDR.to_string(),
+        admin: None,
         lsd_config,
     };
 
@@ -124,6 +138,7 @@ fn proper_initialization() {
         decimals: 6,
         initial_balances: vec![],
         hub_contract: MOCK_HUB_CONTRACT_ADDR.to_string(),
+        admin: None,
         lsd_config,
     };
     let info = mock_info(&String::from("owner"), &[]);
@@ -368,7 +383,7 @@ fn send() {
         Decimal::from_str("1.5").unwrap(),
     );
 
-    let dummy_msg = ExecuteMsg::Transfer {
+    let dummy_msg: ExecuteMsg = ExecuteMsg::Transfer {
         recipient: addr1.clone(),
         amount: Uint128::new(1u128),
     };
@@ -421,7 +436,7 @@ fn send_from() {
     let _ = execute::<StrideLSDConfigRaw, StrideLSDConfig>(deps.as_mut(), mock_env(), info, msg)
         .unwrap();
 
-    let dummy_msg = ExecuteMsg::Transfer {
+    let dummy_msg: ExecuteMsg = ExecuteMsg::Transfer {
         recipient: addr1.clone(),
         amount: Uint128::new(1u128),
     };
@@ -449,3 +464,431 @@ fn send_from() {
         .unwrap()
     );
 }
+
+#[test]
+fn balance_change_hooks() {
+    let mut deps = mock_dependencies(&coins(2, "token"));
+    let addr1 = String::from("addr0001");
+    let addr2 = String::from("addr0002");
+    let amount1 = Uint128::from(12340000u128);
+
+    do_init_with_minter(deps.borrow_mut(), &String::from(MOCK_CONTRACT_ADDR), None);
+    do_mint(
+        deps.as_mut(),
+        addr1.clone(),
+        amount1,
+        Decimal::from_str("1.5").unwrap(),
+    );
+
+    // Only the admin can register hooks
+    let msg = ExecuteMsg::AddHook {
+        addr: "hook".to_string(),
+    };
+    let err = execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(addr1.as_str(), &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        msg,
+    )
+    .unwrap();
+
+    let hooks: HooksResponse = from_binary(
+        &query::<StrideLSDConfigRaw, StrideLSDConfig>(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Hooks {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(hooks.hooks, vec!["hook".to_string()]);
+
+    // Balances minted before the hook was registered are synced
+    let res = execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        ExecuteMsg::SyncHook {
+            addr: "hook".to_string(),
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: "hook".to_string(),
+            msg: to_binary(&BalanceChangeHookMsg::BalanceChangeHook {
+                changes: vec![BalanceChange {
+                    address: addr1.clone(),
+                    old: amount1,
+                    new: amount1,
+                }]
+            })
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+
+    let info = mock_info(addr1.as_str(), &[]);
+    let msg = ExecuteMsg::Transfer {
+        recipient: addr2.clone(),
+        amount: Uint128::new(1u128),
+    };
+    let res = execute::<StrideLSDConfigRaw, StrideLSDConfig>(deps.as_mut(), mock_env(), info, msg)
+        .unwrap();
+
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: "hook".to_string(),
+            msg: to_binary(&BalanceChangeHookMsg::BalanceChangeHook {
+                changes: vec![
+                    BalanceChange {
+                        address: addr1,
+                        old: amount1,
+                        new: amount1 - Uint128::new(1u128),
+                    },
+                    BalanceChange {
+                        address: addr2,
+                        old: Uint128::zero(),
+                        new: Uint128::new(1u128),
+                    },
+                ]
+            })
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+}
+
+#[test]
+fn pause_operations() {
+    let mut deps = mock_dependencies(&coins(2, "token"));
+    let addr1 = String::from("addr0001");
+    let addr2 = String::from("addr0002");
+    let amount1 = Uint128::from(12340000u128);
+
+    do_init_with_minter(deps.borrow_mut(), &String::from(MOCK_CONTRACT_ADDR), None);
+    do_mint(
+        deps.as_mut(),
+        addr1.clone(),
+        amount1,
+        Decimal::from_str("1.5").unwrap(),
+    );
+
+    // Only the guardians and the admin can pause
+    let pause = ExecuteMsg::Pause {
+        operations: vec![Operation::Transfer],
+    };
+    let err = execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        pause.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        ExecuteMsg::GrantRole {
+            role: Role::Guardian,
+            addr: "guardian".to_string(),
+        },
+    )
+    .unwrap();
+    let res = execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        pause,
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![attr("action", "pause"), attr("operations", "transfer")]
+    );
+
+    let status: PauseStatusResponse = from_binary(
+        &query::<StrideLSDConfigRaw, StrideLSDConfig>(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PauseStatus {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(status.paused, vec![Operation::Transfer]);
+
+    let transfer = ExecuteMsg::Transfer {
+        recipient: addr2,
+        amount: Uint128::new(1u128),
+    };
+    let err = execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(addr1.as_str(), &[]),
+        transfer.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Paused(Paused {
+            operation: Operation::Transfer
+        })
+    );
+
+    // The hub updates can't be paused on the wrapper
+    let err = execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::Pause {
+            operations: vec![Operation::Update],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err(
+            "update can't be paused on this contract"
+        ))
+    );
+
+    // Only the admin can unpause
+    let unpause = ExecuteMsg::Unpause {
+        operations: vec![Operation::Transfer],
+    };
+    let err = execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        unpause.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        unpause,
+    )
+    .unwrap();
+    execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(addr1.as_str(), &[]),
+        transfer,
+    )
+    .unwrap();
+}
+
+#[test]
+fn migrate_to_wrapper_with_limit() {
+    let mut deps = mock_dependencies(&coins(2, "token"));
+    do_init_with_minter(deps.borrow_mut(), &String::from(MOCK_CONTRACT_ADDR), None);
+    assert!(DECOMPOUND_STATE
+        .may_load(deps.as_ref().storage)
+        .unwrap()
+        .is_none());
+
+    // The decompounds are tracked from the migration on
+    let env = mock_env();
+    let msg = MigrateMsg {
+        max_decompound_ratio: Some(Decimal::percent(10)),
+        remove_max_decompound_ratio: None,
+        admin: None,
+        enable_snapshots: None,
+    };
+    coin_with_limit::migrate(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(
+        DECOMPOUND_STATE.load(deps.as_ref().storage).unwrap(),
+        DecompoundState {
+            ratio_sum: Decimal::zero(),
+            total_seconds: 0,
+            last_decompound: env.block.time,
+        }
+    );
+
+    // Later migrations replace the ratio and keep tracking the decompounds
+    let msg = MigrateMsg {
+        max_decompound_ratio: Some(Decimal::percent(5)),
+        remove_max_decompound_ratio: None,
+        admin: None,
+        enable_snapshots: None,
+    };
+    coin_with_limit::migrate(deps.as_mut(), mock_env(), msg).unwrap();
+    assert_eq!(
+        DECOMPOUND_CONFIG.load(deps.as_ref().storage).unwrap(),
+        DecompoundConfig {
+            max_decompound_ratio: Some(Decimal::percent(5)),
+        }
+    );
+
+    // The ratio is kept when none is given
+    let msg = MigrateMsg {
+        max_decompound_ratio: None,
+        remove_max_decompound_ratio: None,
+        admin: None,
+        enable_snapshots: None,
+    };
+    coin_with_limit::migrate(deps.as_mut(), mock_env(), msg).unwrap();
+    assert_eq!(
+        DECOMPOUND_CONFIG.load(deps.as_ref().storage).unwrap(),
+        DecompoundConfig {
+            max_decompound_ratio: Some(Decimal::percent(5)),
+        }
+    );
+
+    // The limit can't be replaced and removed at once
+    let msg = MigrateMsg {
+        max_decompound_ratio: Some(Decimal::percent(5)),
+        remove_max_decompound_ratio: Some(true),
+        admin: None,
+        enable_snapshots: None,
+    };
+    coin_with_limit::migrate(deps.as_mut(), mock_env(), msg).unwrap_err();
+
+    // The limit is only removed explicitly
+    let msg = MigrateMsg {
+        max_decompound_ratio: None,
+        remove_max_decompound_ratio: Some(true),
+        admin: None,
+        enable_snapshots: None,
+    };
+    coin_with_limit::migrate(deps.as_mut(), mock_env(), msg).unwrap();
+    assert_eq!(
+        DECOMPOUND_CONFIG.load(deps.as_ref().storage).unwrap(),
+        DecompoundConfig {
+            max_decompound_ratio: None,
+        }
+    );
+    assert_eq!(
+        DECOMPOUND_STATE
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .last_decompound,
+        env.block.time
+    );
+}
+
+#[test]
+fn update_lsd_config_and_hub() {
+    let mut deps = mock_dependencies(&coins(2, "token"));
+    do_init_with_minter(deps.borrow_mut(), &String::from(MOCK_CONTRACT_ADDR), None);
+
+    let new_config = StrideLSDConfigRaw {
+        denom: "stluna".to_string(),
+        underlying_token_denom: "uluna".to_string(),
+        oracle_contract: "new_oracle".to_string(),
+    };
+
+    // Only the admin can update the lsd config and the hub
+    let update_config = ExecuteMsg::UpdateLsdConfig {
+        config: new_config.clone(),
+    };
+    let update_hub = ExecuteMsg::UpdateHub {
+        hub: "new_hub".to_string(),
+    };
+    for msg in [update_config.clone(), update_hub.clone()] {
+        let err = execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("addr0001", &[]),
+            msg,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+    }
+
+    // The new config is validated like at instantiation
+    execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        ExecuteMsg::UpdateLsdConfig {
+            config: StrideLSDConfigRaw {
+                oracle_contract: "INVALID".to_string(),
+                ..new_config.clone()
+            },
+        },
+    )
+    .unwrap_err();
+
+    // The asset held by the wrapper can't be swapped
+    let err = execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        ExecuteMsg::UpdateLsdConfig {
+            config: StrideLSDConfigRaw {
+                denom: "stuluna".to_string(),
+                ..new_config.clone()
+            },
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::AssetMismatch {});
+
+    execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        update_config,
+    )
+    .unwrap();
+
+    let lsd_config: LsdConfigResponse = from_binary(
+        &query::<StrideLSDConfigRaw, StrideLSDConfig>(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::LsdConfig {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        lsd_config.asset,
+        AssetInfo::NativeToken {
+            denom: "stluna".to_string()
+        }
+    );
+    assert_eq!(
+        from_binary::<StrideLSDConfig>(&lsd_config.config).unwrap(),
+        StrideLSDConfig {
+            denom: "stluna".to_string(),
+            underlying_token_denom: "uluna".to_string(),
+            oracle_contract: Addr::unchecked("new_oracle"),
+        }
+    );
+
+    let res = execute::<StrideLSDConfigRaw, StrideLSDConfig>(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        update_hub,
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![attr("action", "update_hub"), attr("hub", "new_hub")]
+    );
+    assert_eq!(
+        read_hub_contract(deps.as_ref().storage).unwrap(),
+        Addr::unchecked("new_hub")
+    );
+}
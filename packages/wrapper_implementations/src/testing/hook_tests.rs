@@ -0,0 +1,121 @@
+use cosmwasm_std::{
+    Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError, StdResult, Uint128,
+};
+use cw20::{BalanceResponse, Cw20Coin};
+use cw_multi_test::{App, ContractWrapper, Executor};
+
+use basset::wrapper::{BalanceChangeHookMsg, ExecuteMsg, QueryMsg};
+use cavern_lsd_wrapper_token::msg::TokenInitMsg;
+
+use crate::coin::{execute, instantiate, query, StrideLSDConfigRaw};
+
+fn failing_hook_execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: BalanceChangeHookMsg,
+) -> StdResult<Response> {
+    Err(StdError::generic_err("hook error"))
+}
+
+fn hook_instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::default())
+}
+
+fn hook_query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    Err(StdError::generic_err("no queries"))
+}
+
+fn query_balance(app: &App, wrapper: &Addr, address: &str) -> Uint128 {
+    let balance: BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            wrapper,
+            &QueryMsg::Balance {
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+    balance.balance
+}
+
+#[test]
+fn failing_hook_reverts_the_transfer() {
+    let mut app = App::default();
+    let owner = Addr::unchecked("owner");
+    let holder = Addr::unchecked("addr0001");
+
+    let wrapper_code = app.store_code(Box::new(ContractWrapper::new(execute, instantiate, query)));
+    let hook_code = app.store_code(Box::new(ContractWrapper::new(
+        failing_hook_execute,
+        hook_instantiate,
+        hook_query,
+    )));
+
+    let init_msg = TokenInitMsg {
+        types: None,
+        name: "bluna".to_string(),
+        symbol: "BLUNA".to_string(),
+        decimals: 6,
+        initial_balances: vec![Cw20Coin {
+            address: holder.to_string(),
+            amount: Uint128::new(1000),
+        }],
+        hub_contract: "hub".to_string(),
+        admin: None,
+        lsd_config: StrideLSDConfigRaw {
+            denom: "stluna".to_string(),
+            underlying_token_denom: "uluna".to_string(),
+            oracle_contract: "oracle".to_string(),
+        },
+    };
+    let wrapper = app
+        .instantiate_contract(wrapper_code, owner.clone(), &init_msg, &[], "wrapper", None)
+        .unwrap();
+    let hook = app
+        .instantiate_contract(hook_code, owner.clone(), &Empty {}, &[], "hook", None)
+        .unwrap();
+
+    app.execute_contract(
+        owner.clone(),
+        wrapper.clone(),
+        &ExecuteMsg::<StrideLSDConfigRaw>::AddHook {
+            addr: hook.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The hooks keep the holders accounting, the transfer is reverted when one fails
+    let transfer = ExecuteMsg::<StrideLSDConfigRaw>::Transfer {
+        recipient: "addr0002".to_string(),
+        amount: Uint128::new(100),
+    };
+    app.execute_contract(holder.clone(), wrapper.clone(), &transfer, &[])
+        .unwrap_err();
+    assert_eq!(
+        query_balance(&app, &wrapper, "addr0001"),
+        Uint128::new(1000)
+    );
+    assert_eq!(query_balance(&app, &wrapper, "addr0002"), Uint128::zero());
+
+    // Once the failing hook is removed, the transfers go through again
+    app.execute_contract(
+        owner,
+        wrapper.clone(),
+        &ExecuteMsg::<StrideLSDConfigRaw>::RemoveHook {
+            addr: hook.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(holder, wrapper.clone(), &transfer, &[])
+        .unwrap();
+    assert_eq!(query_balance(&app, &wrapper, "addr0001"), Uint128::new(900));
+    assert_eq!(query_balance(&app, &wrapper, "addr0002"), Uint128::new(100));
+}
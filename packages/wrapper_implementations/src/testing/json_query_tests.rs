@@ -0,0 +1,147 @@
+use std::str::FromStr;
+
+use basset::contract_error::ContractError;
+use basset::external::{LSDQueryMsg, LSDStateResponse};
+use cavern_lsd_wrapper_token_with_limit::trait_def::LSDHub;
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{coins, to_binary, Addr, CosmosMsg, Decimal, StdError, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+
+use crate::json_query::{JsonQueryLSDConfigRaw, JsonQueryLSDHub, LsdAssetRaw};
+use crate::testing::mock_querier::{
+    mock_dependencies, MOCK_LSD_DENOM, MOCK_LSD_HUB_CONTRACT_ADDR, MOCK_LSD_TOKEN_CONTRACT_ADDR,
+};
+
+fn lsd_config(exchange_rate_path: &str, asset: LsdAssetRaw) -> JsonQueryLSDConfigRaw {
+    JsonQueryLSDConfigRaw {
+        contract: MOCK_LSD_HUB_CONTRACT_ADDR.to_string(),
+        query_msg: to_binary(&LSDQueryMsg::State {}).unwrap(),
+        exchange_rate_path: exchange_rate_path.to_string(),
+        invert: None,
+        asset,
+    }
+}
+
+fn cw20_asset() -> LsdAssetRaw {
+    LsdAssetRaw::Cw20 {
+        token: MOCK_LSD_TOKEN_CONTRACT_ADDR.to_string(),
+    }
+}
+
+#[test]
+fn exchange_rate_from_json_path() {
+    let mut deps = mock_dependencies(&[]);
+    deps.querier.with_lsd_state(LSDStateResponse {
+        exchange_rate: Decimal::from_str("1.5").unwrap(),
+        total_usteak: Uint128::from(1000000000u128),
+        total_uluna: Uint128::from(1500000000u128),
+        unlocked_coins: vec![],
+    });
+
+    let hub = JsonQueryLSDHub::instantiate_config(
+        deps.as_ref(),
+        lsd_config("exchange_rate", cw20_asset()),
+    )
+    .unwrap();
+    assert_eq!(
+        hub.query_exchange_rate(deps.as_ref(), mock_env()).unwrap(),
+        Decimal::from_str("1.5").unwrap()
+    );
+
+    // Rates quoted the other way round are inverted
+    let hub = JsonQueryLSDHub::instantiate_config(
+        deps.as_ref(),
+        JsonQueryLSDConfigRaw {
+            invert: Some(true),
+            ..lsd_config("exchange_rate", cw20_asset())
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        hub.query_exchange_rate(deps.as_ref(), mock_env()).unwrap(),
+        Decimal::from_ratio(2u128, 3u128)
+    );
+
+    // Missing fields are reported
+    let hub = JsonQueryLSDHub::instantiate_config(
+        deps.as_ref(),
+        lsd_config("unlocked_coins.0.amount", cw20_asset()),
+    )
+    .unwrap();
+    assert_eq!(
+        hub.query_exchange_rate(deps.as_ref(), mock_env())
+            .unwrap_err(),
+        ContractError::Std(StdError::generic_err(
+            "no exchange rate at unlocked_coins.0.amount"
+        ))
+    );
+
+    // Empty keys are refused when configuring the wrapper
+    JsonQueryLSDHub::instantiate_config(deps.as_ref(), lsd_config("exchange_rate.", cw20_asset()))
+        .unwrap_err();
+}
+
+#[test]
+fn deposit_modes() {
+    let deps = mock_dependencies(&coins(100, MOCK_LSD_DENOM));
+
+    let hub = JsonQueryLSDHub::instantiate_config(
+        deps.as_ref(),
+        lsd_config("exchange_rate", cw20_asset()),
+    )
+    .unwrap();
+    assert_eq!(
+        hub.deposit_funds(
+            deps.as_ref(),
+            mock_env(),
+            mock_info("addr0000", &[]),
+            Uint128::new(10),
+            Addr::unchecked("addr0000"),
+        )
+        .unwrap(),
+        vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: MOCK_LSD_TOKEN_CONTRACT_ADDR.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                owner: "addr0000".to_string(),
+                recipient: MOCK_CONTRACT_ADDR.to_string(),
+                amount: Uint128::new(10),
+            })
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+
+    // Native deposits are sent with the mint and are not counted in the balance
+    let hub = JsonQueryLSDHub::instantiate_config(
+        deps.as_ref(),
+        lsd_config(
+            "exchange_rate",
+            LsdAssetRaw::Native {
+                denom: MOCK_LSD_DENOM.to_string(),
+            },
+        ),
+    )
+    .unwrap();
+    let funds = coins(40, MOCK_LSD_DENOM);
+    assert_eq!(
+        hub.deposit_funds(
+            deps.as_ref(),
+            mock_env(),
+            mock_info("addr0000", &funds),
+            Uint128::new(40),
+            Addr::unchecked("addr0000"),
+        )
+        .unwrap(),
+        vec![]
+    );
+    assert_eq!(
+        hub.get_balance(
+            deps.as_ref(),
+            mock_env(),
+            Addr::unchecked(MOCK_CONTRACT_ADDR),
+            funds,
+        )
+        .unwrap(),
+        Uint128::new(60)
+    );
+}
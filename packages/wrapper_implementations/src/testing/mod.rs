@@ -1,3 +1,6 @@
 mod coin_tests;
+mod hook_tests;
+mod json_query_tests;
 mod mock_querier;
 mod steak_tests;
+mod token_factory_tests;
@@ -1,24 +1,29 @@
 use crate::steak::LsdContractsRaw;
 
 use crate::steak::SteakLSDHub;
+use crate::steak_with_limit;
 use crate::testing::mock_querier::{WasmMockQuerier, MOCK_HUB_CONTRACT_ADDR};
 use basset::external::LSDStateResponse;
+use basset::wrapper::MigrateMsg;
 use basset::wrapper::ExecuteMsg;
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    coins, to_binary, Api, CosmosMsg, Decimal, DepsMut, OwnedDeps, Storage, SubMsg, Uint128,
+    coins, to_binary, Addr, Api, CosmosMsg, Decimal, DepsMut, OwnedDeps, Storage, SubMsg, Uint128,
     WasmMsg,
 };
 use std::str::FromStr;
 
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg, MinterResponse, TokenInfoResponse};
-use cw20_base::contract::{query_minter, query_token_info};
+use cw20_base::contract::{query_balance, query_minter, query_token_info};
 
 use crate::testing::mock_querier::{
     mock_dependencies, MOCK_LSD_HUB_CONTRACT_ADDR, MOCK_LSD_TOKEN_CONTRACT_ADDR,
 };
 use cavern_lsd_wrapper_token::contract::{execute, instantiate};
 use cavern_lsd_wrapper_token::msg::TokenInitMsg;
+use cavern_lsd_wrapper_token_with_limit::state::{
+    read_lsd_config, DecompoundState, DECOMPOUND_STATE,
+};
 use std::borrow::BorrowMut;
 
 // this will set up the init for other tests
@@ -56,6 +61,7 @@ fn _do_init<S: Storage, A: Api>(
         decimals: 6,
         initial_balances: vec![],
         hub_contract: MOCK_HUB_CONTRACT_ADDR.to_string(),
+        admin: None,
         lsd_config,
     };
 
@@ -139,6 +145,7 @@ fn proper_initialization() {
         decimals: 6,
         initial_balances: vec![],
         hub_contract: MOCK_HUB_CONTRACT_ADDR.to_string(),
+        admin: None,
         lsd_config,
     };
     let info = mock_info(&String::from("owner"), &[]);
@@ -439,7 +446,7 @@ fn send() {
         Decimal::from_str("1.5").unwrap(),
     );
 
-    let dummy_msg = ExecuteMsg::Transfer {
+    let dummy_msg: ExecuteMsg = ExecuteMsg::Transfer {
         recipient: addr1.clone(),
         amount: Uint128::new(1u128),
     };
@@ -502,7 +509,7 @@ fn send_from() {
     )
     .unwrap();
 
-    let dummy_msg = ExecuteMsg::Transfer {
+    let dummy_msg: ExecuteMsg = ExecuteMsg::Transfer {
         recipient: addr1.clone(),
         amount: Uint128::new(1u128),
     };
@@ -535,3 +542,43 @@ fn send_from() {
         .unwrap()
     );
 }
+
+#[test]
+fn migrate_to_wrapper_with_limit() {
+    let mut deps = mock_dependencies(&coins(2, "token"));
+    let addr = String::from("addr0000");
+    let amount = Uint128::new(12340000u128);
+
+    do_init_with_minter(deps.borrow_mut(), &String::from(MOCK_CONTRACT_ADDR), None);
+    do_mint(
+        deps.as_mut(),
+        addr.clone(),
+        amount,
+        Decimal::from_str("1.5").unwrap(),
+    );
+
+    let env = mock_env();
+    let msg = MigrateMsg {
+        max_decompound_ratio: Some(Decimal::percent(10)),
+        remove_max_decompound_ratio: None,
+        admin: None,
+        enable_snapshots: None,
+    };
+    steak_with_limit::migrate::<LSDStateResponse>(deps.as_mut(), env.clone(), msg).unwrap();
+
+    // The balances and the lsd config are kept
+    assert_eq!(query_balance(deps.as_ref(), addr).unwrap().balance, amount);
+    let lsd_config: SteakLSDHub<LSDStateResponse> = read_lsd_config(deps.as_ref().storage).unwrap();
+    assert_eq!(
+        lsd_config.lsd_contracts.token,
+        Addr::unchecked(MOCK_LSD_TOKEN_CONTRACT_ADDR)
+    );
+    assert_eq!(
+        DECOMPOUND_STATE.load(deps.as_ref().storage).unwrap(),
+        DecompoundState {
+            ratio_sum: Decimal::zero(),
+            total_seconds: 0,
+            last_decompound: env.block.time,
+        }
+    );
+}
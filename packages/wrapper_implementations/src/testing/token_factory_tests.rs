@@ -0,0 +1,105 @@
+use std::str::FromStr;
+
+use basset::external::{LSDStateResponse, LSDStateResponseTrait};
+use cavern_lsd_wrapper_token::trait_def::LSDHub as LSDHubWithoutLimit;
+use cavern_lsd_wrapper_token_with_limit::trait_def::LSDHub;
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{coins, from_slice, Addr, BankMsg, CosmosMsg, Decimal, Uint128};
+
+use crate::testing::mock_querier::{mock_dependencies, MOCK_LSD_HUB_CONTRACT_ADDR};
+use crate::token_factory::{HubStateResponse, TokenFactoryLSDConfigRaw, TokenFactoryLSDHub};
+
+const MOCK_TOKEN_FACTORY_DENOM: &str = "factory/lsd/ampluna";
+
+fn lsd_config() -> TokenFactoryLSDConfigRaw {
+    TokenFactoryLSDConfigRaw {
+        hub: MOCK_LSD_HUB_CONTRACT_ADDR.to_string(),
+        denom: MOCK_TOKEN_FACTORY_DENOM.to_string(),
+    }
+}
+
+#[test]
+fn exchange_rate_from_hub() {
+    let mut deps = mock_dependencies(&[]);
+    deps.querier.with_lsd_state(LSDStateResponse {
+        exchange_rate: Decimal::from_str("1.2").unwrap(),
+        total_usteak: Uint128::from(1000000000u128),
+        total_uluna: Uint128::from(1200000000u128),
+        unlocked_coins: vec![],
+    });
+
+    let hub: TokenFactoryLSDHub<LSDStateResponse> =
+        LSDHubWithoutLimit::instantiate_config(deps.as_ref(), lsd_config()).unwrap();
+    assert_eq!(
+        LSDHubWithoutLimit::query_exchange_rate(&hub, deps.as_ref(), mock_env()).unwrap(),
+        Decimal::from_str("1.2").unwrap()
+    );
+    assert_eq!(
+        LSDHub::query_exchange_rate(&hub, deps.as_ref(), mock_env()).unwrap(),
+        Decimal::from_str("1.2").unwrap()
+    );
+}
+
+#[test]
+fn native_deposits() {
+    let deps = mock_dependencies(&coins(100, MOCK_TOKEN_FACTORY_DENOM));
+    let hub: TokenFactoryLSDHub<LSDStateResponse> =
+        LSDHubWithoutLimit::instantiate_config(deps.as_ref(), lsd_config()).unwrap();
+
+    // Only the lsd denom can be deposited
+    LSDHubWithoutLimit::deposit_funds(
+        &hub,
+        deps.as_ref(),
+        mock_env(),
+        mock_info("addr0000", &coins(40, "uluna")),
+        Uint128::new(40),
+        Addr::unchecked("addr0000"),
+    )
+    .unwrap_err();
+    let funds = coins(40, MOCK_TOKEN_FACTORY_DENOM);
+    LSDHubWithoutLimit::deposit_funds(
+        &hub,
+        deps.as_ref(),
+        mock_env(),
+        mock_info("addr0000", &funds),
+        Uint128::new(40),
+        Addr::unchecked("addr0000"),
+    )
+    .unwrap();
+
+    let contract = Addr::unchecked(MOCK_CONTRACT_ADDR);
+    assert_eq!(
+        LSDHubWithoutLimit::get_balance(&hub, deps.as_ref(), mock_env(), contract.clone()).unwrap(),
+        Uint128::new(100)
+    );
+    // With limit, the funds sent with the mint are not counted in the balance
+    assert_eq!(
+        LSDHub::get_balance(&hub, deps.as_ref(), mock_env(), contract, funds).unwrap(),
+        Uint128::new(60)
+    );
+
+    assert_eq!(
+        LSDHub::send_funds(
+            &hub,
+            deps.as_ref(),
+            mock_env(),
+            Uint128::new(10),
+            Addr::unchecked("addr0000"),
+        )
+        .unwrap(),
+        vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "addr0000".to_string(),
+            amount: coins(10, MOCK_TOKEN_FACTORY_DENOM),
+        })]
+    );
+}
+
+#[test]
+fn hub_state_with_any_fields() {
+    // Only the exchange rate is read from the state of the hub
+    let state: HubStateResponse = from_slice(
+        br#"{"total_ustake":"1000","total_uluna":"1200","exchange_rate":"1.2","unlocked_coins":[]}"#,
+    )
+    .unwrap();
+    assert_eq!(state.exchange_rate(), Decimal::from_str("1.2").unwrap());
+}
@@ -0,0 +1,158 @@
+use crate::native::{assert_native_deposit, query_native_balance, send_native};
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
+use basset::external::LSDQueryMsg;
+use basset::external::LSDStateResponseTrait;
+use basset::wrapper::ExecuteMsg;
+use basset::wrapper::MigrateMsg;
+use basset::wrapper::QueryMsg;
+use cavern_lsd_wrapper_token::msg::TokenInitMsg;
+use cavern_lsd_wrapper_token::trait_def::LSDHub;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::to_binary;
+use cosmwasm_std::Addr;
+use cosmwasm_std::Binary;
+use cosmwasm_std::CosmosMsg;
+use cosmwasm_std::Decimal;
+use cosmwasm_std::Deps;
+use cosmwasm_std::DepsMut;
+use cosmwasm_std::Env;
+use cosmwasm_std::MessageInfo;
+use cosmwasm_std::QueryRequest;
+use cosmwasm_std::Response;
+use cosmwasm_std::StdResult;
+use cosmwasm_std::Uint128;
+use cosmwasm_std::WasmQuery;
+
+#[cw_serde]
+pub struct TokenFactoryLSDConfigRaw {
+    /// Hub of the lsd, queried for its exchange rate
+    pub hub: String,
+    /// Token factory denom of the lsd
+    pub denom: String,
+}
+
+/// State of a hub exposing its exchange rate, like the Eris and Backbone hubs.
+/// Unlike the `cw_serde` types, the other fields of the state are ignored
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct HubStateResponse {
+    pub exchange_rate: Decimal,
+}
+
+impl LSDStateResponseTrait for HubStateResponse {
+    fn exchange_rate(&self) -> Decimal {
+        self.exchange_rate
+    }
+}
+
+/// Native lsds whose hub exposes the exchange rate, like ampLUNA and bLUNA on Migaloo.
+/// `T` is the state response of the hub
+#[cw_serde]
+pub struct TokenFactoryLSDHub<T: for<'a> Deserialize<'a>> {
+    pub types: Option<PhantomData<T>>,
+    pub hub: Addr,
+    pub denom: String,
+}
+
+impl<T: LSDStateResponseTrait + for<'a> Deserialize<'a>> TokenFactoryLSDHub<T> {
+    pub fn query_lsd_state(&self, deps: Deps) -> StdResult<T> {
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: self.hub.to_string(),
+            msg: to_binary(&LSDQueryMsg::State {})?,
+        }))
+    }
+}
+
+impl<T: LSDStateResponseTrait + for<'a> Deserialize<'a>> LSDHub<TokenFactoryLSDConfigRaw>
+    for TokenFactoryLSDHub<T>
+{
+    fn instantiate_config(
+        deps: Deps,
+        config: TokenFactoryLSDConfigRaw,
+    ) -> Result<Self, ContractError> {
+        Ok(Self {
+            types: None,
+            hub: deps.api.addr_validate(&config.hub)?,
+            denom: config.denom,
+        })
+    }
+
+    fn query_exchange_rate(&self, deps: Deps, _env: Env) -> Result<Decimal, ContractError> {
+        // Unlike the stride tokens, the rate is exposed on chain by the hub of the lsd
+        Ok(self.query_lsd_state(deps)?.exchange_rate())
+    }
+
+    fn held_asset(&self) -> AssetInfo {
+        AssetInfo::NativeToken {
+            denom: self.denom.clone(),
+        }
+    }
+
+    fn get_balance(&self, deps: Deps, _env: Env, address: Addr) -> Result<Uint128, ContractError> {
+        query_native_balance(deps, &address, &self.denom)
+    }
+
+    fn deposit_funds(
+        &self,
+        _deps: Deps,
+        _env: Env,
+        info: MessageInfo,
+        amount: Uint128,
+        _from: Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        assert_native_deposit(&info, amount, &self.denom)
+    }
+
+    fn send_funds(
+        &self,
+        _deps: Deps,
+        _env: Env,
+        amount: Uint128,
+        to: Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        Ok(send_native(amount, &to, &self.denom))
+    }
+}
+
+pub type TokenFactoryInitMsg = TokenInitMsg<TokenFactoryLSDConfigRaw>;
+pub type TokenFactoryExecuteMsg = ExecuteMsg<TokenFactoryLSDConfigRaw>;
+
+pub fn instantiate<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: TokenFactoryInitMsg,
+) -> StdResult<Response> {
+    cavern_lsd_wrapper_token::contract::instantiate::<TokenFactoryLSDConfigRaw, TokenFactoryLSDHub<T>>(
+        deps, env, info, msg,
+    )
+}
+
+pub fn execute<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: TokenFactoryExecuteMsg,
+) -> Result<Response, ContractError> {
+    cavern_lsd_wrapper_token::contract::execute::<TokenFactoryLSDConfigRaw, TokenFactoryLSDHub<T>>(
+        deps, env, info, msg,
+    )
+}
+
+pub fn query<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
+    deps: Deps,
+    env: Env,
+    msg: QueryMsg,
+) -> StdResult<Binary> {
+    cavern_lsd_wrapper_token::contract::query::<TokenFactoryLSDConfigRaw, TokenFactoryLSDHub<T>>(
+        deps, env, msg,
+    )
+}
+
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    cavern_lsd_wrapper_token::contract::migrate(deps, env, msg)
+}
@@ -0,0 +1,121 @@
+use crate::native::query_native_balance_before;
+use basset::contract_error::ContractError;
+use basset::dex_router::AssetInfo;
+use basset::external::LSDStateResponseTrait;
+use basset::wrapper::ExecuteMsg;
+use basset::wrapper::MigrateMsg;
+use basset::wrapper::QueryMsg;
+use cavern_lsd_wrapper_token::trait_def::LSDHub as LSDHubWithoutLimit;
+use cavern_lsd_wrapper_token_with_limit::msg::TokenInitMsg;
+use cavern_lsd_wrapper_token_with_limit::trait_def::LSDHub;
+use serde::Deserialize;
+
+use cosmwasm_std::Addr;
+use cosmwasm_std::Binary;
+use cosmwasm_std::Coin;
+use cosmwasm_std::CosmosMsg;
+use cosmwasm_std::Decimal;
+use cosmwasm_std::Deps;
+use cosmwasm_std::DepsMut;
+use cosmwasm_std::Env;
+use cosmwasm_std::MessageInfo;
+use cosmwasm_std::Response;
+use cosmwasm_std::StdResult;
+use cosmwasm_std::Uint128;
+
+use crate::token_factory::{TokenFactoryLSDConfigRaw, TokenFactoryLSDHub};
+
+// Same lsd config as the token factory wrappers without limit, so that they can be migrated in place
+impl<T: LSDStateResponseTrait + for<'a> Deserialize<'a>> LSDHub<TokenFactoryLSDConfigRaw>
+    for TokenFactoryLSDHub<T>
+{
+    fn instantiate_config(
+        deps: Deps,
+        config: TokenFactoryLSDConfigRaw,
+    ) -> Result<Self, ContractError> {
+        <Self as LSDHubWithoutLimit<TokenFactoryLSDConfigRaw>>::instantiate_config(deps, config)
+    }
+
+    fn query_exchange_rate(&self, deps: Deps, env: Env) -> Result<Decimal, ContractError> {
+        LSDHubWithoutLimit::<TokenFactoryLSDConfigRaw>::query_exchange_rate(self, deps, env)
+    }
+
+    fn held_asset(&self) -> AssetInfo {
+        LSDHubWithoutLimit::<TokenFactoryLSDConfigRaw>::held_asset(self)
+    }
+
+    fn get_balance(
+        &self,
+        deps: Deps,
+        _env: Env,
+        address: Addr,
+        funds: Vec<Coin>,
+    ) -> Result<Uint128, ContractError> {
+        query_native_balance_before(deps, &address, &self.denom, &funds)
+    }
+
+    fn deposit_funds(
+        &self,
+        deps: Deps,
+        env: Env,
+        info: MessageInfo,
+        amount: Uint128,
+        from: Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        LSDHubWithoutLimit::<TokenFactoryLSDConfigRaw>::deposit_funds(
+            self, deps, env, info, amount, from,
+        )
+    }
+
+    fn send_funds(
+        &self,
+        deps: Deps,
+        env: Env,
+        amount: Uint128,
+        to: Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        LSDHubWithoutLimit::<TokenFactoryLSDConfigRaw>::send_funds(self, deps, env, amount, to)
+    }
+}
+
+pub type TokenFactoryInitMsg = TokenInitMsg<TokenFactoryLSDConfigRaw>;
+pub type TokenFactoryExecuteMsg = ExecuteMsg<TokenFactoryLSDConfigRaw>;
+
+pub fn instantiate<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: TokenFactoryInitMsg,
+) -> StdResult<Response> {
+    cavern_lsd_wrapper_token_with_limit::contract::instantiate::<
+        TokenFactoryLSDConfigRaw,
+        TokenFactoryLSDHub<T>,
+    >(deps, env, info, msg)
+}
+
+pub fn execute<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: TokenFactoryExecuteMsg,
+) -> Result<Response, ContractError> {
+    cavern_lsd_wrapper_token_with_limit::contract::execute::<
+        TokenFactoryLSDConfigRaw,
+        TokenFactoryLSDHub<T>,
+    >(deps, env, info, msg)
+}
+
+pub fn query<T: LSDStateResponseTrait + for<'a> Deserialize<'a>>(
+    deps: Deps,
+    env: Env,
+    msg: QueryMsg,
+) -> StdResult<Binary> {
+    cavern_lsd_wrapper_token_with_limit::contract::query::<
+        TokenFactoryLSDConfigRaw,
+        TokenFactoryLSDHub<T>,
+    >(deps, env, msg)
+}
+
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    cavern_lsd_wrapper_token_with_limit::contract::migrate(deps, env, msg)
+}
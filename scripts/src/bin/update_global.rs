@@ -1,5 +1,5 @@
 use cw_orch::{prelude::DaemonBuilder, tokio::runtime::Runtime};
-use scripts::{hub_reward_migration::{migrate_migaloo_swap::upload_reward, reward_addr_and_migrate_hub::upload_hub_simple}, MIGALOO_1};
+use scripts::{hub_reward_migration::{migrate_migaloo_swap::upload_reward, reward_addr_and_migrate_hub::upload_hub}, MIGALOO_1};
 
 
 fn update_global() -> anyhow::Result<()>{
@@ -15,7 +15,7 @@ fn update_global() -> anyhow::Result<()>{
         .build()?;
 
     upload_reward(app.clone())?;
-    upload_hub_simple(app.clone())?;
+    upload_hub(app.clone())?;
 
     // Other functions have to be executed inside the multisig
 